@@ -4,10 +4,39 @@
 //! for the longtime application. It manages timezone data, time offset,
 //! and application state.
 
-use std::rc::Rc;
+use std::{collections::BTreeSet, path::PathBuf, rc::Rc, sync::Arc};
 
 use chrono::{DateTime, Duration, Utc};
-use longtime_core::{Config, TimezoneConfig};
+use longtime_core::{
+    Clock, Config, DayBoundary, DisplayOptions, PRESETS, SystemClock, TimezoneConfig, WorkHours,
+    clamp_time_offset, day_boundary_offset, diff_header_label, format_hours_minutes,
+    format_invite_text, format_overlap_report, format_relative_offset, get_time_display_info,
+    group_by_status, local_time_instant, local_zone, next_work_start, offset_to_reach,
+    parse_time_expression, reference_name, reference_offset, reference_zone, remaining_week_work,
+    sort_by_offset, system_offset,
+};
+
+use crate::config_loader::SaveFormat;
+
+/// Minutes the arrow keys can move the time by, smallest first
+pub const TIME_STEPS: [i64; 5] = [1, 5, 15, 30, 60];
+
+/// Default arrow key step in minutes
+const DEFAULT_TIME_STEP: i64 = 15;
+
+/// Default distance of the compared instant, in days
+const DEFAULT_COMPARE_DAYS: i64 = 7;
+
+/// A bulk work hours change waiting for confirmation
+#[derive(Debug, Clone, PartialEq)]
+pub struct PendingHours {
+    /// The new work hours
+    pub hours: WorkHours,
+    /// Only zones currently working this range are changed, if set
+    pub only_matching: Option<WorkHours>,
+    /// Number of zones that will change
+    pub zones: usize,
+}
 
 /// The main application state
 ///
@@ -17,10 +46,26 @@ use longtime_core::{Config, TimezoneConfig};
 pub struct App {
     /// Configuration loaded from the TOML file
     config: Rc<Config>,
+    /// Source of the current time
+    clock: Arc<dyn Clock>,
     /// Currently selected timezone index
     pub selected: usize,
+    /// Config index of the selected zone, followed when grouping by status
+    /// reorders the list as time passes
+    selected_zone: Option<usize>,
+    /// Config index of the zone selected before the current one
+    pub previous_selection: Option<usize>,
+    /// Config indices of the zones marked for export
+    pub marked: BTreeSet<usize>,
     /// Time offset for simulating different times
     pub time_offset: Duration,
+    /// Minutes the arrow keys move the time by, one of `TIME_STEPS`
+    pub time_step_minutes: i64,
+    /// Whether each zone is listed a second time, `compare_delta` after the
+    /// simulated time
+    pub compare: bool,
+    /// Distance of the compared instant from the simulated time
+    pub compare_delta: Duration,
     /// Whether to show the help modal
     pub show_help: bool,
     /// Search query for filtering timezones
@@ -29,6 +74,47 @@ pub struct App {
     pub is_searching: bool,
     /// Whether to use 12-hour format
     pub use_12h_format: bool,
+    /// Transient message shown in the footer until the next key press
+    pub status_message: Option<String>,
+    /// Whether muted timezones are listed alongside the enabled ones
+    pub show_muted: bool,
+    /// Whether to show elapsed/remaining work time per zone
+    pub show_progress: bool,
+    /// Whether to show the diff column
+    pub show_diff: bool,
+    /// Whether to show each zone's work hours on the reference clock
+    pub show_reference_hours: bool,
+    /// Whether rows of zones outside work hours are dimmed
+    pub focus_mode: bool,
+    /// Whether diffs are measured against UTC instead of the home or
+    /// selected zone
+    pub utc_reference: bool,
+    /// Whether zones are listed by UTC offset instead of config order
+    pub sort_by_offset: bool,
+    /// Whether working zones are listed ahead of zones outside work hours
+    pub group_by_status: bool,
+    /// Whether the preset picker is open
+    pub show_presets: bool,
+    /// Whether the saved offset picker is open
+    pub show_offsets: bool,
+    /// Whether to show the pairwise diff matrix instead of the list
+    pub show_matrix: bool,
+    /// Whether to show the 7-day availability grid instead of the list
+    pub show_week: bool,
+    /// Whether the user is typing a time expression to jump to
+    pub is_jumping: bool,
+    /// Time expression being typed, e.g. "tomorrow 9am"
+    pub jump_input: String,
+    /// Whether the user is typing work hours to apply to all zones
+    pub is_setting_hours: bool,
+    /// Work hours being typed, e.g. "10:00-18:00 from 09:00-17:00"
+    pub hours_input: String,
+    /// Parsed bulk work hours change awaiting a y/n answer
+    pub pending_hours: Option<PendingHours>,
+    /// File the configuration is saved to, if known
+    pub config_path: Option<PathBuf>,
+    /// Format the configuration is saved in
+    pub save_format: SaveFormat,
 }
 
 impl App {
@@ -38,30 +124,79 @@ impl App {
     ///
     /// * `config` - The configuration containing timezone information
     pub fn new(config: Config) -> Self {
+        Self::with_clock(config, Arc::new(SystemClock))
+    }
+
+    /// Creates a new application driven by the given clock
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - The configuration containing timezone information
+    /// * `clock` - The source of the current time
+    pub fn with_clock(config: Config, clock: Arc<dyn Clock>) -> Self {
         let use_12h_format = config.use_12h_format;
+        let show_diff = config.show_diff;
         App {
             config: Rc::new(config),
+            clock,
             selected: 0,
+            selected_zone: None,
+            previous_selection: None,
+            marked: BTreeSet::new(),
             time_offset: Duration::zero(),
+            time_step_minutes: DEFAULT_TIME_STEP,
+            compare: false,
+            compare_delta: Duration::days(DEFAULT_COMPARE_DAYS),
             show_help: false,
             search_query: String::new(),
             is_searching: false,
             use_12h_format,
+            status_message: None,
+            show_muted: false,
+            show_progress: false,
+            show_diff,
+            show_reference_hours: false,
+            focus_mode: false,
+            utc_reference: false,
+            sort_by_offset: false,
+            group_by_status: false,
+            show_presets: false,
+            show_offsets: false,
+            show_matrix: false,
+            show_week: false,
+            is_jumping: false,
+            jump_input: String::new(),
+            is_setting_hours: false,
+            hours_input: String::new(),
+            pending_hours: None,
+            config_path: None,
+            save_format: SaveFormat::default(),
         }
     }
 
     /// Returns the current configuration
-    #[allow(dead_code)]
     pub fn config(&self) -> &Config {
         &self.config
     }
 
     /// Returns the filtered timezones based on search query
+    ///
+    /// When offset sorting is on, the result is ordered by current UTC offset.
     pub fn get_filtered_timezones(&self) -> Vec<(usize, &TimezoneConfig)> {
-        self.config
+        self.filtered_timezones_at(self.current_time())
+    }
+
+    /// Returns the filtered timezones, ordered by their offsets at `now`
+    ///
+    /// Rendering reads the clock once per frame and passes that instant here,
+    /// so the order matches the offsets shown even across a DST change.
+    pub fn filtered_timezones_at(&self, now: DateTime<Utc>) -> Vec<(usize, &TimezoneConfig)> {
+        let mut timezones: Vec<_> = self
+            .config
             .timezones
             .iter()
             .enumerate()
+            .filter(|(_, tz)| self.show_muted || tz.enabled)
             .filter(|(_, tz)| {
                 self.search_query.is_empty()
                     || tz
@@ -73,7 +208,14 @@ impl App {
                         .to_lowercase()
                         .contains(&self.search_query.to_lowercase())
             })
-            .collect()
+            .collect();
+        if self.sort_by_offset {
+            sort_by_offset(now, &mut timezones, |(_, tz)| *tz);
+        }
+        if self.group_by_status {
+            group_by_status(now, &mut timezones, |(_, tz)| *tz);
+        }
+        timezones
     }
 
     /// Returns the number of configured timezones (filtered)
@@ -81,9 +223,103 @@ impl App {
         self.get_filtered_timezones().len()
     }
 
+    /// Returns the selected timezone, or None if the filtered list is empty
+    pub fn selected_timezone(&self) -> Option<(usize, &TimezoneConfig)> {
+        self.get_filtered_timezones().get(self.selected).copied()
+    }
+
+    /// Returns the selected timezone in the list as ordered at `now`
+    pub fn selected_timezone_at(&self, now: DateTime<Utc>) -> Option<(usize, &TimezoneConfig)> {
+        self.filtered_timezones_at(now).get(self.selected).copied()
+    }
+
+    /// Returns the number of muted (disabled) timezones
+    pub fn muted_count(&self) -> usize {
+        self.config
+            .timezones
+            .iter()
+            .filter(|tz| !tz.enabled)
+            .count()
+    }
+
+    /// Mutes or unmutes the selected timezone
+    pub fn toggle_selected_enabled(&mut self) {
+        let Some(index) = self
+            .get_filtered_timezones()
+            .get(self.selected)
+            .map(|(i, _)| *i)
+        else {
+            return;
+        };
+        let tz = &mut Rc::make_mut(&mut self.config).timezones[index];
+        tz.enabled = !tz.enabled;
+        self.clamp_selection();
+    }
+
+    /// Toggles whether muted timezones are listed
+    pub fn toggle_show_muted(&mut self) {
+        self.show_muted = !self.show_muted;
+        self.clamp_selection();
+    }
+
+    /// Keeps the selection within the filtered list after it shrinks
+    fn clamp_selection(&mut self) {
+        self.selected = self.selected.min(self.timezone_count().saturating_sub(1));
+    }
+
+    /// Returns the time and date rendering options in effect
+    pub fn display_options(&self) -> DisplayOptions {
+        DisplayOptions {
+            use_12h_format: self.use_12h_format,
+            ..DisplayOptions::from_config(&self.config)
+        }
+    }
+
     /// Gets the current time with the applied offset
     pub fn current_time(&self) -> DateTime<Utc> {
-        Utc::now() + self.time_offset
+        self.clock.now() + self.time_offset
+    }
+
+    /// Builds an invite sentence for the selected timezone
+    ///
+    /// The recipient side is the selected timezone and the sender side is the
+    /// diff reference (see [`App::reference_zone_at`]), both at the simulated
+    /// instant. When the selected zone is the reference itself, the sender
+    /// side is the system's local time. The web app phrases invites the same
+    /// way.
+    ///
+    /// # Returns
+    ///
+    /// * `Option<String>` - The invite text, or None if nothing valid is selected
+    pub fn invite_text(&self) -> Option<String> {
+        let (_, target) = self.selected_timezone()?;
+        let now = self.current_time();
+        let options = self.display_options();
+        let theirs = get_time_display_info(now, target, 0, options)?;
+
+        let mut sender = self.reference_zone_at(now, Some(target));
+        if sender == *target {
+            sender = local_zone(system_offset(now));
+        }
+        let mine = get_time_display_info(now, &sender, 0, options)?;
+
+        Some(format_invite_text(&theirs, &mine))
+    }
+
+    /// Builds a Markdown report of the work hour overlap of every pair of
+    /// listed zones
+    ///
+    /// # Returns
+    ///
+    /// * `Option<String>` - The report, or None if fewer than two zones are listed
+    pub fn overlap_report(&self) -> Option<String> {
+        let now = self.current_time();
+        let zones: Vec<_> = self
+            .filtered_timezones_at(now)
+            .into_iter()
+            .map(|(_, tz)| tz)
+            .collect();
+        (zones.len() >= 2).then(|| format_overlap_report(now, &zones))
     }
 
     /// Toggles the help modal
@@ -91,6 +327,232 @@ impl App {
         self.show_help = !self.show_help;
     }
 
+    /// Toggles the diff column
+    pub fn toggle_diff(&mut self) {
+        self.show_diff = !self.show_diff;
+    }
+
+    /// Toggles the elapsed/remaining work time column
+    pub fn toggle_progress(&mut self) {
+        self.show_progress = !self.show_progress;
+    }
+
+    /// Toggles sorting zones by UTC offset
+    pub fn toggle_sort_by_offset(&mut self) {
+        self.sort_by_offset = !self.sort_by_offset;
+        self.selected = 0;
+    }
+
+    /// Toggles listing working zones ahead of the others
+    ///
+    /// The selected zone stays selected at its new position.
+    pub fn toggle_group_by_status(&mut self) {
+        let selected = self.selected_timezone().map(|(index, _)| index);
+        self.group_by_status = !self.group_by_status;
+        if let Some(selected) = selected {
+            self.selected = self
+                .get_filtered_timezones()
+                .iter()
+                .position(|(index, _)| *index == selected)
+                .unwrap_or(0);
+        }
+    }
+
+    /// Toggles between the timezone list and the diff matrix
+    pub fn toggle_matrix(&mut self) {
+        self.show_matrix = !self.show_matrix;
+        self.show_week = false;
+    }
+
+    /// Toggles the column of work hours read on the reference clock
+    pub fn toggle_reference_hours(&mut self) {
+        self.show_reference_hours = !self.show_reference_hours;
+    }
+
+    /// Toggles dimming of zones outside work hours
+    pub fn toggle_focus_mode(&mut self) {
+        self.focus_mode = !self.focus_mode;
+    }
+
+    /// Toggles measuring diffs against UTC
+    pub fn toggle_utc_reference(&mut self) {
+        self.utc_reference = !self.utc_reference;
+    }
+
+    /// Gets the offset diffs and conversions are relative to
+    ///
+    /// # Arguments
+    ///
+    /// * `now` - Current (simulated) UTC time
+    /// * `selected` - The selected zone, used without a home zone
+    ///
+    /// # Returns
+    ///
+    /// * `i32` - Offset in seconds; 0 while locked to UTC
+    pub fn reference_offset_at(
+        &self,
+        now: DateTime<Utc>,
+        selected: Option<&TimezoneConfig>,
+    ) -> i32 {
+        if self.utc_reference {
+            0
+        } else {
+            reference_offset(now, &self.config, selected)
+        }
+    }
+
+    /// Gets the zone diffs are relative to: UTC while locked to it or
+    /// without a reference, otherwise the diff baseline's zone
+    pub fn reference_zone_at(
+        &self,
+        now: DateTime<Utc>,
+        selected: Option<&TimezoneConfig>,
+    ) -> TimezoneConfig {
+        let zone = (!self.utc_reference)
+            .then(|| reference_zone(now, &self.config, selected))
+            .flatten();
+        zone.unwrap_or_else(|| TimezoneConfig {
+            name: "UTC".to_string(),
+            timezone: "UTC".to_string(),
+            ..Default::default()
+        })
+    }
+
+    /// Gets the name of the zone diffs and conversions are relative to
+    pub fn reference_name_at(&self, now: DateTime<Utc>) -> String {
+        reference_name(
+            &self.config,
+            self.selected_timezone_at(now).map(|(_, tz)| tz),
+            self.utc_reference,
+        )
+    }
+
+    /// Gets the diff column header naming the reference zone
+    pub fn diff_header(&self) -> String {
+        if self.utc_reference {
+            "Diff (vs UTC)".to_string()
+        } else {
+            diff_header_label(&self.config)
+        }
+    }
+
+    /// Toggles the 7-day availability grid
+    pub fn toggle_week(&mut self) {
+        self.show_week = !self.show_week;
+        self.show_matrix = false;
+    }
+
+    /// Opens or closes the preset picker
+    pub fn toggle_presets(&mut self) {
+        self.show_presets = !self.show_presets;
+        self.show_help = false;
+    }
+
+    /// Opens or closes the saved offset picker
+    pub fn toggle_offsets(&mut self) {
+        self.show_offsets = !self.show_offsets;
+        self.show_help = false;
+    }
+
+    /// Sets the time offset to a saved offset from now
+    ///
+    /// Errors are reported through the status message.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - Index into the configured `saved_offsets`
+    pub fn apply_saved_offset(&mut self, index: usize) {
+        let Some(saved) = self.config.saved_offsets.get(index) else {
+            return;
+        };
+        self.show_offsets = false;
+        match saved.seconds() {
+            Ok(seconds) => {
+                self.time_offset = Duration::seconds(clamp_time_offset(seconds));
+                self.status_message = Some(format!("Now at {} ({})", saved.name, saved.offset));
+            }
+            Err(message) => self.status_message = Some(format!("{}: {message}", saved.name)),
+        }
+    }
+
+    /// Appends the zones of a preset, skipping ones already configured
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - Index into `PRESETS`
+    pub fn apply_preset(&mut self, index: usize) {
+        let Some(preset) = PRESETS.get(index) else {
+            return;
+        };
+        let added = preset.apply_to(Rc::make_mut(&mut self.config));
+        self.show_presets = false;
+        self.status_message = Some(format!("Added {added} zones from {}", preset.name));
+    }
+
+    /// Saves the configuration, including the current view settings
+    ///
+    /// Comments and unknown keys in the file are kept; see
+    /// [`crate::config_loader::save_config`].
+    pub fn save_config(&mut self) {
+        let Some(path) = self.config_path.clone() else {
+            self.status_message = Some("No config file to save to".to_string());
+            return;
+        };
+        let config = Rc::make_mut(&mut self.config);
+        config.use_12h_format = self.use_12h_format;
+        config.show_diff = self.show_diff;
+        self.status_message = Some(
+            match crate::config_loader::save_config(&path, config, self.save_format) {
+                Ok(()) => format!("Saved to {}", path.display()),
+                Err(e) => format!("Save failed: {e}"),
+            },
+        );
+    }
+
+    /// Marks or unmarks the selected timezone for export
+    pub fn toggle_marked(&mut self) {
+        if let Some((index, _)) = self.selected_timezone()
+            && !self.marked.remove(&index)
+        {
+            self.marked.insert(index);
+        }
+    }
+
+    /// Returns the configuration with only the marked timezones
+    pub fn marked_config(&self) -> Config {
+        let marked: Vec<usize> = self.marked.iter().copied().collect();
+        Config {
+            use_12h_format: self.use_12h_format,
+            show_diff: self.show_diff,
+            ..self.config.with_zones(&marked)
+        }
+    }
+
+    /// Writes the marked timezones to a file next to the config file
+    ///
+    /// See [`crate::config_loader::export_path`].
+    pub fn export_marked(&mut self) {
+        if self.marked.is_empty() {
+            self.status_message = Some("Mark zones with Space to export them".to_string());
+            return;
+        }
+        let Some(config_path) = &self.config_path else {
+            self.status_message = Some("No config file to export next to".to_string());
+            return;
+        };
+        let path = crate::config_loader::export_path(config_path, self.save_format);
+        self.status_message = Some(
+            match crate::config_loader::export_config(
+                &path,
+                &self.marked_config(),
+                self.save_format,
+            ) {
+                Ok(()) => format!("Exported {} zones to {}", self.marked.len(), path.display()),
+                Err(e) => format!("Export failed: {e}"),
+            },
+        );
+    }
+
     /// Toggles 12/24 hour format
     pub fn toggle_format(&mut self) {
         self.use_12h_format = !self.use_12h_format;
@@ -125,13 +587,171 @@ impl App {
         self.selected = 0;
     }
 
+    /// Starts typing a time expression to jump to
+    pub fn enter_jump(&mut self) {
+        self.is_jumping = true;
+        self.jump_input.clear();
+        self.show_help = false;
+    }
+
+    /// Cancels the time expression prompt
+    pub fn exit_jump(&mut self) {
+        self.is_jumping = false;
+    }
+
+    /// Appends a character to the time expression
+    pub fn append_jump(&mut self, c: char) {
+        self.jump_input.push(c);
+    }
+
+    /// Removes the last character from the time expression
+    pub fn backspace_jump(&mut self) {
+        self.jump_input.pop();
+    }
+
+    /// Applies the typed time expression relative to the simulated time
+    ///
+    /// Errors are reported through the status message.
+    pub fn submit_jump(&mut self) {
+        self.is_jumping = false;
+        let expr = self.jump_input.clone();
+        if let Err(message) = self.apply_time_expression(&expr) {
+            self.status_message = Some(message);
+        }
+    }
+
+    /// Moves the simulated time by a human time expression such as "+2h" or
+    /// "tomorrow 9am"
+    ///
+    /// Absolute times are read in the home zone, or the selected zone
+    /// without one.
+    ///
+    /// # Arguments
+    ///
+    /// * `expr` - The time expression
+    ///
+    /// # Returns
+    ///
+    /// * `Result<(), String>` - Ok once applied, or a message describing the problem
+    pub fn apply_time_expression(&mut self, expr: &str) -> Result<(), String> {
+        let seconds = parse_time_expression(expr, self.current_time(), &self.expression_zone())?;
+        self.shift_time(seconds);
+        Ok(())
+    }
+
+    /// Lists each zone a second time at a time expression such as "+7d" or
+    /// "tomorrow 9am", read like `apply_time_expression`
+    ///
+    /// The expression is stored as its distance from the current time, so
+    /// the compared rows keep that distance as time moves on: "tomorrow 9am"
+    /// stays pinned to 9am tomorrow only until the simulated time changes.
+    ///
+    /// # Arguments
+    ///
+    /// * `expr` - The time expression
+    ///
+    /// # Returns
+    ///
+    /// * `Result<(), String>` - Ok once applied, or a message describing the problem
+    pub fn set_compare(&mut self, expr: &str) -> Result<(), String> {
+        let seconds = parse_time_expression(expr, self.current_time(), &self.expression_zone())?;
+        self.compare_delta = Duration::seconds(clamp_time_offset(seconds));
+        self.compare = true;
+        Ok(())
+    }
+
+    /// Toggles listing each zone a second time at the compared instant
+    pub fn toggle_compare(&mut self) {
+        self.compare = !self.compare;
+    }
+
+    /// Gets the label of the compared rows, e.g. "+7d"
+    pub fn compare_label(&self) -> String {
+        format_relative_offset(self.compare_delta.num_seconds())
+    }
+
+    /// Gets the zone absolute time expressions are read in
+    fn expression_zone(&self) -> TimezoneConfig {
+        let now = self.current_time();
+        self.reference_zone_at(now, self.selected_timezone_at(now).map(|(_, tz)| tz))
+    }
+
+    /// Opens the prompt for setting all zones' work hours
+    pub fn enter_set_hours(&mut self) {
+        self.is_setting_hours = true;
+        self.hours_input.clear();
+        self.show_help = false;
+    }
+
+    /// Cancels the work hours prompt
+    pub fn exit_set_hours(&mut self) {
+        self.is_setting_hours = false;
+    }
+
+    /// Appends a character to the work hours input
+    pub fn append_set_hours(&mut self, c: char) {
+        self.hours_input.push(c);
+    }
+
+    /// Removes the last character from the work hours input
+    pub fn backspace_set_hours(&mut self) {
+        self.hours_input.pop();
+    }
+
+    /// Parses the typed work hours and asks for confirmation
+    ///
+    /// The input is a range such as "10:00-18:00", optionally followed by
+    /// "from 09:00-17:00" to only change zones working that range.
+    pub fn submit_set_hours(&mut self) {
+        self.is_setting_hours = false;
+        let (range, only) = match self.hours_input.split_once(" from ") {
+            Some((range, only)) => (range, Some(only)),
+            None => (self.hours_input.as_str(), None),
+        };
+        let parsed = WorkHours::parse_range(range).and_then(|hours| {
+            let only_matching = only.map(WorkHours::parse_range).transpose()?;
+            Ok((hours, only_matching))
+        });
+        match parsed {
+            Ok((hours, only_matching)) => {
+                let zones = self.config.count_work_hours_targets(only_matching.as_ref());
+                self.pending_hours = Some(PendingHours {
+                    hours,
+                    only_matching,
+                    zones,
+                });
+            }
+            Err(message) => self.status_message = Some(message),
+        }
+    }
+
+    /// Applies the pending work hours change and saves the configuration
+    pub fn confirm_set_hours(&mut self) {
+        let Some(pending) = self.pending_hours.take() else {
+            return;
+        };
+        let changed = Rc::make_mut(&mut self.config)
+            .set_all_work_hours(&pending.hours, pending.only_matching.as_ref());
+        self.save_config();
+        let saved = self.status_message.take().unwrap_or_default();
+        self.status_message = Some(format!(
+            "Set {changed} zones to {}-{} ({saved})",
+            pending.hours.start, pending.hours.end
+        ));
+    }
+
+    /// Discards the pending work hours change
+    pub fn cancel_set_hours(&mut self) {
+        self.pending_hours = None;
+    }
+
     /// Adjusts the time forward by the specified minutes
     ///
     /// # Arguments
     ///
     /// * `minutes` - Number of minutes to move forward
     pub fn adjust_time_forward(&mut self, minutes: i64) {
-        self.time_offset += Duration::minutes(minutes);
+        self.shift_time(minutes.saturating_mul(60));
     }
 
     /// Adjusts the time backward by the specified minutes
@@ -140,7 +760,106 @@ impl App {
     ///
     /// * `minutes` - Number of minutes to move backward
     pub fn adjust_time_backward(&mut self, minutes: i64) {
-        self.time_offset -= Duration::minutes(minutes);
+        self.shift_time(minutes.saturating_mul(-60));
+    }
+
+    /// Moves the time forward by the current step
+    pub fn step_forward(&mut self) {
+        self.adjust_time_forward(self.time_step_minutes);
+    }
+
+    /// Moves the time backward by the current step
+    pub fn step_backward(&mut self) {
+        self.adjust_time_backward(self.time_step_minutes);
+    }
+
+    /// Switches to the next larger time step, if any
+    pub fn increase_time_step(&mut self) {
+        if let Some(step) = TIME_STEPS
+            .iter()
+            .find(|step| **step > self.time_step_minutes)
+        {
+            self.time_step_minutes = *step;
+        }
+    }
+
+    /// Switches to the next smaller time step, if any
+    pub fn decrease_time_step(&mut self) {
+        if let Some(step) = TIME_STEPS
+            .iter()
+            .rev()
+            .find(|step| **step < self.time_step_minutes)
+        {
+            self.time_step_minutes = *step;
+        }
+    }
+
+    /// Shifts the time offset by the given seconds, saturating at the
+    /// supported offset range instead of overflowing
+    fn shift_time(&mut self, seconds: i64) {
+        let offset = self.time_offset.num_seconds().saturating_add(seconds);
+        self.time_offset = Duration::seconds(clamp_time_offset(offset));
+    }
+
+    /// Sets the simulated time to the selected timezone's next work start
+    pub fn jump_to_next_work_start(&mut self) {
+        let target = self
+            .selected_timezone()
+            .and_then(|(_, tz)| next_work_start(self.current_time(), tz));
+        if let Some(target) = target {
+            self.jump_to(target);
+        }
+    }
+
+    /// Shows the work time left this week in the selected timezone
+    pub fn show_remaining_week_work(&mut self) {
+        let now = self.current_time();
+        let Some((_, tz)) = self.selected_timezone_at(now) else {
+            return;
+        };
+        self.status_message = Some(match remaining_week_work(now, tz) {
+            Some(remaining) => format!(
+                "{}: {} of work left this week",
+                tz.name,
+                format_hours_minutes(remaining.num_seconds())
+            ),
+            None => format!("{}: invalid timezone or work hours", tz.name),
+        });
+    }
+
+    /// Sets the simulated time so the reference zone reads a configured
+    /// quick-jump time on its current local date
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - Index into the configured quick-jump times
+    pub fn quick_jump(&mut self, index: usize) {
+        let Some(time) = self.config.quick_jumps().get(index).copied() else {
+            return;
+        };
+        let now = self.current_time();
+        let reference = self.reference_zone_at(now, self.selected_timezone().map(|(_, tz)| tz));
+        if let Some(target) = local_time_instant(now, &reference, time) {
+            self.jump_to(target);
+        }
+    }
+
+    /// Snaps the simulated time to the start or end of the reference zone's day
+    pub fn jump_to_day_boundary(&mut self, boundary: DayBoundary) {
+        let now = self.current_time();
+        let reference = self.reference_zone_at(now, self.selected_timezone().map(|(_, tz)| tz));
+        if let Some(seconds) = day_boundary_offset(now, &reference, boundary) {
+            self.jump_to(now + Duration::seconds(seconds));
+        }
+    }
+
+    /// Sets the time offset so that the simulated time reads `target`
+    ///
+    /// The offset is rounded up to whole seconds, as in the web app; see
+    /// [`longtime_core::offset_to_reach`].
+    fn jump_to(&mut self, target: DateTime<Utc>) {
+        let seconds = offset_to_reach(target, self.clock.now());
+        self.time_offset = Duration::seconds(clamp_time_offset(seconds));
     }
 
     /// Resets the time offset to zero
@@ -150,24 +869,92 @@ impl App {
 
     /// Moves the selection to the next timezone
     pub fn next(&mut self) {
+        let before = self.selected_timezone().map(|(index, _)| index);
         let len = self.timezone_count();
-        if len > 0 {
-            self.selected = (self.selected + 1) % len;
-        }
+        self.selected = if len > 0 {
+            (self.selected.min(len - 1) + 1) % len
+        } else {
+            0
+        };
+        self.remember_selection(before);
     }
 
     /// Moves the selection to the previous timezone
     pub fn previous(&mut self) {
+        let before = self.selected_timezone().map(|(index, _)| index);
         let len = self.timezone_count();
-        if len > 0 {
-            self.selected = (self.selected + len - 1) % len;
+        self.selected = if len > 0 {
+            (self.selected.min(len - 1) + len - 1) % len
+        } else {
+            0
+        };
+        self.remember_selection(before);
+    }
+
+    /// Swaps the selection with the previously selected zone
+    ///
+    /// Flips the reference of the diffs between the two most recently
+    /// selected zones.
+    pub fn swap_reference(&mut self) {
+        let Some(previous) = self.previous_selection else {
+            self.status_message = Some("No previous zone to swap to".to_string());
+            return;
+        };
+        let before = self.selected_timezone().map(|(index, _)| index);
+        match self
+            .get_filtered_timezones()
+            .iter()
+            .position(|(index, _)| *index == previous)
+        {
+            Some(position) => {
+                self.selected = position;
+                self.remember_selection(before);
+            }
+            None => self.status_message = Some("Previous zone is not listed".to_string()),
+        }
+    }
+
+    /// Remembers the zone selected before a selection change
+    ///
+    /// # Arguments
+    ///
+    /// * `before` - Config index of the zone selected before the change
+    fn remember_selection(&mut self, before: Option<usize>) {
+        let after = self.selected_timezone().map(|(index, _)| index);
+        if before.is_some() && before != after {
+            self.previous_selection = before;
+        }
+    }
+
+    /// Moves the selection back to the tracked zone after the list reordered
+    ///
+    /// Grouped by status, zones change position as they start or stop
+    /// working, so the selection follows the zone rather than the row.
+    /// Call [`App::track_selection`] after the selection is changed.
+    pub fn follow_selection(&mut self) {
+        if !self.group_by_status {
+            return;
+        }
+        if let Some(selected) = self.selected_zone
+            && let Some(position) = self
+                .get_filtered_timezones()
+                .iter()
+                .position(|(index, _)| *index == selected)
+        {
+            self.selected = position;
         }
     }
+
+    /// Records the selected zone for [`App::follow_selection`]
+    pub fn track_selection(&mut self) {
+        self.selected_zone = self.selected_timezone().map(|(index, _)| index);
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use longtime_core::WorkHours;
+    use chrono::TimeZone;
+    use longtime_core::{FixedClock, SavedOffset, WorkHours};
 
     use super::*;
 
@@ -180,7 +967,9 @@ mod tests {
                     work_hours: WorkHours {
                         start: "09:00".to_string(),
                         end: "17:00".to_string(),
+                        ..Default::default()
                     },
+                    ..Default::default()
                 },
                 TimezoneConfig {
                     name: "Test2".to_string(),
@@ -188,10 +977,12 @@ mod tests {
                     work_hours: WorkHours {
                         start: "09:00".to_string(),
                         end: "17:00".to_string(),
+                        ..Default::default()
                     },
+                    ..Default::default()
                 },
             ],
-            use_12h_format: false,
+            ..Default::default()
         }
     }
 
@@ -206,6 +997,42 @@ mod tests {
         assert!(app.search_query.is_empty());
         assert!(!app.is_searching);
         assert!(!app.use_12h_format);
+        assert!(app.status_message.is_none());
+    }
+
+    #[test]
+    fn test_current_time_uses_clock() {
+        let instant = Utc.with_ymd_and_hms(2024, 3, 1, 12, 0, 0).unwrap();
+        let mut app = App::with_clock(create_test_config(), Arc::new(FixedClock(instant)));
+        assert_eq!(app.current_time(), instant);
+
+        app.adjust_time_forward(90);
+        assert_eq!(app.current_time(), instant + Duration::minutes(90));
+
+        app.reset_time();
+        assert_eq!(app.current_time(), instant);
+    }
+
+    #[test]
+    fn test_toggle_diff() {
+        let mut config = create_test_config();
+        config.show_diff = false;
+        let mut app = App::new(config);
+        assert!(!app.show_diff);
+        app.toggle_diff();
+        assert!(app.show_diff);
+    }
+
+    #[test]
+    fn test_week_and_matrix_are_exclusive() {
+        let mut app = App::new(create_test_config());
+        app.toggle_matrix();
+        app.toggle_week();
+        assert!(app.show_week);
+        assert!(!app.show_matrix);
+        app.toggle_matrix();
+        assert!(!app.show_week);
+        assert!(app.show_matrix);
     }
 
     #[test]
@@ -222,6 +1049,63 @@ mod tests {
         assert_eq!(app.selected, 1); // Wraps around backward
     }
 
+    #[test]
+    fn test_marked_config() {
+        let mut config = create_test_config();
+        config.timezones.push(TimezoneConfig {
+            name: "Test3".to_string(),
+            timezone: "Asia/Tokyo".to_string(),
+            ..Default::default()
+        });
+        let mut app = App::new(config);
+        app.toggle_format();
+
+        app.toggle_marked();
+        app.next();
+        app.next();
+        app.toggle_marked();
+        app.next();
+        app.toggle_marked();
+        app.toggle_marked();
+
+        let marked = app.marked_config();
+        let names: Vec<_> = marked.timezones.iter().map(|tz| tz.name.as_str()).collect();
+        assert_eq!(names, ["Test1", "Test3"]);
+        assert!(marked.use_12h_format);
+
+        app.export_marked();
+        assert_eq!(
+            app.status_message.as_deref(),
+            Some("No config file to export next to")
+        );
+    }
+
+    #[test]
+    fn test_swap_reference() {
+        let mut config = create_test_config();
+        config.timezones.push(TimezoneConfig {
+            name: "Test3".to_string(),
+            timezone: "Asia/Tokyo".to_string(),
+            ..Default::default()
+        });
+        let mut app = App::new(config);
+
+        app.swap_reference();
+        assert_eq!(app.selected, 0);
+        assert!(app.status_message.is_some());
+
+        app.next();
+        app.next();
+        assert_eq!(app.selected, 2);
+        app.swap_reference();
+        assert_eq!(app.selected, 1);
+        // Swapping again returns to the prior reference
+        app.swap_reference();
+        assert_eq!(app.selected, 2);
+        app.swap_reference();
+        assert_eq!(app.selected, 1);
+    }
+
     #[test]
     fn test_time_adjustment() {
         let config = create_test_config();
@@ -237,6 +1121,254 @@ mod tests {
         assert_eq!(app.time_offset, Duration::zero());
     }
 
+    #[test]
+    fn test_time_step() {
+        let config = create_test_config();
+        let mut app = App::new(config);
+        assert_eq!(app.time_step_minutes, 15);
+
+        app.decrease_time_step();
+        app.decrease_time_step();
+        assert_eq!(app.time_step_minutes, 1);
+        app.decrease_time_step();
+        assert_eq!(app.time_step_minutes, 1);
+        app.step_forward();
+        assert_eq!(app.time_offset, Duration::minutes(1));
+
+        for _ in 0..5 {
+            app.increase_time_step();
+        }
+        assert_eq!(app.time_step_minutes, 60);
+        app.step_forward();
+        app.step_backward();
+        app.step_backward();
+        assert_eq!(app.time_offset, Duration::minutes(-59));
+
+        app.adjust_time_forward(app.time_step_minutes);
+        assert_eq!(app.time_offset, Duration::minutes(1));
+    }
+
+    #[test]
+    fn test_time_adjustment_clamped() {
+        let config = create_test_config();
+        let mut app = App::new(config);
+        let max = Duration::seconds(longtime_core::MAX_TIME_OFFSET_SECONDS);
+
+        app.adjust_time_forward(i64::MAX);
+        assert_eq!(app.time_offset, max);
+        app.adjust_time_forward(60);
+        assert_eq!(app.time_offset, max);
+
+        app.adjust_time_backward(i64::MAX);
+        assert_eq!(app.time_offset, -max);
+        app.adjust_time_backward(60);
+        assert_eq!(app.time_offset, -max);
+    }
+
+    #[test]
+    fn test_jump_to_next_work_start() {
+        let instant = Utc.with_ymd_and_hms(2024, 3, 1, 12, 0, 0).unwrap();
+        let mut app = App::with_clock(create_test_config(), Arc::new(FixedClock(instant)));
+
+        app.jump_to_next_work_start();
+        assert_eq!(
+            app.current_time(),
+            Utc.with_ymd_and_hms(2024, 3, 2, 9, 0, 0).unwrap()
+        );
+        assert_eq!(app.time_offset, Duration::hours(21));
+    }
+
+    #[test]
+    fn test_muted_timezones_excluded() {
+        let mut config = create_test_config();
+        config.timezones[0].enabled = false;
+        let mut app = App::new(config);
+
+        let filtered = app.get_filtered_timezones();
+        assert_eq!(filtered.len(), 1);
+        assert_eq!(filtered[0].1.name, "Test2");
+        assert_eq!(app.muted_count(), 1);
+
+        app.toggle_show_muted();
+        assert_eq!(app.timezone_count(), 2);
+
+        app.toggle_selected_enabled();
+        assert_eq!(app.muted_count(), 0);
+
+        app.toggle_show_muted();
+        app.selected = 1;
+        app.toggle_selected_enabled();
+        assert_eq!(app.timezone_count(), 1);
+        assert_eq!(app.selected, 0);
+    }
+
+    #[test]
+    fn test_sort_by_offset() {
+        let mut config = create_test_config();
+        config.timezones[0].timezone = "Asia/Tokyo".to_string();
+        let mut app = App::new(config);
+        assert_eq!(app.get_filtered_timezones()[0].1.name, "Test1");
+
+        app.toggle_sort_by_offset();
+        let filtered = app.get_filtered_timezones();
+        assert_eq!(filtered[0].1.name, "Test2");
+        assert_eq!(filtered[0].0, 1);
+        assert_eq!(filtered[1].1.name, "Test1");
+    }
+
+    #[test]
+    fn test_apply_saved_offset() {
+        let mut config = create_test_config();
+        config.saved_offsets = vec![
+            SavedOffset {
+                name: "India EOD".to_string(),
+                offset: "+8h".to_string(),
+            },
+            SavedOffset {
+                name: "Broken".to_string(),
+                offset: "later".to_string(),
+            },
+        ];
+        let mut app = App::new(config);
+        // Saved offsets are measured from now, not from the simulated time
+        app.shift_time(3600);
+
+        app.toggle_offsets();
+        app.apply_saved_offset(0);
+        assert!(!app.show_offsets);
+        assert_eq!(app.time_offset, Duration::hours(8));
+        assert_eq!(
+            app.status_message.as_deref(),
+            Some("Now at India EOD (+8h)")
+        );
+
+        app.apply_saved_offset(1);
+        assert_eq!(app.time_offset, Duration::hours(8));
+        assert!(app.status_message.unwrap().starts_with("Broken: "));
+    }
+
+    #[test]
+    fn test_apply_preset() {
+        let mut app = App::new(create_test_config());
+        app.toggle_presets();
+        app.apply_preset(0);
+
+        assert!(!app.show_presets);
+        assert_eq!(app.timezone_count(), 6);
+        assert_eq!(
+            app.status_message.as_deref(),
+            Some("Added 4 zones from US offices")
+        );
+
+        app.apply_preset(0);
+        assert_eq!(app.timezone_count(), 6);
+        app.apply_preset(PRESETS.len());
+        assert_eq!(app.timezone_count(), 6);
+    }
+
+    #[test]
+    fn test_show_remaining_week_work() {
+        // Saturday noon: 5h left today and 8h on Sunday
+        let instant = Utc.with_ymd_and_hms(2024, 3, 2, 12, 0, 0).unwrap();
+        let mut app = App::with_clock(create_test_config(), Arc::new(FixedClock(instant)));
+        app.show_remaining_week_work();
+        assert_eq!(
+            app.status_message.as_deref(),
+            Some("Test1: 13h 0m of work left this week")
+        );
+    }
+
+    #[test]
+    fn test_quick_jump_to_noon_in_reference() {
+        let instant = Utc.with_ymd_and_hms(2024, 3, 1, 0, 0, 0).unwrap();
+        let mut config = create_test_config();
+        config.timezones[0].timezone = "Asia/Tokyo".to_string();
+        let mut app = App::with_clock(config, Arc::new(FixedClock(instant)));
+
+        // Quick jumps default to 09:00, 12:00 and 17:00; Tokyo reads 09:00
+        app.quick_jump(1);
+        assert_eq!(app.time_offset, Duration::hours(3));
+
+        app.quick_jump(9);
+        assert_eq!(app.time_offset, Duration::hours(3));
+    }
+
+    #[test]
+    fn test_group_by_status_keeps_selection() {
+        // 21:00 in Tokyo, 13:00 in London
+        let instant = Utc.with_ymd_and_hms(2023, 6, 1, 12, 0, 0).unwrap();
+        let mut config = create_test_config();
+        config.timezones[0].timezone = "Asia/Tokyo".to_string();
+        config.timezones[1].timezone = "Europe/London".to_string();
+        let mut app = App::with_clock(config, Arc::new(FixedClock(instant)));
+        app.next();
+        assert_eq!(app.selected_timezone().map(|(index, _)| index), Some(1));
+
+        app.toggle_group_by_status();
+        let order: Vec<_> = app
+            .get_filtered_timezones()
+            .iter()
+            .map(|(index, _)| *index)
+            .collect();
+        assert_eq!(order, [1, 0]);
+        assert_eq!(app.selected, 0);
+        assert_eq!(app.selected_timezone().map(|(index, _)| index), Some(1));
+
+        // At midnight UTC Tokyo starts working and London stops, so London
+        // moves down a row and the selection follows it
+        app.track_selection();
+        app.time_offset = Duration::hours(12);
+        app.follow_selection();
+        assert_eq!(app.selected, 1);
+        assert_eq!(app.selected_timezone().map(|(index, _)| index), Some(1));
+    }
+
+    #[test]
+    fn test_utc_reference_lock() {
+        let instant = Utc.with_ymd_and_hms(2024, 3, 1, 0, 0, 0).unwrap();
+        let mut config = create_test_config();
+        config.timezones[0].timezone = "Asia/Tokyo".to_string();
+        config.home_timezone = Some("Asia/Tokyo".to_string());
+        let mut app = App::with_clock(config, Arc::new(FixedClock(instant)));
+        let selected = app.selected_timezone().map(|(_, tz)| tz.clone());
+        assert_eq!(
+            app.reference_offset_at(instant, selected.as_ref()),
+            9 * 3600
+        );
+
+        app.toggle_utc_reference();
+        assert_eq!(app.reference_offset_at(instant, selected.as_ref()), 0);
+        assert_eq!(app.diff_header(), "Diff (vs UTC)");
+
+        // Unlocking restores the home zone
+        app.toggle_utc_reference();
+        assert_eq!(
+            app.reference_offset_at(instant, selected.as_ref()),
+            9 * 3600
+        );
+        assert_eq!(app.diff_header(), "Diff (vs Test1)");
+    }
+
+    #[test]
+    fn test_jump_expression() {
+        let instant = Utc.with_ymd_and_hms(2024, 3, 1, 10, 0, 0).unwrap();
+        let mut app = App::with_clock(create_test_config(), Arc::new(FixedClock(instant)));
+
+        app.enter_jump();
+        for c in "tomorrow 9am".chars() {
+            app.append_jump(c);
+        }
+        app.submit_jump();
+        assert!(!app.is_jumping);
+        assert_eq!(app.time_offset, Duration::hours(23));
+
+        app.enter_jump();
+        app.append_jump('?');
+        app.submit_jump();
+        assert_eq!(app.time_offset, Duration::hours(23));
+        assert!(app.status_message.is_some());
+    }
+
     #[test]
     fn test_search() {
         let config = create_test_config();
@@ -258,4 +1390,98 @@ mod tests {
         app.clear_search();
         assert!(app.search_query.is_empty());
     }
+
+    #[test]
+    fn test_set_all_hours_after_confirmation() {
+        let mut app = App::new(create_test_config());
+        app.enter_set_hours();
+        for c in "10:00-18:00 from 09:00-17:00".chars() {
+            app.append_set_hours(c);
+        }
+        app.submit_set_hours();
+
+        // Nothing changes until confirmed
+        assert_eq!(app.pending_hours.as_ref().map(|p| p.zones), Some(2));
+        assert!(
+            app.config()
+                .timezones
+                .iter()
+                .all(|tz| tz.work_hours.start == "09:00")
+        );
+
+        app.confirm_set_hours();
+        assert!(app.pending_hours.is_none());
+        assert!(
+            app.config()
+                .timezones
+                .iter()
+                .all(|tz| tz.work_hours.start == "10:00" && tz.work_hours.end == "18:00")
+        );
+
+        app.enter_set_hours();
+        app.append_set_hours('x');
+        app.submit_set_hours();
+        assert!(app.pending_hours.is_none());
+        assert!(app.status_message.is_some());
+    }
+
+    #[test]
+    fn test_initial_filter() {
+        // As set up by `--filter test2`
+        let mut app = App::new(create_test_config());
+        app.search_query = "test2".to_string();
+
+        assert!(!app.is_searching);
+        let names: Vec<_> = app
+            .get_filtered_timezones()
+            .iter()
+            .map(|(_, tz)| tz.name.as_str())
+            .collect();
+        assert_eq!(names, ["Test2"]);
+    }
+
+    #[test]
+    fn test_search_without_matches() {
+        let mut app = App::new(create_test_config());
+        for c in "nowhere".chars() {
+            app.append_search(c);
+        }
+
+        assert_eq!(app.timezone_count(), 0);
+        assert!(app.selected_timezone().is_none());
+        app.next();
+        assert_eq!(app.selected, 0);
+        app.previous();
+        assert_eq!(app.selected, 0);
+        assert!(app.invite_text().is_none());
+
+        app.jump_to_next_work_start();
+        assert_eq!(app.time_offset, Duration::zero());
+        app.toggle_selected_enabled();
+        assert_eq!(app.muted_count(), 0);
+    }
+
+    #[test]
+    fn test_invite_text() {
+        let config = create_test_config();
+        let mut app = App::new(config);
+
+        let text = app.invite_text().unwrap();
+        assert!(text.starts_with("Let's meet at "));
+        assert!(text.contains("your time"));
+
+        // Nothing selected when the filter matches no timezone
+        app.append_search('x');
+        assert!(app.invite_text().is_none());
+
+        // Sent from the diff reference, here the home zone
+        let instant = Utc.with_ymd_and_hms(2023, 6, 1, 12, 0, 0).unwrap();
+        let mut config = create_test_config();
+        config.home_timezone = Some("Asia/Tokyo".to_string());
+        let app = App::with_clock(config, Arc::new(FixedClock(instant)));
+        assert_eq!(
+            app.invite_text().as_deref(),
+            Some("Let's meet at 12:00 your time (21:00 mine)")
+        );
+    }
 }
@@ -1,10 +1,59 @@
 //! Configuration file loading for the TUI application
 //!
-//! This module handles loading configuration from TOML files,
-//! while the core data structures are defined in `longtime-core`.
+//! This module handles loading configuration from TOML files and saving
+//! it back, while the core data structures are defined in `longtime-core`.
+
+use std::{
+    path::{Path, PathBuf},
+    str::FromStr,
+};
 
 use config::{Config as ConfigLoader, File};
-use longtime_core::Config;
+use longtime_core::{CONFIG_KEYS, Config, TIMEZONE_KEYS, WORK_HOURS_KEYS};
+use toml_edit::{DocumentMut, InlineTable, Item, Table, TableLike, Value};
+
+/// File format used when saving the configuration
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SaveFormat {
+    /// TOML, edited in place to keep comments and unknown keys
+    #[default]
+    Toml,
+    /// Pretty-printed JSON, written from scratch
+    Json,
+}
+
+impl FromStr for SaveFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "toml" => Ok(SaveFormat::Toml),
+            "json" => Ok(SaveFormat::Json),
+            _ => Err(format!(
+                "Unknown save format \"{s}\", expected toml or json"
+            )),
+        }
+    }
+}
+
+/// Resolve the config file path
+///
+/// # Arguments
+///
+/// * `config_path` - Optional path to the config file. If None, uses default location.
+///
+/// # Returns
+///
+/// * `Result<PathBuf, Box<dyn std::error::Error>>` - The path, or an error if the home directory is unknown
+pub fn config_file_path(config_path: Option<&str>) -> Result<PathBuf, Box<dyn std::error::Error>> {
+    match config_path {
+        Some(path) => Ok(PathBuf::from(path)),
+        None => {
+            let home = dirs::home_dir().ok_or("Could not find home directory")?;
+            Ok(home.join(".config").join("longtime").join("config.toml"))
+        }
+    }
+}
 
 /// Load configuration from a file path
 ///
@@ -26,10 +75,7 @@ pub fn load_config(config_path: Option<&str>) -> Result<Config, Box<dyn std::err
     let config_source = if let Some(path) = config_path {
         File::with_name(path)
     } else {
-        // Default path: ~/.config/longtime/config.toml
-        let home = dirs::home_dir().ok_or("Could not find home directory")?;
-        let default_path = home.join(".config").join("longtime").join("config.toml");
-        File::from(default_path)
+        File::from(config_file_path(None)?)
     };
 
     let config = builder.add_source(config_source).build()?;
@@ -38,6 +84,224 @@ pub fn load_config(config_path: Option<&str>) -> Result<Config, Box<dyn std::err
     Ok(app_config)
 }
 
+/// Save configuration to a file, keeping the user's edits intact
+///
+/// For TOML the existing file is edited in place: comments, formatting and
+/// keys that LongTime does not know about are preserved, and only the
+/// values of known settings are updated. JSON files are overwritten.
+///
+/// # Arguments
+///
+/// * `path` - The config file to write
+/// * `config` - The configuration to save
+/// * `format` - The file format to write
+///
+/// # Returns
+///
+/// * `Result<(), Box<dyn std::error::Error>>` - Ok on success, or an error
+pub fn save_config(
+    path: &Path,
+    config: &Config,
+    format: SaveFormat,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let updated = match format {
+        SaveFormat::Toml => {
+            let existing = match std::fs::read_to_string(path) {
+                Ok(text) => text,
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => String::new(),
+                Err(e) => return Err(e.into()),
+            };
+            update_document(&existing, config)?
+        }
+        SaveFormat::Json => serde_json::to_string_pretty(config)? + "\n",
+    };
+    write_file(path, &updated)
+}
+
+/// Write a configuration to a new file, replacing any existing one
+///
+/// # Arguments
+///
+/// * `path` - The file to write
+/// * `config` - The configuration to export
+/// * `format` - The file format to write
+///
+/// # Returns
+///
+/// * `Result<(), Box<dyn std::error::Error>>` - Ok on success, or an error
+pub fn export_config(
+    path: &Path,
+    config: &Config,
+    format: SaveFormat,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let text = match format {
+        SaveFormat::Toml => update_document("", config)?,
+        SaveFormat::Json => serde_json::to_string_pretty(config)? + "\n",
+    };
+    write_file(path, &text)
+}
+
+/// Get the file a save writes to
+///
+/// An explicit `save_path` is used as given. Otherwise TOML saves go back
+/// to the config file, while JSON saves go to a sibling with a `.json`
+/// extension so the TOML source is never overwritten with JSON.
+///
+/// # Arguments
+///
+/// * `config_path` - The config file that was read
+/// * `save_path` - The `--save-path` value, if any
+/// * `format` - The file format to write
+///
+/// # Returns
+///
+/// * `PathBuf` - The file to save to
+pub fn save_path(config_path: &Path, save_path: Option<&Path>, format: SaveFormat) -> PathBuf {
+    match (save_path, format) {
+        (Some(path), _) => path.to_path_buf(),
+        (None, SaveFormat::Toml) => config_path.to_path_buf(),
+        (None, SaveFormat::Json) => config_path.with_extension("json"),
+    }
+}
+
+/// Get the export file next to a config file, e.g. "config-selected.toml"
+pub fn export_path(config_path: &Path, format: SaveFormat) -> PathBuf {
+    let stem = config_path
+        .file_stem()
+        .and_then(|stem| stem.to_str())
+        .unwrap_or("config");
+    let extension = match format {
+        SaveFormat::Toml => "toml",
+        SaveFormat::Json => "json",
+    };
+    config_path.with_file_name(format!("{stem}-selected.{extension}"))
+}
+
+/// Write a file, creating its parent directories
+fn write_file(path: &Path, text: &str) -> Result<(), Box<dyn std::error::Error>> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(path, text)?;
+    Ok(())
+}
+
+/// Apply a configuration to the text of an existing TOML document
+///
+/// # Arguments
+///
+/// * `existing` - The current file contents (may be empty)
+/// * `config` - The configuration to write into it
+///
+/// # Returns
+///
+/// * `Result<String, Box<dyn std::error::Error>>` - The updated document text
+fn update_document(existing: &str, config: &Config) -> Result<String, Box<dyn std::error::Error>> {
+    let mut document: DocumentMut = existing.parse()?;
+    let fresh: DocumentMut = toml::to_string(config)?.parse()?;
+    remove_cleared_settings(document.as_table_mut(), fresh.as_table());
+    merge_table(document.as_table_mut(), fresh.as_table().clone());
+    Ok(document.to_string())
+}
+
+/// Remove the settings that a save leaves out from the existing document
+///
+/// Unset and default options are not serialized, so without this the
+/// merge would keep their old values, e.g. a zone that was unmuted would
+/// stay `enabled = false`. Keys LongTime does not know about are kept.
+fn remove_cleared_settings(old: &mut Table, new: &Table) {
+    remove_cleared_keys(old, new, CONFIG_KEYS);
+    let (Some(Item::ArrayOfTables(old_zones)), Some(Item::ArrayOfTables(new_zones))) =
+        (old.get_mut("timezones"), new.get("timezones"))
+    else {
+        return;
+    };
+    for (old_zone, new_zone) in old_zones.iter_mut().zip(new_zones.iter()) {
+        remove_cleared_keys(old_zone, new_zone, TIMEZONE_KEYS);
+        if let (Some(old_hours), Some(new_hours)) = (
+            old_zone
+                .get_mut("work_hours")
+                .and_then(Item::as_table_like_mut),
+            new_zone.get("work_hours").and_then(Item::as_table_like),
+        ) {
+            remove_cleared_keys(old_hours, new_hours, WORK_HOURS_KEYS);
+        }
+    }
+}
+
+/// Remove the `known` keys of `old` that are missing from `new`
+fn remove_cleared_keys(old: &mut dyn TableLike, new: &dyn TableLike, known: &[&str]) {
+    for key in known {
+        if !new.contains_key(key) {
+            old.remove(key);
+        }
+    }
+}
+
+/// Merge `new` into `old`, keeping keys that only exist in `old`
+fn merge_table(old: &mut Table, new: Table) {
+    for (key, item) in new {
+        match old.get_mut(&key) {
+            Some(existing) => merge_item(existing, item),
+            None => {
+                old.insert(&key, item);
+            }
+        }
+    }
+}
+
+/// Merge `new` into `old`, keeping keys that only exist in `old`
+fn merge_inline_table(old: &mut InlineTable, new: InlineTable) {
+    let mut inserted = false;
+    for (key, value) in new {
+        match old.get_mut(&key) {
+            Some(existing) => merge_value(existing, value),
+            None => {
+                old.insert(&key, value);
+                inserted = true;
+            }
+        }
+    }
+    // Re-space the table so the new keys line up with the old ones
+    if inserted {
+        old.fmt();
+    }
+}
+
+/// Replace a value, keeping the comments and spacing around the old one
+fn merge_value(old: &mut Value, mut new: Value) {
+    if let (Value::InlineTable(old), Value::InlineTable(new)) = (&mut *old, &new) {
+        merge_inline_table(old, new.clone());
+        return;
+    }
+    *new.decor_mut() = old.decor().clone();
+    *old = new;
+}
+
+/// Merge one item, matching arrays of tables by position
+fn merge_item(old: &mut Item, new: Item) {
+    match (old, new) {
+        (Item::Table(old), Item::Table(new)) => merge_table(old, new),
+        (Item::Value(Value::InlineTable(old)), Item::Table(new)) => {
+            merge_inline_table(old, new.into_inline_table());
+        }
+        (Item::Value(old), Item::Value(new)) => merge_value(old, new),
+        (Item::ArrayOfTables(old), Item::ArrayOfTables(new)) => {
+            let len = new.len();
+            for (index, table) in new.into_iter().enumerate() {
+                match old.get_mut(index) {
+                    Some(existing) => merge_table(existing, table),
+                    None => old.push(table),
+                }
+            }
+            while old.len() > len {
+                old.remove(old.len() - 1);
+            }
+        }
+        (old, new) => *old = new,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -47,4 +311,137 @@ mod tests {
         let result = load_config(Some("/nonexistent/path/config.toml"));
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_save_preserves_unknown_keys_and_comments() {
+        let path = std::env::temp_dir().join(format!("longtime-save-{}.toml", std::process::id()));
+        let original = r#"# My zones
+editor_theme = "dark"
+use_12h_format = false
+
+[[timezones]]
+name = "London" # the office
+timezone = "Europe/London"
+work_hours = { start = "09:00", end = "17:30" }
+"#;
+        std::fs::write(&path, original).unwrap();
+
+        let mut config = load_config(path.to_str()).unwrap();
+        config.use_12h_format = true;
+        config.timezones[0].enabled = false;
+        save_config(&path, &config, SaveFormat::Toml).unwrap();
+
+        let saved = std::fs::read_to_string(&path).unwrap();
+        let reloaded = load_config(path.to_str()).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert!(saved.contains("# My zones"));
+        assert!(saved.contains("editor_theme = \"dark\""));
+        assert!(saved.contains("# the office"));
+        assert!(saved.contains("work_hours = { start = \"09:00\", end = \"17:30\" }"));
+        assert_eq!(reloaded, config);
+    }
+
+    #[test]
+    fn test_save_removes_cleared_settings() {
+        let path =
+            std::env::temp_dir().join(format!("longtime-cleared-{}.toml", std::process::id()));
+        let original = r#"editor_theme = "dark"
+show_diff = false
+compact_12h = true
+
+[[timezones]]
+name = "London"
+timezone = "Europe/London"
+enabled = false
+holidays = ["2024-12-25"]
+work_hours = { start = "09:00", end = "17:30", end_of_minute = true }
+"#;
+        std::fs::write(&path, original).unwrap();
+
+        let mut config = load_config(path.to_str()).unwrap();
+        config.show_diff = true;
+        config.compact_12h = false;
+        config.timezones[0].enabled = true;
+        config.timezones[0].holidays.clear();
+        config.timezones[0].work_hours.end_of_minute = false;
+        save_config(&path, &config, SaveFormat::Toml).unwrap();
+
+        let saved = std::fs::read_to_string(&path).unwrap();
+        let reloaded = load_config(path.to_str()).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        for key in [
+            "show_diff",
+            "compact_12h",
+            "enabled",
+            "holidays",
+            "end_of_minute",
+        ] {
+            assert!(!saved.contains(key), "{key} in {saved}");
+        }
+        assert!(saved.contains("editor_theme = \"dark\""));
+        assert_eq!(reloaded, config);
+    }
+
+    #[test]
+    fn test_export_replaces_file() {
+        let config_path =
+            std::env::temp_dir().join(format!("longtime-{}.toml", std::process::id()));
+        let path = export_path(&config_path, SaveFormat::Toml);
+        assert_eq!(
+            path.file_name().and_then(|name| name.to_str()),
+            Some(format!("longtime-{}-selected.toml", std::process::id()).as_str())
+        );
+
+        std::fs::write(&path, "stale = true\n").unwrap();
+        let config = Config::default().with_zones(&[1]);
+        export_config(&path, &config, SaveFormat::Toml).unwrap();
+        let exported = std::fs::read_to_string(&path).unwrap();
+        let reloaded = load_config(path.to_str()).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert!(!exported.contains("stale"));
+        assert_eq!(reloaded, config);
+    }
+
+    #[test]
+    fn test_save_json_is_parseable() {
+        let path = std::env::temp_dir().join(format!("longtime-save-{}.json", std::process::id()));
+        let config = Config {
+            use_12h_format: true,
+            ..Config::default()
+        };
+        save_config(&path, &config, SaveFormat::Json).unwrap();
+
+        let saved = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        let parsed: Config = serde_json::from_str(&saved).unwrap();
+        assert_eq!(parsed, config);
+
+        assert_eq!("JSON".parse(), Ok(SaveFormat::Json));
+        assert!("yaml".parse::<SaveFormat>().is_err());
+    }
+
+    #[test]
+    fn test_save_path() {
+        let config_path = Path::new("/tmp/longtime/config.toml");
+        assert_eq!(
+            save_path(config_path, None, SaveFormat::Toml),
+            PathBuf::from("/tmp/longtime/config.toml")
+        );
+        // JSON never replaces the TOML source unless asked to
+        assert_eq!(
+            save_path(config_path, None, SaveFormat::Json),
+            PathBuf::from("/tmp/longtime/config.json")
+        );
+        assert_eq!(
+            save_path(config_path, Some(Path::new("out.json")), SaveFormat::Json),
+            PathBuf::from("out.json")
+        );
+        assert_eq!(
+            save_path(config_path, Some(Path::new("out.toml")), SaveFormat::Toml),
+            PathBuf::from("out.toml")
+        );
+    }
 }
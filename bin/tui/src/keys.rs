@@ -0,0 +1,251 @@
+//! Keyboard shortcut registry
+//!
+//! Lists the main-screen key bindings once, so the help overlay and the
+//! footer describe the same keys.
+
+use ratatui::{
+    style::{Color, Style},
+    text::{Line, Span},
+};
+
+use crate::app::App;
+
+/// How a key binding appears in the footer
+#[derive(Clone, Copy)]
+pub enum Footer {
+    /// Only listed in the help overlay
+    Never,
+    /// Always listed, with a short label
+    Always(&'static str),
+    /// Listed with a short label while the mode it toggles is on
+    WhileOn(&'static str, fn(&App) -> bool),
+    /// Always listed, with a label showing the current value
+    Value(fn(&App) -> String),
+}
+
+/// A documented key binding
+#[derive(Clone, Copy)]
+pub struct KeyBinding {
+    /// Keys as shown to the user, e.g. "←/→"
+    pub keys: &'static str,
+    /// Description for the help overlay
+    pub help: &'static str,
+    /// Footer appearance
+    pub footer: Footer,
+}
+
+impl KeyBinding {
+    const fn new(keys: &'static str, help: &'static str, footer: Footer) -> Self {
+        Self { keys, help, footer }
+    }
+}
+
+/// Key bindings of the main screen, in help overlay order
+pub const KEY_BINDINGS: &[KeyBinding] = &[
+    KeyBinding::new("↑/↓", "Navigate list", Footer::Always("Navigate")),
+    KeyBinding::new(
+        "←/→",
+        "Adjust time by the step",
+        Footer::Always("Adjust time"),
+    ),
+    KeyBinding::new(
+        "+/-",
+        "Change the time step (1/5/15/30/60m)",
+        Footer::Value(|app| format!("Step {}m", app.time_step_minutes)),
+    ),
+    KeyBinding::new("r", "Reset time to now", Footer::Always("Reset")),
+    KeyBinding::new(
+        "b",
+        "Swap back to the previously selected zone",
+        Footer::Never,
+    ),
+    KeyBinding::new(
+        "w",
+        "Jump to selected zone's next work start",
+        Footer::Never,
+    ),
+    KeyBinding::new(
+        "W",
+        "Show selected zone's work time left this week",
+        Footer::Never,
+    ),
+    KeyBinding::new(
+        "g",
+        "Jump to a time expression (+2h, 14:00, tomorrow 9am)",
+        Footer::Never,
+    ),
+    KeyBinding::new(
+        "1-9",
+        "Jump to a configured quick time in the reference zone",
+        Footer::Never,
+    ),
+    KeyBinding::new(
+        "n",
+        "Jump to a saved offset from now (saved_offsets)",
+        Footer::Never,
+    ),
+    KeyBinding::new(
+        "C",
+        "List each zone again later (+7d, or as set by --compare)",
+        Footer::WhileOn("Compare", |app| app.compare),
+    ),
+    KeyBinding::new(
+        "0 / e",
+        "Jump to 00:00 / 23:59 in the reference zone",
+        Footer::Never,
+    ),
+    KeyBinding::new("/", "Search/Filter timezones", Footer::Always("Search")),
+    KeyBinding::new(
+        "t",
+        "Toggle 12/24h format",
+        Footer::WhileOn("12h", |app| app.use_12h_format),
+    ),
+    KeyBinding::new("i", "Copy invite text for selected zone", Footer::Never),
+    KeyBinding::new("y", "Copy the selected zone's IANA id", Footer::Never),
+    KeyBinding::new(
+        "O",
+        "Copy pairwise work hour overlaps as Markdown",
+        Footer::Never,
+    ),
+    KeyBinding::new(
+        "d",
+        "Show/hide the diff column",
+        Footer::WhileOn("Diff hidden", |app| !app.show_diff),
+    ),
+    KeyBinding::new(
+        "p",
+        "Toggle work day progress column",
+        Footer::WhileOn("Progress", |app| app.show_progress),
+    ),
+    KeyBinding::new(
+        "o",
+        "Toggle sorting by UTC offset",
+        Footer::WhileOn("By offset", |app| app.sort_by_offset),
+    ),
+    KeyBinding::new("a", "Add a preset group of zones", Footer::Never),
+    KeyBinding::new(
+        "x",
+        "Toggle pairwise diff matrix",
+        Footer::WhileOn("Matrix", |app| app.show_matrix),
+    ),
+    KeyBinding::new(
+        "c",
+        "Toggle work hours on the reference clock",
+        Footer::WhileOn("Ref hours", |app| app.show_reference_hours),
+    ),
+    KeyBinding::new(
+        "f",
+        "Toggle focus mode (dim zones off work)",
+        Footer::WhileOn("Focus", |app| app.focus_mode),
+    ),
+    KeyBinding::new(
+        "G",
+        "Group working zones ahead of the others",
+        Footer::WhileOn("Grouped", |app| app.group_by_status),
+    ),
+    KeyBinding::new(
+        "U",
+        "Lock diffs to UTC / restore the reference zone",
+        Footer::WhileOn("UTC", |app| app.utc_reference),
+    ),
+    KeyBinding::new(
+        "v",
+        "Toggle 7-day availability at this time",
+        Footer::WhileOn("Week", |app| app.show_week),
+    ),
+    KeyBinding::new("m", "Mute/unmute selected zone", Footer::Never),
+    KeyBinding::new(
+        "M",
+        "Show/hide muted zones",
+        Footer::WhileOn("Muted shown", |app| app.show_muted),
+    ),
+    KeyBinding::new("H", "Set work hours of all zones", Footer::Never),
+    KeyBinding::new("S", "Save config (keeps comments)", Footer::Never),
+    KeyBinding::new(
+        "Space",
+        "Mark/unmark selected zone for export",
+        Footer::Never,
+    ),
+    KeyBinding::new(
+        "E",
+        "Export marked zones to <config>-selected file",
+        Footer::Never,
+    ),
+    KeyBinding::new("?", "Toggle this help", Footer::Always("Help")),
+    KeyBinding::new("q", "Quit", Footer::Always("Quit")),
+    KeyBinding::new("Esc", "Close help / Clear search", Footer::Never),
+];
+
+/// Builds the help overlay lines for the given bindings
+pub fn help_lines(bindings: &[KeyBinding]) -> Vec<Line<'static>> {
+    bindings
+        .iter()
+        .map(|binding| {
+            Line::from(vec![
+                Span::styled(binding.keys, Style::default().fg(Color::Yellow)),
+                Span::raw(format!(": {}", binding.help)),
+            ])
+        })
+        .collect()
+}
+
+/// Builds the footer line for the given bindings
+///
+/// Bindings listed `Always` or with a `Value` come first; bindings whose mode is on follow
+/// with an "on" marker, so the footer shows which modes are active.
+///
+/// # Arguments
+///
+/// * `app` - Application state, for the modes
+/// * `bindings` - The bindings to list
+pub fn footer_line(app: &App, bindings: &[KeyBinding]) -> Line<'static> {
+    let always = bindings.iter().filter_map(|binding| match binding.footer {
+        Footer::Always(label) => Some((binding.keys, label.to_string())),
+        Footer::Value(label) => Some((binding.keys, label(app))),
+        _ => None,
+    });
+    let active = bindings.iter().filter_map(|binding| match binding.footer {
+        Footer::WhileOn(label, is_on) if is_on(app) => Some((binding.keys, format!("{label} on"))),
+        _ => None,
+    });
+
+    let mut spans = Vec::new();
+    for (keys, label) in always.chain(active) {
+        if !spans.is_empty() {
+            spans.push(Span::raw(" | "));
+        }
+        spans.push(Span::styled(keys, Style::default().fg(Color::Yellow)));
+        spans.push(Span::raw(format!(" {label}")));
+    }
+    Line::from(spans)
+}
+
+#[cfg(test)]
+mod tests {
+    use longtime_core::Config;
+
+    use super::*;
+
+    #[test]
+    fn test_footer_lists_registered_bindings() {
+        let mut app = App::new(Config::default());
+        let footer = footer_line(&app, KEY_BINDINGS).to_string();
+        assert!(
+            footer.starts_with("↑/↓ Navigate | ←/→ Adjust time | +/- Step 15m"),
+            "{footer}"
+        );
+        assert!(footer.ends_with("q Quit"), "{footer}");
+        assert!(!footer.contains("Focus"), "{footer}");
+
+        // Modes show up while they are on
+        app.toggle_focus_mode();
+        let footer = footer_line(&app, KEY_BINDINGS).to_string();
+        assert!(footer.ends_with("q Quit | f Focus on"), "{footer}");
+
+        let mut bindings = KEY_BINDINGS.to_vec();
+        bindings.push(KeyBinding::new("z", "Zoom", Footer::Always("Zoom")));
+        let footer = footer_line(&app, &bindings).to_string();
+        assert!(footer.contains("z Zoom"), "{footer}");
+        assert_eq!(help_lines(&bindings).last().unwrap().to_string(), "z: Zoom");
+    }
+}
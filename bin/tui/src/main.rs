@@ -12,9 +12,9 @@
 //! - Time adjustment simulation
 //! - Configuration via TOML file
 
-use std::{error::Error, io};
+use std::{error::Error, io, path::PathBuf};
 
-use clap::{Arg, Command};
+use clap::{Arg, ArgAction, Command};
 use crossterm::{
     event::{DisableMouseCapture, EnableMouseCapture},
     execute,
@@ -24,10 +24,11 @@ use ratatui::{Terminal, backend::CrosstermBackend};
 
 mod app;
 mod config_loader;
+mod keys;
 mod ui;
 
 use app::App;
-use config_loader::load_config;
+use config_loader::{SaveFormat, config_file_path, load_config, save_path};
 
 /// The main entry point for the LongTime application
 ///
@@ -45,6 +46,55 @@ fn main() -> Result<(), Box<dyn Error>> {
                 .value_name("FILE")
                 .help("Sets a custom config file path (default: ~/.config/longtime/config.toml)"),
         )
+        .arg(
+            Arg::new("filter")
+                .long("filter")
+                .value_name("QUERY")
+                .help("Starts with the list filtered by name or timezone"),
+        )
+        .arg(
+            Arg::new("save-path")
+                .long("save-path")
+                .value_name("FILE")
+                .help("Saves to this file instead of the config file"),
+        )
+        .arg(
+            Arg::new("save-format")
+                .long("save-format")
+                .value_name("FORMAT")
+                .value_parser(str::parse::<SaveFormat>)
+                .help("Format used when saving: toml (default) or json"),
+        )
+        .arg(
+            Arg::new("print")
+                .long("print")
+                .action(ArgAction::SetTrue)
+                .help("Prints the current times and exits instead of starting the interface"),
+        )
+        .arg(
+            Arg::new("json")
+                .long("json")
+                .action(ArgAction::SetTrue)
+                .conflicts_with("print")
+                .help("Prints the current times as JSON and exits"),
+        )
+        .arg(
+            Arg::new("at")
+                .long("at")
+                .value_name("EXPR")
+                .allow_hyphen_values(true)
+                .help("Starts at a simulated time, e.g. \"+2h\" or \"tomorrow 9am\""),
+        )
+        .arg(
+            Arg::new("compare")
+                .long("compare")
+                .value_name("EXPR")
+                .allow_hyphen_values(true)
+                .help(
+                    "Also lists each zone at a second time, e.g. \"+7d\" for next week; \
+                     kept as a distance from the shown time",
+                ),
+        )
         .get_matches();
 
     // Get the config file path from the command line arguments
@@ -58,6 +108,51 @@ fn main() -> Result<(), Box<dyn Error>> {
         }
     };
 
+    // Create app state
+    let mut app = App::new(config);
+    if let Some(format) = matches.get_one::<SaveFormat>("save-format") {
+        app.save_format = *format;
+    }
+    app.config_path = match matches.get_one::<String>("save-path") {
+        Some(path) => Some(PathBuf::from(path)),
+        None => config_file_path(config_path)
+            .ok()
+            .map(|path| save_path(&path, None, app.save_format)),
+    };
+    if let Some(query) = matches.get_one::<String>("filter") {
+        app.search_query = query.clone();
+    }
+
+    if let Some(expr) = matches.get_one::<String>("at") {
+        app.apply_time_expression(expr)
+            .map_err(|message| format!("Invalid --at: {message}"))?;
+    }
+    if let Some(expr) = matches.get_one::<String>("compare") {
+        app.set_compare(expr)
+            .map_err(|message| format!("Invalid --compare: {message}"))?;
+    }
+
+    // Misconfigured zones still load; point them out instead of failing
+    let problems = app.config().validate().err().unwrap_or_default();
+
+    if matches.get_flag("print") || matches.get_flag("json") {
+        for problem in &problems {
+            eprintln!("Warning: {problem}");
+        }
+        if matches.get_flag("json") {
+            println!("{}", ui::json_output(&app));
+        } else {
+            for line in ui::print_lines(&app) {
+                println!("{line}");
+            }
+        }
+        return Ok(());
+    }
+
+    if !problems.is_empty() {
+        app.status_message = Some(format!("Config problems: {}", problems.join("; ")));
+    }
+
     // Setup terminal
     enable_raw_mode()?;
     let mut stdout = io::stdout();
@@ -65,9 +160,6 @@ fn main() -> Result<(), Box<dyn Error>> {
     let backend = CrosstermBackend::new(stdout);
     let mut terminal = Terminal::new(backend)?;
 
-    // Create app state
-    let app = App::new(config);
-
     // Run the main loop
     let res = ui::run_app(&mut terminal, app);
 
@@ -3,22 +3,35 @@
 //! This module contains all UI-related functionality including
 //! rendering the terminal interface and handling user input events.
 
-use std::{io, str::FromStr, time::Duration};
+use std::{io, time::Duration};
 
-use chrono::Offset;
-use chrono_tz::Tz;
-use crossterm::event::{self, Event, KeyCode};
-use longtime_core::is_work_hours;
+use chrono::{DateTime, NaiveDate, SecondsFormat, Utc};
+use crossterm::{
+    clipboard::CopyToClipboard,
+    event::{self, Event, KeyCode},
+    execute,
+};
+use longtime_core::{
+    Config, DayBoundary, DisplayOptions, OffReason, PLANNING_DAYS, PRESETS, TimeDisplayInfo,
+    TimezoneConfig, WorkStatus, compare_display_info, configured_zone, day_offset,
+    distinct_offsets, format_day_offset, format_time_diff, get_time_display_info, is_work_hours,
+    offset_matrix, week_availability, work_hours_in_reference, work_progress, work_statuses,
+    zone_local_time, zone_offset,
+};
 use ratatui::{
     Frame, Terminal,
     backend::Backend,
-    layout::{Constraint, Direction, Layout, Rect},
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
     text::{Line, Span, Text},
     widgets::{Block, Borders, Cell, Clear, Paragraph, Row, Table},
 };
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
 
-use crate::app::App;
+use crate::{
+    app::App,
+    keys::{KEY_BINDINGS, footer_line, help_lines},
+};
 
 /// Runs the application's main loop
 ///
@@ -37,7 +50,9 @@ where
     let tick_rate = Duration::from_millis(100);
     let mut last_tick = std::time::Instant::now();
 
+    app.track_selection();
     loop {
+        app.follow_selection();
         terminal.draw(|f| ui(f, &app))?;
 
         let timeout = tick_rate
@@ -47,24 +62,115 @@ where
         if event::poll(timeout)?
             && let Event::Key(key) = event::read()?
         {
-            if app.is_searching {
+            app.status_message = None;
+
+            if app.pending_hours.is_some() {
+                match key.code {
+                    KeyCode::Char('y') => app.confirm_set_hours(),
+                    _ => app.cancel_set_hours(),
+                }
+            } else if app.is_setting_hours {
+                match key.code {
+                    KeyCode::Esc => app.exit_set_hours(),
+                    KeyCode::Enter => app.submit_set_hours(),
+                    KeyCode::Backspace => app.backspace_set_hours(),
+                    KeyCode::Char(c) => app.append_set_hours(c),
+                    _ => {}
+                }
+            } else if app.is_jumping {
+                match key.code {
+                    KeyCode::Esc => app.exit_jump(),
+                    KeyCode::Enter => app.submit_jump(),
+                    KeyCode::Backspace => app.backspace_jump(),
+                    KeyCode::Char(c) => app.append_jump(c),
+                    _ => {}
+                }
+            } else if app.is_searching {
                 match key.code {
                     KeyCode::Esc | KeyCode::Enter => app.exit_search(),
                     KeyCode::Backspace => app.backspace_search(),
                     KeyCode::Char(c) => app.append_search(c),
                     _ => {}
                 }
+            } else if app.show_offsets {
+                match key.code {
+                    KeyCode::Esc | KeyCode::Char('n') => app.toggle_offsets(),
+                    KeyCode::Char(c) => {
+                        if let Some(digit) = c.to_digit(10).filter(|d| *d > 0) {
+                            app.apply_saved_offset(digit as usize - 1);
+                        }
+                    }
+                    _ => {}
+                }
+            } else if app.show_presets {
+                match key.code {
+                    KeyCode::Esc | KeyCode::Char('a') => app.toggle_presets(),
+                    KeyCode::Char(c) => {
+                        if let Some(digit) = c.to_digit(10).filter(|d| *d > 0) {
+                            app.apply_preset(digit as usize - 1);
+                        }
+                    }
+                    _ => {}
+                }
             } else {
                 match key.code {
                     KeyCode::Char('q') => return Ok(()),
                     KeyCode::Up => app.previous(),
                     KeyCode::Down => app.next(),
-                    KeyCode::Right => app.adjust_time_forward(15),
-                    KeyCode::Left => app.adjust_time_backward(15),
+                    KeyCode::Right => app.step_forward(),
+                    KeyCode::Left => app.step_backward(),
+                    KeyCode::Char('+') => app.increase_time_step(),
+                    KeyCode::Char('-') => app.decrease_time_step(),
                     KeyCode::Char('r') => app.reset_time(),
+                    KeyCode::Char('b') => app.swap_reference(),
+                    KeyCode::Char('w') => app.jump_to_next_work_start(),
+                    KeyCode::Char('W') => app.show_remaining_week_work(),
+                    KeyCode::Char('g') => app.enter_jump(),
+                    KeyCode::Char('n') => app.toggle_offsets(),
+                    KeyCode::Char('C') => app.toggle_compare(),
                     KeyCode::Char('?') => app.toggle_help(),
                     KeyCode::Char('/') => app.enter_search(),
                     KeyCode::Char('t') => app.toggle_format(),
+                    KeyCode::Char('p') => app.toggle_progress(),
+                    KeyCode::Char('d') => app.toggle_diff(),
+                    KeyCode::Char('o') => app.toggle_sort_by_offset(),
+                    KeyCode::Char('m') => app.toggle_selected_enabled(),
+                    KeyCode::Char('S') => app.save_config(),
+                    KeyCode::Char(' ') => app.toggle_marked(),
+                    KeyCode::Char('E') => app.export_marked(),
+                    KeyCode::Char('v') => app.toggle_week(),
+                    KeyCode::Char('f') => app.toggle_focus_mode(),
+                    KeyCode::Char('G') => app.toggle_group_by_status(),
+                    KeyCode::Char('U') => app.toggle_utc_reference(),
+                    KeyCode::Char('c') => app.toggle_reference_hours(),
+                    KeyCode::Char('M') => app.toggle_show_muted(),
+                    KeyCode::Char('H') => app.enter_set_hours(),
+                    KeyCode::Char('a') => app.toggle_presets(),
+                    KeyCode::Char('x') => app.toggle_matrix(),
+                    KeyCode::Char('0') => app.jump_to_day_boundary(DayBoundary::Start),
+                    KeyCode::Char('e') => app.jump_to_day_boundary(DayBoundary::End),
+                    KeyCode::Char(c @ '1'..='9') => {
+                        app.quick_jump(c as usize - '1' as usize);
+                    }
+                    KeyCode::Char('i') => {
+                        if let Some(text) = app.invite_text() {
+                            execute!(io::stdout(), CopyToClipboard::to_clipboard_from(text))?;
+                            app.status_message = Some("Invite text copied".to_string());
+                        }
+                    }
+                    KeyCode::Char('O') => {
+                        if let Some(report) = app.overlap_report() {
+                            execute!(io::stdout(), CopyToClipboard::to_clipboard_from(report))?;
+                            app.status_message = Some("Overlap report copied".to_string());
+                        }
+                    }
+                    KeyCode::Char('y') => {
+                        if let Some((_, tz)) = app.selected_timezone() {
+                            let id = tz.timezone.clone();
+                            execute!(io::stdout(), CopyToClipboard::to_clipboard_from(&id))?;
+                            app.status_message = Some(format!("Copied {id}"));
+                        }
+                    }
                     KeyCode::Esc => {
                         if app.show_help {
                             app.toggle_help();
@@ -75,6 +181,7 @@ where
                     _ => {}
                 }
             }
+            app.track_selection();
         }
 
         if last_tick.elapsed() >= tick_rate {
@@ -90,8 +197,14 @@ where
 /// * `f` - Frame to render to
 /// * `app` - Application state with timezone data
 fn ui(f: &mut Frame, app: &App) {
+    // One instant for the whole frame, so the title and views agree
+    let now = app.current_time();
+
     // Define layout
-    let constraints = if app.is_searching || !app.search_query.is_empty() {
+    let setting_hours = app.is_setting_hours || app.pending_hours.is_some();
+    let show_input =
+        setting_hours || app.is_jumping || app.is_searching || !app.search_query.is_empty();
+    let constraints = if show_input {
         vec![
             Constraint::Length(3), // Title
             Constraint::Length(3), // Search
@@ -112,23 +225,43 @@ fn ui(f: &mut Frame, app: &App) {
         .constraints(constraints)
         .split(f.area());
 
-    render_title(f, chunks[0]);
+    render_title(f, app, now, chunks[0]);
 
-    let list_area = if app.is_searching || !app.search_query.is_empty() {
-        render_search(f, app, chunks[1]);
+    let list_area = if show_input {
+        if setting_hours {
+            render_set_hours(f, app, chunks[1]);
+        } else if app.is_jumping {
+            render_jump(f, app, chunks[1]);
+        } else {
+            render_search(f, app, chunks[1]);
+        }
         chunks[2]
     } else {
         chunks[1]
     };
 
-    render_timezones(f, app, list_area);
+    if app.show_matrix {
+        render_matrix(f, app, now, list_area);
+    } else if app.show_week {
+        render_week(f, app, now, list_area);
+    } else {
+        render_timezones(f, app, now, list_area);
+    }
 
     // Footer is always the last chunk
-    render_footer(f, *chunks.last().expect("Footer chunk should exist"));
+    render_footer(f, app, *chunks.last().expect("Footer chunk should exist"));
 
     if app.show_help {
         render_help(f);
     }
+
+    if app.show_presets {
+        render_presets(f);
+    }
+
+    if app.show_offsets {
+        render_offsets(f, app);
+    }
 }
 
 /// Renders the application title
@@ -136,10 +269,12 @@ fn ui(f: &mut Frame, app: &App) {
 /// # Arguments
 ///
 /// * `f` - Frame to render to
+/// * `app` - Application state holding the configured title
+/// * `now` - Frame instant shown by the anchor clock
 /// * `area` - Area to render in
-fn render_title(f: &mut Frame, area: Rect) {
+fn render_title(f: &mut Frame, app: &App, now: DateTime<Utc>, area: Rect) {
     let title = Paragraph::new(Text::styled(
-        "LongTime - Multi-timezone Time Manager",
+        title_text(app.config(), now, app.use_12h_format),
         Style::default()
             .fg(Color::Yellow)
             .add_modifier(Modifier::BOLD),
@@ -148,117 +283,624 @@ fn render_title(f: &mut Frame, area: Rect) {
     f.render_widget(title, area);
 }
 
+/// Draws a text gauge such as "███░░░░░" for a fraction from 0.0 to 1.0
+///
+/// # Arguments
+///
+/// * `fraction` - How much of the gauge is filled
+/// * `width` - Number of characters
+fn gauge(fraction: f64, width: usize) -> String {
+    let filled = ((fraction.clamp(0.0, 1.0) * width as f64).round() as usize).min(width);
+    format!("{}{}", "█".repeat(filled), "░".repeat(width - filled))
+}
+
+/// Returns the title bar text, e.g. "LongTime - Multi-timezone Time Manager"
+///
+/// With `title_timezone` set, the subtitle is replaced by that zone's
+/// current time, e.g. "LongTime - 17:03 Shanghai".
+///
+/// # Arguments
+///
+/// * `config` - Configuration holding the title and anchor zone
+/// * `now` - Current (simulated) UTC time
+/// * `use_12h_format` - Whether to show the anchor time on a 12-hour clock
+fn title_text(config: &Config, now: DateTime<Utc>, use_12h_format: bool) -> String {
+    let anchor = config
+        .title_timezone
+        .as_deref()
+        .and_then(|tz| configured_zone(config, tz))
+        .and_then(|zone| {
+            let local_time = zone_local_time(now, &zone)?;
+            let options = DisplayOptions {
+                use_12h_format,
+                ..DisplayOptions::from_config(config)
+            };
+            Some(format!(
+                "{} {}",
+                options.format_time(&local_time),
+                zone.name
+            ))
+        });
+    match anchor {
+        Some(anchor) => format!("{} - {anchor}", config.display_title()),
+        None => format!("{} - Multi-timezone Time Manager", config.display_title()),
+    }
+}
+
 /// Renders the timezone list
 ///
 /// # Arguments
 ///
 /// * `f` - Frame to render to
 /// * `app` - Application state with timezone data
+/// * `now` - Frame instant that order, offsets and times are computed at
 /// * `area` - Area to render in
-fn render_timezones(f: &mut Frame, app: &App, area: Rect) {
-    let header_cells = ["Name", "Time", "Diff", "Date", "Status"]
-        .iter()
-        .map(|h| Cell::from(*h).style(Style::default().fg(Color::Yellow)));
+fn render_timezones(f: &mut Frame, app: &App, now: DateTime<Utc>, area: Rect) {
+    let diff_header = app.diff_header();
+    let mut headers = vec!["Name", "Time"];
+    if app.show_diff {
+        headers.push(diff_header.as_str());
+    }
+    headers.push("Date");
+    let show_day_offset = app.config().show_day_offset;
+    if show_day_offset {
+        headers.push("Day");
+    }
+    headers.push("Status");
+    if app.show_progress {
+        headers.push("Work Day");
+    }
+    let hours_header = format!("Hours in {}", app.reference_name_at(now));
+    if app.show_reference_hours {
+        headers.push(hours_header.as_str());
+    }
+    let header_cells = headers
+        .into_iter()
+        .map(|h| Cell::from(h).style(Style::default().fg(Color::Yellow)));
     let header = Row::new(header_cells)
         .style(Style::default().add_modifier(Modifier::BOLD))
         .height(1)
         .bottom_margin(1);
 
-    let filtered_timezones = app.get_filtered_timezones();
-    let now = app.current_time();
+    let filtered_timezones = app.filtered_timezones_at(now);
 
-    // Calculate offset of the selected timezone to show relative difference
-    let selected_tz_offset = if !filtered_timezones.is_empty() {
-        let idx = app.selected % filtered_timezones.len();
-        let (_, selected_tz_config) = filtered_timezones[idx];
-        if let Ok(tz) = Tz::from_str(&selected_tz_config.timezone) {
-            now.with_timezone(&tz).offset().fix().local_minus_utc()
+    if filtered_timezones.is_empty() {
+        let message = if app.search_query.is_empty() {
+            "No timezones to show"
         } else {
-            0
-        }
-    } else {
-        0
-    };
+            "No matches"
+        };
+        let empty = Paragraph::new(Span::styled(message, Style::default().fg(Color::DarkGray)))
+            .alignment(Alignment::Center)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(timezones_title(0, 0, 0, app.muted_count())),
+            );
+        f.render_widget(empty, area);
+        return;
+    }
 
-    let rows = filtered_timezones
+    // Diffs are relative to the home zone, or the selected zone without one
+    let selected = filtered_timezones.get(app.selected).map(|(_, tz)| *tz);
+    let selected_tz_offset = app.reference_offset_at(now, selected);
+
+    let widths = column_widths(
+        app.show_diff,
+        show_day_offset,
+        app.show_progress,
+        app.show_reference_hours,
+    );
+    let name_width = name_column_width(area, &widths);
+    // Full name of the selected zone when its cell is cut short
+    let mut selected_full_name = None;
+
+    let options = app.display_options();
+    let statuses = work_statuses(now, &app.config().timezones);
+    let working = filtered_timezones
         .iter()
-        .enumerate()
-        .map(|(i, (_, tz_config))| {
-            let (time_str, diff_str, date_str, status_str, status_style) =
-                if let Ok(tz) = Tz::from_str(&tz_config.timezone) {
-                    let local_time = now.with_timezone(&tz);
-
-                    let time_format = if app.use_12h_format {
-                        "%I:%M %p"
-                    } else {
-                        "%H:%M"
-                    };
-                    let time_s = local_time.format(time_format).to_string();
-                    let date_s = local_time.format("%Y-%m-%d").to_string();
-
-                    let current_offset = local_time.offset().fix().local_minus_utc();
-                    let diff_seconds = current_offset - selected_tz_offset;
-                    let diff_hours = diff_seconds as f64 / 3600.0;
-                    let diff_s = if diff_hours == 0.0 {
-                        "=".to_string()
-                    } else if diff_hours > 0.0 {
-                        format!("+{diff_hours}")
-                    } else {
-                        format!("{diff_hours}")
-                    };
-                    let is_working = is_work_hours(now, tz_config);
-                    let (status, style) = if is_working {
-                        ("WORKING", Style::default().fg(Color::Green))
-                    } else {
-                        ("OFF", Style::default().fg(Color::Red))
-                    };
-                    (time_s, diff_s, date_s, status, style)
-                } else {
-                    (
-                        "Error".to_string(),
-                        "".to_string(),
-                        "".to_string(),
-                        "Invalid TZ",
-                        Style::default().fg(Color::Red),
-                    )
-                };
-
-            let style = if i == app.selected {
-                Style::default().add_modifier(Modifier::REVERSED)
+        .filter(|(index, _)| statuses[*index])
+        .count();
+    let mut rows = Vec::with_capacity(filtered_timezones.len());
+    let mut previous_date = None;
+    for (i, (index, tz_config)) in filtered_timezones.iter().enumerate() {
+        // Head the working and off groups, which are listed in that order
+        if app.group_by_status && (i == 0 || i == working) {
+            let label = if i < working {
+                format!("Working ({working})")
             } else {
-                Style::default()
+                format!("Off ({})", filtered_timezones.len() - working)
             };
+            rows.push(separator_row(label));
+            previous_date = None;
+        }
+
+        // Mark where the local date flips between neighbouring zones
+        let date = zone_local_time(now, tz_config).map(|local_time| local_time.date_naive());
+        if app.sort_by_offset
+            && let (Some(previous), Some(date)) = (previous_date, date)
+            && previous != date
+        {
+            rows.push(date_line_row(date, options));
+        }
+        previous_date = date.or(previous_date);
+
+        let info = get_time_display_info(now, tz_config, selected_tz_offset, options);
+
+        // Muted zones, and in focus mode zones outside work hours, are dimmed
+        let dimmed = !tz_config.enabled || (app.focus_mode && !statuses[*index]);
+        let style = if dimmed {
+            Style::default().fg(Color::DarkGray)
+        } else {
+            Style::default()
+        };
+        let style = if i == app.selected {
+            style.add_modifier(Modifier::REVERSED)
+        } else {
+            style
+        };
+
+        let mut name = if app.marked.contains(index) {
+            format!("* {}", tz_config.name)
+        } else {
+            tz_config.name.clone()
+        };
+        if tz_config.offset_override.is_some() {
+            name.push_str(" (override)");
+        }
+        if tz_config.always_working {
+            name.push_str(" [24/7]");
+        }
+        let shown_name = truncate_to_width(&name, name_width);
+        if i == app.selected && shown_name != name {
+            selected_full_name = Some(name);
+        }
+        let mut cells = vec![Cell::from(shown_name)];
+        cells.extend(clock_cells(info.as_ref(), app.show_diff, options));
+        if show_day_offset {
+            // Ahead of the status cell
+            cells.insert(
+                cells.len() - 1,
+                day_cell(now, tz_config, selected_tz_offset),
+            );
+        }
+        if app.show_progress {
+            let progress = work_progress(now, tz_config)
+                .map(|progress| format!("{} {}", gauge(progress.fraction(), 8), progress.label()));
+            cells.push(Cell::from(progress.unwrap_or_default()));
+        }
+        if app.show_reference_hours {
+            let hours = work_hours_in_reference(now, tz_config, selected_tz_offset);
+            cells.push(Cell::from(hours.map(|h| h.label()).unwrap_or_default()));
+        }
+        rows.push(Row::new(cells).style(style).height(1));
 
-            let cells = vec![
-                Cell::from(tz_config.name.clone()),
-                Cell::from(time_str),
-                Cell::from(diff_str),
-                Cell::from(date_str),
-                Cell::from(status_str).style(status_style),
-            ];
-            Row::new(cells).style(style).height(1)
+        // The same zone at the compared instant, below its own row
+        if app.compare {
+            let later = compare_display_info(
+                now,
+                app.compare_delta.num_seconds(),
+                tz_config,
+                |at| app.reference_offset_at(at, selected),
+                options,
+            );
+            let mut cells = vec![Cell::from(format!("  ↳ {}", app.compare_label()))];
+            cells.extend(clock_cells(later.as_ref(), app.show_diff, options));
+            if show_day_offset {
+                let day = now
+                    .checked_add_signed(app.compare_delta)
+                    .map(|at| day_cell(at, tz_config, app.reference_offset_at(at, selected)))
+                    .unwrap_or_default();
+                cells.insert(cells.len() - 1, day);
+            }
+            rows.push(Row::new(cells).style(style).height(1));
+        }
+    }
+
+    let listed = || filtered_timezones.iter().map(|(_, tz)| *tz);
+    let invalid = listed().filter(|tz| zone_offset(now, tz).is_none()).count();
+    let offsets = distinct_offsets(now, listed()).len();
+    let mut block = Block::default()
+        .borders(Borders::ALL)
+        .title(timezones_title(
+            filtered_timezones.len(),
+            invalid,
+            offsets,
+            app.muted_count(),
+        ));
+    if let Some(full_name) = selected_full_name {
+        block = block.title_bottom(format!(" {full_name} "));
+    }
+    let t = Table::new(rows, widths).header(header).block(block);
+
+    f.render_widget(t, area);
+}
+
+/// Renders the pairwise diff matrix of the listed timezones
+///
+/// Cell (row, column) shows how far the column zone is ahead of the row zone.
+///
+/// # Arguments
+///
+/// * `f` - Frame to render to
+/// * `app` - Application state with timezone data
+/// * `now` - Frame instant the diffs are computed at
+/// * `area` - Area to render in
+fn render_matrix(f: &mut Frame, app: &App, now: DateTime<Utc>, area: Rect) {
+    let zones: Vec<_> = app
+        .filtered_timezones_at(now)
+        .into_iter()
+        .map(|(_, tz)| tz)
+        .collect();
+    let matrix = offset_matrix(now, &zones);
+
+    let header_cells = std::iter::once(Cell::from("")).chain(
+        zones
+            .iter()
+            .map(|tz| Cell::from(tz.name.clone()).style(Style::default().fg(Color::Yellow))),
+    );
+    let header = Row::new(header_cells)
+        .style(Style::default().add_modifier(Modifier::BOLD))
+        .height(1)
+        .bottom_margin(1);
+
+    let rows = zones.iter().zip(&matrix).enumerate().map(|(i, (tz, row))| {
+        let cells = row.iter().enumerate().map(|(j, diff)| {
+            let text = match diff {
+                _ if i == j => String::new(),
+                Some(seconds) => format_time_diff(f64::from(*seconds) / 3600.0),
+                None => "?".to_string(),
+            };
+            Cell::from(text)
         });
+        let name = Cell::from(tz.name.clone()).style(Style::default().fg(Color::Yellow));
+        Row::new(std::iter::once(name).chain(cells)).height(1)
+    });
 
-    let t = Table::new(
-        rows,
-        [
-            Constraint::Percentage(25),
-            Constraint::Percentage(20),
-            Constraint::Percentage(10),
-            Constraint::Percentage(25),
-            Constraint::Percentage(20),
-        ],
-    )
-    .header(header)
-    .block(
+    let widths = vec![Constraint::Fill(1); zones.len() + 1];
+    let t = Table::new(rows, widths).header(header).block(
         Block::default()
             .borders(Borders::ALL)
-            .title(format!(" Timezones ({}) ", filtered_timezones.len())),
+            .title(" Diff Matrix (column vs row) "),
     );
 
     f.render_widget(t, area);
 }
 
+/// Renders whether each zone is working at the reference zone's current
+/// local time over the next 7 days
+///
+/// # Arguments
+///
+/// * `f` - Frame to render to
+/// * `app` - Application state with timezone data
+/// * `now` - Frame instant the week starts from
+/// * `area` - Area to render in
+fn render_week(f: &mut Frame, app: &App, now: DateTime<Utc>, area: Rect) {
+    let selected = app.selected_timezone_at(now).map(|(_, tz)| tz);
+    let reference = app.reference_zone_at(now, selected);
+    let reference_name = app.reference_name_at(now);
+    let Some(local) = zone_local_time(now, &reference) else {
+        return;
+    };
+
+    let zones: Vec<_> = app
+        .filtered_timezones_at(now)
+        .into_iter()
+        .map(|(_, tz)| tz)
+        .collect();
+    let week = week_availability(&reference, local.date_naive(), local.time(), &zones);
+
+    let header_cells = std::iter::once(Cell::from("")).chain(
+        local
+            .date_naive()
+            .iter_days()
+            .take(PLANNING_DAYS)
+            .map(|date| {
+                Cell::from(date.format("%a %d").to_string())
+                    .style(Style::default().fg(Color::Yellow))
+            }),
+    );
+    let header = Row::new(header_cells)
+        .style(Style::default().add_modifier(Modifier::BOLD))
+        .height(1)
+        .bottom_margin(1);
+
+    let rows = zones.iter().zip(&week).map(|(tz, days)| {
+        let cells = days.iter().map(|working| {
+            if *working {
+                Cell::from("WORK").style(Style::default().fg(Color::Green))
+            } else {
+                Cell::from("off").style(Style::default().fg(Color::DarkGray))
+            }
+        });
+        let name = Cell::from(tz.name.clone()).style(Style::default().fg(Color::Yellow));
+        Row::new(std::iter::once(name).chain(cells)).height(1)
+    });
+
+    let title = format!(" Week at {} {} ", local.format("%H:%M"), reference_name);
+    let widths = vec![Constraint::Fill(1); PLANNING_DAYS + 1];
+    let t = Table::new(rows, widths)
+        .header(header)
+        .block(Block::default().borders(Borders::ALL).title(title));
+
+    f.render_widget(t, area);
+}
+
+/// Builds the separator row shown where the local date changes
+fn date_line_row(date: NaiveDate, options: DisplayOptions) -> Row<'static> {
+    separator_row(format!(
+        "{} {}",
+        date.format("%a"),
+        date.format(options.date_style.format_str())
+    ))
+}
+
+/// Builds a dimmed row separating parts of the timezone table
+fn separator_row(label: String) -> Row<'static> {
+    Row::new(vec![Cell::from(format!("── {label} ──"))])
+        .style(Style::default().fg(Color::DarkGray))
+        .height(1)
+}
+
+/// Builds the timezone table title
+///
+/// Mentions the number of distinct offsets when valid zones share some,
+/// and invalid and muted zones when there are any. Invalid zones have no
+/// offset, so they are counted separately rather than hidden in the offsets.
+///
+/// # Arguments
+///
+/// * `shown` - Number of listed zones
+/// * `invalid` - Number of listed zones with an invalid timezone
+/// * `offsets` - Number of distinct UTC offsets among the valid listed zones
+/// * `muted` - Number of muted zones
+fn timezones_title(shown: usize, invalid: usize, offsets: usize, muted: usize) -> String {
+    let mut parts = vec![shown.to_string()];
+    if offsets < shown - invalid {
+        parts.push(format!("{offsets} offsets"));
+    }
+    if invalid > 0 {
+        parts.push(format!("{invalid} invalid"));
+    }
+    if muted > 0 {
+        parts.push(format!("{muted} muted"));
+    }
+    format!(" Timezones ({}) ", parts.join(", "))
+}
+
+/// Builds the time, diff (optional), date and status cells for a timezone row
+///
+/// This is the single place where the TUI turns a `TimeDisplayInfo` into
+/// table cells, so every view renders clocks the same way.
+///
+/// # Arguments
+///
+/// * `info` - Display information, or None if the timezone is invalid
+/// * `show_diff` - Whether to include the diff cell
+/// * `options` - Display options selecting the diff style
+///
+/// # Returns
+///
+/// * `Vec<Cell>` - Time, diff, date and status cells in column order
+fn clock_cells(
+    info: Option<&TimeDisplayInfo>,
+    show_diff: bool,
+    options: DisplayOptions,
+) -> Vec<Cell<'static>> {
+    let mut cells = match info {
+        Some(info) => {
+            let (status, style) = status_label(info.status);
+            vec![
+                Cell::from(info.time.clone()),
+                Cell::from(options.format_diff(info.diff_hours)),
+                Cell::from(info.date.clone()),
+                Cell::from(status).style(style),
+            ]
+        }
+        None => vec![
+            Cell::from("Error"),
+            Cell::from(""),
+            Cell::from(""),
+            Cell::from("Invalid TZ").style(Style::default().fg(Color::Red)),
+        ],
+    };
+    if !show_diff {
+        cells.remove(1);
+    }
+    cells
+}
+
+/// Returns the day offset cell of a zone, e.g. "+1d", empty on the
+/// reference clock's day
+///
+/// # Arguments
+///
+/// * `now` - Current (simulated) UTC time
+/// * `tz` - The zone of the row
+/// * `reference` - Offset of the reference clock in seconds
+fn day_cell(now: DateTime<Utc>, tz: &TimezoneConfig, reference: i32) -> Cell<'static> {
+    let chip = day_offset(now, tz, reference).and_then(format_day_offset);
+    Cell::from(chip.unwrap_or_default()).style(Style::default().fg(Color::Cyan))
+}
+
+/// Returns the width in cells of the name column of the timezone table
+///
+/// # Arguments
+///
+/// * `area` - Area the bordered table is rendered in
+/// * `widths` - Column widths from `column_widths`
+fn name_column_width(area: Rect, widths: &[Constraint]) -> usize {
+    let inner = Rect::new(0, 0, area.width.saturating_sub(2), 1);
+    Layout::horizontal(widths.iter().copied())
+        .spacing(1)
+        .split(inner)
+        .first()
+        .map_or(0, |column| usize::from(column.width))
+}
+
+/// Shortens text to fit a width in terminal cells, ending it with "…" when
+/// anything is cut
+///
+/// # Arguments
+///
+/// * `text` - The text to fit
+/// * `width` - Available width in cells
+fn truncate_to_width(text: &str, width: usize) -> String {
+    if text.width() <= width {
+        return text.to_string();
+    }
+    let Some(room) = width.checked_sub(1) else {
+        return String::new();
+    };
+    let mut used = 0;
+    let mut shown: String = text
+        .chars()
+        .take_while(|c| {
+            used += c.width().unwrap_or(0);
+            used <= room
+        })
+        .collect();
+    shown.push('…');
+    shown
+}
+
+/// Returns the timezone table column widths for the visible columns
+///
+/// Each column has a relative weight; the shown columns share 100%.
+///
+/// # Arguments
+///
+/// * `show_diff` - Whether the diff column is shown
+/// * `show_day_offset` - Whether the day offset column is shown
+/// * `show_progress` - Whether the work day progress column is shown
+/// * `show_reference_hours` - Whether the converted work hours column is shown
+fn column_widths(
+    show_diff: bool,
+    show_day_offset: bool,
+    show_progress: bool,
+    show_reference_hours: bool,
+) -> Vec<Constraint> {
+    // Name, Time, Diff, Date, Day, Status, Work Day, Hours in reference
+    let columns = [
+        (22, true),
+        (16, true),
+        (20, show_diff),
+        (22, true),
+        (8, show_day_offset),
+        (20, true),
+        (30, show_progress),
+        (24, show_reference_hours),
+    ];
+    let weights: Vec<u16> = columns
+        .into_iter()
+        .filter(|(_, shown)| *shown)
+        .map(|(weight, _)| weight)
+        .collect();
+    let total: u16 = weights.iter().sum();
+    let mut widths: Vec<u16> = weights.iter().map(|w| w * 100 / total).collect();
+    // Give the rounding remainder to the name column
+    widths[0] += 100 - widths.iter().sum::<u16>();
+    widths.into_iter().map(Constraint::Percentage).collect()
+}
+
+/// Renders the listed timezones as plain text lines for `--print`
+///
+/// Uses the same filter, order and columns as the timezone table, including
+/// the compared rows.
+///
+/// # Arguments
+///
+/// * `app` - Application state with timezone data
+pub fn print_lines(app: &App) -> Vec<String> {
+    let now = app.current_time();
+    let zones = app.filtered_timezones_at(now);
+    let selected = zones.get(app.selected).map(|(_, tz)| *tz);
+    let options = app.display_options();
+    let line = |label: &str, info: Option<TimeDisplayInfo>| match info {
+        Some(info) => {
+            let mut fields = vec![info.time];
+            if app.show_diff {
+                fields.push(options.format_diff(info.diff_hours));
+            }
+            fields.push(info.date);
+            fields.push(status_label(info.status).0.to_string());
+            format!("{label:<20} {}", fields.join("  "))
+        }
+        None => format!("{label:<20} Invalid TZ"),
+    };
+
+    let reference = app.reference_offset_at(now, selected);
+    let mut lines = Vec::new();
+    for (_, tz) in zones {
+        lines.push(line(
+            &tz.name,
+            get_time_display_info(now, tz, reference, options),
+        ));
+        if app.compare {
+            let later = compare_display_info(
+                now,
+                app.compare_delta.num_seconds(),
+                tz,
+                |at| app.reference_offset_at(at, selected),
+                options,
+            );
+            lines.push(line(&format!("  ↳ {}", app.compare_label()), later));
+        }
+    }
+    lines
+}
+
+/// Renders the listed timezones as a JSON array for `--json`
+///
+/// Uses the same filter and order as `--print`. Each zone has `name`,
+/// `timezone`, `iso` (local RFC 3339 time), `diff` (hours from the
+/// reference zone) and `working`; `iso` and `diff` are null for invalid
+/// zones.
+///
+/// # Arguments
+///
+/// * `app` - Application state with timezone data
+pub fn json_output(app: &App) -> String {
+    let now = app.current_time();
+    let zones = app.filtered_timezones_at(now);
+    let reference = app.reference_offset_at(now, zones.get(app.selected).map(|(_, tz)| *tz));
+    let entries: Vec<_> = zones
+        .into_iter()
+        .map(|(_, tz)| {
+            let local_time = zone_local_time(now, tz);
+            serde_json::json!({
+                "name": tz.name,
+                "timezone": tz.timezone,
+                "iso": local_time.map(|local_time| local_time.to_rfc3339_opts(SecondsFormat::Secs, false)),
+                "diff": local_time.map(|local_time| {
+                    f64::from(local_time.offset().local_minus_utc() - reference) / 3600.0
+                }),
+                "working": is_work_hours(now, tz),
+            })
+        })
+        .collect();
+    serde_json::Value::Array(entries).to_string()
+}
+
+/// Returns the status label and its style for a work status
+fn status_label(status: WorkStatus) -> (&'static str, Style) {
+    match status {
+        WorkStatus::Working => ("WORKING", Style::default().fg(Color::Green)),
+        WorkStatus::EndingSoon => ("ENDING SOON", Style::default().fg(Color::Yellow)),
+        WorkStatus::Grace => ("GRACE", Style::default().fg(Color::Cyan)),
+        WorkStatus::Off(reason) => {
+            let label = match reason {
+                OffReason::AfterHours => "OFF (AFTER HOURS)",
+                OffReason::Weekend => "OFF (WEEKEND)",
+                OffReason::Holiday => "OFF (HOLIDAY)",
+            };
+            (label, Style::default().fg(Color::Red))
+        }
+        WorkStatus::InvalidHours => ("BAD HOURS", Style::default().fg(Color::Magenta)),
+    }
+}
+
 fn render_search(f: &mut Frame, app: &App, area: Rect) {
     let search_text = format!("Search: {}", app.search_query);
     let search = Paragraph::new(search_text)
@@ -271,47 +913,126 @@ fn render_search(f: &mut Frame, app: &App, area: Rect) {
     f.render_widget(search, area);
 }
 
-fn render_help(f: &mut Frame) {
-    let area = centered_rect(60, 50, f.area());
-    let help_text = vec![
+/// Renders the time expression prompt
+///
+/// # Arguments
+///
+/// * `f` - Frame to render to
+/// * `app` - Application state with the typed expression
+/// * `area` - Area to render in
+fn render_jump(f: &mut Frame, app: &App, area: Rect) {
+    let jump = Paragraph::new(format!("Go to: {}", app.jump_input))
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(" Jump (+2h, in 90m, 14:00, tomorrow 9am) "),
+        )
+        .style(Style::default().fg(Color::Yellow));
+    f.render_widget(jump, area);
+}
+
+fn render_set_hours(f: &mut Frame, app: &App, area: Rect) {
+    let text = match &app.pending_hours {
+        Some(pending) => format!(
+            "Set work hours of {} zones to {}-{} and save? (y/n)",
+            pending.zones, pending.hours.start, pending.hours.end
+        ),
+        None => format!("Hours: {}", app.hours_input),
+    };
+    let prompt = Paragraph::new(text)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(" Set all work hours (10:00-18:00, optionally: from 09:00-17:00) "),
+        )
+        .style(Style::default().fg(Color::Yellow));
+    f.render_widget(prompt, area);
+}
+
+/// Renders the preset picker overlay
+///
+/// # Arguments
+///
+/// * `f` - Frame to render to
+fn render_presets(f: &mut Frame) {
+    let area = centered_rect(50, 40, f.area());
+    let mut lines = vec![
         Line::from(Span::styled(
-            "Help / Shortcuts",
+            "Add preset",
             Style::default().add_modifier(Modifier::BOLD),
         )),
         Line::from(""),
+    ];
+    lines.extend(PRESETS.iter().enumerate().map(|(i, preset)| {
+        let names: Vec<_> = preset.zones.iter().map(|(name, _)| *name).collect();
         Line::from(vec![
-            Span::styled("↑/↓", Style::default().fg(Color::Yellow)),
-            Span::raw(": Navigate list"),
-        ]),
-        Line::from(vec![
-            Span::styled("←/→", Style::default().fg(Color::Yellow)),
-            Span::raw(": Adjust time (-/+ 15m)"),
-        ]),
-        Line::from(vec![
-            Span::styled("r", Style::default().fg(Color::Yellow)),
-            Span::raw(": Reset time to now"),
-        ]),
-        Line::from(vec![
-            Span::styled("/", Style::default().fg(Color::Yellow)),
-            Span::raw(": Search/Filter timezones"),
-        ]),
-        Line::from(vec![
-            Span::styled("t", Style::default().fg(Color::Yellow)),
-            Span::raw(": Toggle 12/24h format"),
-        ]),
-        Line::from(vec![
-            Span::styled("?", Style::default().fg(Color::Yellow)),
-            Span::raw(": Toggle this help"),
-        ]),
-        Line::from(vec![
-            Span::styled("q", Style::default().fg(Color::Yellow)),
-            Span::raw(": Quit"),
-        ]),
+            Span::styled(format!("{}", i + 1), Style::default().fg(Color::Yellow)),
+            Span::raw(format!(": {} ({})", preset.name, names.join(", "))),
+        ])
+    }));
+    lines.push(Line::from(""));
+    lines.push(Line::from(vec![
+        Span::styled("Esc", Style::default().fg(Color::Yellow)),
+        Span::raw(": Cancel"),
+    ]));
+
+    let block = Paragraph::new(lines)
+        .block(Block::default().borders(Borders::ALL))
+        .style(Style::default().bg(Color::DarkGray));
+
+    f.render_widget(Clear, area);
+    f.render_widget(block, area);
+}
+
+/// Renders the saved offset picker
+///
+/// # Arguments
+///
+/// * `f` - Frame to render to
+/// * `app` - Application state holding the saved offsets
+fn render_offsets(f: &mut Frame, app: &App) {
+    let area = centered_rect(50, 40, f.area());
+    let mut lines = vec![
+        Line::from(Span::styled(
+            "Jump to saved offset",
+            Style::default().add_modifier(Modifier::BOLD),
+        )),
+        Line::from(""),
+    ];
+    let saved_offsets = &app.config().saved_offsets;
+    if saved_offsets.is_empty() {
+        lines.push(Line::from("No saved_offsets in the config"));
+    }
+    lines.extend(saved_offsets.iter().take(9).enumerate().map(|(i, saved)| {
         Line::from(vec![
-            Span::styled("Esc", Style::default().fg(Color::Yellow)),
-            Span::raw(": Close help / Clear search"),
-        ]),
+            Span::styled(format!("{}", i + 1), Style::default().fg(Color::Yellow)),
+            Span::raw(format!(": {} ({})", saved.name, saved.offset)),
+        ])
+    }));
+    lines.push(Line::from(""));
+    lines.push(Line::from(vec![
+        Span::styled("Esc", Style::default().fg(Color::Yellow)),
+        Span::raw(": Cancel"),
+    ]));
+
+    let block = Paragraph::new(lines)
+        .block(Block::default().borders(Borders::ALL))
+        .style(Style::default().bg(Color::DarkGray));
+
+    f.render_widget(Clear, area);
+    f.render_widget(block, area);
+}
+
+fn render_help(f: &mut Frame) {
+    let area = centered_rect(60, 80, f.area());
+    let mut help_text = vec![
+        Line::from(Span::styled(
+            "Help / Shortcuts",
+            Style::default().add_modifier(Modifier::BOLD),
+        )),
+        Line::from(""),
     ];
+    help_text.extend(help_lines(KEY_BINDINGS));
 
     let block = Paragraph::new(help_text)
         .block(Block::default().borders(Borders::ALL))
@@ -341,39 +1062,332 @@ fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
         .split(popup_layout[1])[1]
 }
 
-/// Renders the footer with keyboard shortcuts
+/// Renders the footer with keyboard shortcuts and the modes that are on
+///
+/// A pending status message replaces the shortcuts until the next key press.
 ///
 /// # Arguments
 ///
 /// * `f` - Frame to render to
+/// * `app` - Application state
 /// * `area` - Area to render in
-fn render_footer(f: &mut Frame, area: Rect) {
-    let footer_text = Text::from(Line::from(vec![
-        Span::styled("←→", Style::default().fg(Color::Yellow)),
-        Span::raw(" Adjust time | "),
-        Span::styled("↑↓", Style::default().fg(Color::Yellow)),
-        Span::raw(" Navigate | "),
-        Span::styled("r", Style::default().fg(Color::Yellow)),
-        Span::raw(" Reset | "),
-        Span::styled("/", Style::default().fg(Color::Yellow)),
-        Span::raw(" Search | "),
-        Span::styled("?", Style::default().fg(Color::Yellow)),
-        Span::raw(" Help | "),
-        Span::styled("q", Style::default().fg(Color::Yellow)),
-        Span::raw(" Quit"),
-    ]));
+fn render_footer(f: &mut Frame, app: &App, area: Rect) {
+    if let Some(message) = &app.status_message {
+        let footer = Paragraph::new(Span::styled(
+            message.clone(),
+            Style::default().fg(Color::Green),
+        ));
+        f.render_widget(footer, area);
+        return;
+    }
 
-    let footer = Paragraph::new(footer_text);
+    let footer = Paragraph::new(footer_line(app, KEY_BINDINGS));
     f.render_widget(footer, area);
 }
 
 #[cfg(test)]
 mod tests {
+    use std::sync::Arc;
+
     use chrono::{TimeZone, Utc};
-    use longtime_core::{TimezoneConfig, WorkHours};
+    use longtime_core::{FixedClock, TimezoneConfig, WorkHours, is_work_hours};
 
     use super::*;
 
+    /// Characters the main screen dispatches on in `run_app`
+    fn dispatched_chars() -> Vec<char> {
+        let source = include_str!("ui.rs");
+        let start = source
+            .find("KeyCode::Char('q') => return Ok(())")
+            .expect("main screen dispatch");
+        let end = start
+            + source[start..]
+                .find("if last_tick")
+                .expect("end of dispatch");
+        let mut chars: Vec<char> = source[start..end]
+            .split("KeyCode::Char('")
+            .skip(1)
+            .filter_map(|rest| rest.chars().next())
+            .collect();
+        if source[start..end].contains("KeyCode::Char(c @ '1'..='9')") {
+            chars.extend('1'..='9');
+        }
+        chars
+    }
+
+    /// Whether a binding's keys, e.g. "0 / e" or "+/-", include a character
+    fn binding_covers(keys: &str, c: char) -> bool {
+        match keys {
+            "Space" => c == ' ',
+            "1-9" => ('1'..='9').contains(&c),
+            _ => {
+                keys.chars().count() == 1 && keys.starts_with(c)
+                    || keys
+                        .split(['/', ' '])
+                        .any(|key| key.chars().eq(std::iter::once(c)))
+            }
+        }
+    }
+
+    #[test]
+    fn test_key_bindings_match_dispatch() {
+        let dispatched = dispatched_chars();
+        assert!(dispatched.contains(&'q') && dispatched.contains(&'5'));
+        for c in &dispatched {
+            assert!(
+                KEY_BINDINGS
+                    .iter()
+                    .any(|binding| binding_covers(binding.keys, *c)),
+                "{c:?} is handled but not in KEY_BINDINGS"
+            );
+        }
+        // And every documented printable key is handled
+        for binding in KEY_BINDINGS {
+            for c in (' '..='~').filter(|c| binding_covers(binding.keys, *c)) {
+                assert!(
+                    dispatched.contains(&c),
+                    "{c:?} ({}) is documented but not handled",
+                    binding.keys
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_truncate_to_width() {
+        assert_eq!(truncate_to_width("London", 10), "London");
+        assert_eq!(truncate_to_width("London", 6), "London");
+        assert_eq!(truncate_to_width("Los Angeles Office", 10), "Los Angel…");
+        // Wide characters take two cells
+        assert_eq!(truncate_to_width("東京オフィス", 5), "東京…");
+        assert_eq!(truncate_to_width("London", 1), "…");
+        assert_eq!(truncate_to_width("London", 0), "");
+
+        let widths = column_widths(false, false, false, false);
+        let width = name_column_width(Rect::new(0, 0, 102, 20), &widths);
+        assert!((20..=30).contains(&width), "{width}");
+        assert_eq!(name_column_width(Rect::new(0, 0, 1, 20), &widths), 0);
+    }
+
+    #[test]
+    fn test_timezones_title() {
+        assert_eq!(timezones_title(3, 0, 3, 0), " Timezones (3) ");
+        assert_eq!(timezones_title(5, 0, 3, 0), " Timezones (5, 3 offsets) ");
+        assert_eq!(
+            timezones_title(5, 0, 3, 2),
+            " Timezones (5, 3 offsets, 2 muted) "
+        );
+        assert_eq!(timezones_title(0, 0, 0, 2), " Timezones (0, 2 muted) ");
+        // Invalid zones are not passed off as sharing an offset
+        assert_eq!(timezones_title(3, 1, 2, 0), " Timezones (3, 1 invalid) ");
+        assert_eq!(
+            timezones_title(4, 1, 2, 0),
+            " Timezones (4, 2 offsets, 1 invalid) "
+        );
+    }
+
+    #[test]
+    fn test_print_lines_respects_filter() {
+        let zone = |name: &str, timezone: &str| TimezoneConfig {
+            name: name.to_string(),
+            timezone: timezone.to_string(),
+            work_hours: WorkHours {
+                start: "09:00".to_string(),
+                end: "17:00".to_string(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let config = Config {
+            timezones: vec![zone("London", "Europe/London"), zone("Tokyo", "Asia/Tokyo")],
+            ..Default::default()
+        };
+        let instant = Utc.with_ymd_and_hms(2023, 6, 1, 12, 0, 0).unwrap();
+        let mut app = App::with_clock(config, Arc::new(FixedClock(instant)));
+        app.search_query = "tokyo".to_string();
+
+        assert_eq!(
+            print_lines(&app),
+            [format!(
+                "{:<20} 21:00  =  2023-06-01  OFF (AFTER HOURS)",
+                "Tokyo"
+            )]
+        );
+    }
+
+    #[test]
+    fn test_print_lines_compare() {
+        let config = Config {
+            timezones: vec![TimezoneConfig {
+                name: "London".to_string(),
+                timezone: "Europe/London".to_string(),
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+        // Monday 20 March 2023, a week before the clocks go forward
+        let instant = Utc.with_ymd_and_hms(2023, 3, 20, 12, 0, 0).unwrap();
+        let mut app = App::with_clock(config, Arc::new(FixedClock(instant)));
+        assert!(app.set_compare("next week").is_err());
+        assert!(!app.compare);
+        app.set_compare("+7d").unwrap();
+
+        assert_eq!(
+            print_lines(&app),
+            [
+                format!("{:<20} 12:00  =  2023-03-20  WORKING", "London"),
+                format!("{:<20} 13:00  =  2023-03-27  WORKING", "  ↳ +7d"),
+            ]
+        );
+
+        app.toggle_compare();
+        assert_eq!(print_lines(&app).len(), 1);
+    }
+
+    #[test]
+    fn test_json_output() {
+        let config = Config {
+            timezones: vec![
+                TimezoneConfig {
+                    name: "London".to_string(),
+                    timezone: "Europe/London".to_string(),
+                    ..Default::default()
+                },
+                TimezoneConfig {
+                    name: "Tokyo".to_string(),
+                    timezone: "Asia/Tokyo".to_string(),
+                    work_hours: WorkHours {
+                        start: "09:00".to_string(),
+                        end: "17:00".to_string(),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+                TimezoneConfig {
+                    name: "Nowhere".to_string(),
+                    timezone: "Invalid/Zone".to_string(),
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        };
+        let instant = Utc.with_ymd_and_hms(2023, 6, 1, 12, 0, 0).unwrap();
+        let mut app = App::with_clock(config, Arc::new(FixedClock(instant)));
+        // Like `--at`, move to 01:00 UTC, which is 10:00 in Tokyo
+        app.apply_time_expression("-11h").unwrap();
+
+        let json: serde_json::Value = serde_json::from_str(&json_output(&app)).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!([
+                {
+                    "name": "London",
+                    "timezone": "Europe/London",
+                    "iso": "2023-06-01T02:00:00+01:00",
+                    "diff": 0.0,
+                    "working": false,
+                },
+                {
+                    "name": "Tokyo",
+                    "timezone": "Asia/Tokyo",
+                    "iso": "2023-06-01T10:00:00+09:00",
+                    "diff": 8.0,
+                    "working": true,
+                },
+                {
+                    "name": "Nowhere",
+                    "timezone": "Invalid/Zone",
+                    "iso": null,
+                    "diff": null,
+                    "working": false,
+                },
+            ])
+        );
+    }
+
+    #[test]
+    fn test_print_lines_use_simulated_offsets_across_dst() {
+        let config = Config {
+            timezones: vec![
+                TimezoneConfig {
+                    name: "New York".to_string(),
+                    timezone: "America/New_York".to_string(),
+                    ..Default::default()
+                },
+                TimezoneConfig {
+                    name: "UTC".to_string(),
+                    timezone: "UTC".to_string(),
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        };
+        // New York is still on EST (-5) at the real instant
+        let instant = Utc.with_ymd_and_hms(2024, 3, 10, 6, 30, 0).unwrap();
+        let mut app = App::with_clock(config, Arc::new(FixedClock(instant)));
+        let lines = print_lines(&app);
+        assert!(lines[0].contains("01:30"), "{lines:?}");
+        assert!(lines[1].contains("+5"), "{lines:?}");
+
+        // An hour later it has switched to EDT (-4); the reference offset must follow
+        app.adjust_time_forward(60);
+        let lines = print_lines(&app);
+        assert!(lines[0].contains("03:30"), "{lines:?}");
+        assert!(lines[1].contains("+4"), "{lines:?}");
+    }
+
+    #[test]
+    fn test_title_text_uses_custom_title() {
+        let now = Utc::now();
+        let mut config = Config::default();
+        assert_eq!(
+            title_text(&config, now, false),
+            "LongTime - Multi-timezone Time Manager"
+        );
+
+        config.title = Some("Acme Clocks".to_string());
+        assert_eq!(
+            title_text(&config, now, false),
+            "Acme Clocks - Multi-timezone Time Manager"
+        );
+    }
+
+    #[test]
+    fn test_gauge() {
+        assert_eq!(gauge(0.0, 4), "░░░░");
+        assert_eq!(gauge(0.5, 4), "██░░");
+        assert_eq!(gauge(1.0, 4), "████");
+        assert_eq!(gauge(1.5, 4), "████");
+    }
+
+    #[test]
+    fn test_title_text_anchor_zone() {
+        let now = Utc.with_ymd_and_hms(2024, 3, 1, 9, 3, 0).unwrap();
+        // Configured zones use their name
+        let mut config = Config {
+            title_timezone: Some("Asia/Shanghai".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(title_text(&config, now, false), "LongTime - 17:03 Shanghai");
+        assert_eq!(
+            title_text(&config, now, true),
+            "LongTime - 05:03 PM Shanghai"
+        );
+
+        // Other zones are named after their city
+        config.title_timezone = Some("America/Los_Angeles".to_string());
+        assert_eq!(
+            title_text(&config, now, false),
+            "LongTime - 01:03 Los Angeles"
+        );
+
+        config.title_timezone = Some("Mars/Base".to_string());
+        assert_eq!(
+            title_text(&config, now, false),
+            "LongTime - Multi-timezone Time Manager"
+        );
+    }
+
     #[test]
     fn test_is_work_hours() {
         let tz_config = TimezoneConfig {
@@ -382,7 +1396,9 @@ mod tests {
             work_hours: WorkHours {
                 start: "09:00".to_string(),
                 end: "17:00".to_string(),
+                ..Default::default()
             },
+            ..Default::default()
         };
 
         // 12:00 UTC is within 09:00-17:00
@@ -393,4 +1409,50 @@ mod tests {
         let off_time = Utc.with_ymd_and_hms(2023, 1, 1, 20, 0, 0).unwrap();
         assert!(!is_work_hours(off_time, &tz_config));
     }
+
+    #[test]
+    fn test_column_widths() {
+        for (show_progress, show_reference_hours) in
+            [(false, false), (true, false), (false, true), (true, true)]
+        {
+            let with_diff = column_widths(true, false, show_progress, show_reference_hours);
+            let without_diff = column_widths(false, false, show_progress, show_reference_hours);
+            let with_day = column_widths(true, true, show_progress, show_reference_hours);
+            assert_eq!(with_diff.len(), without_diff.len() + 1);
+            assert_eq!(with_day.len(), with_diff.len() + 1);
+            for widths in [with_diff, without_diff, with_day] {
+                let total: u16 = widths
+                    .iter()
+                    .map(|c| match c {
+                        Constraint::Percentage(p) => *p,
+                        _ => 0,
+                    })
+                    .sum();
+                assert_eq!(total, 100);
+            }
+        }
+        let options = DisplayOptions::default();
+        assert_eq!(clock_cells(None, true, options).len(), 4);
+        assert_eq!(clock_cells(None, false, options).len(), 3);
+    }
+
+    #[test]
+    fn test_status_label() {
+        assert_eq!(status_label(WorkStatus::Working).0, "WORKING");
+        assert_eq!(status_label(WorkStatus::EndingSoon).0, "ENDING SOON");
+        assert_eq!(status_label(WorkStatus::Grace).0, "GRACE");
+        assert_eq!(
+            status_label(WorkStatus::Off(OffReason::AfterHours)).0,
+            "OFF (AFTER HOURS)"
+        );
+        assert_eq!(
+            status_label(WorkStatus::Off(OffReason::Weekend)).0,
+            "OFF (WEEKEND)"
+        );
+        assert_eq!(
+            status_label(WorkStatus::Off(OffReason::Holiday)).0,
+            "OFF (HOLIDAY)"
+        );
+        assert_eq!(status_label(WorkStatus::InvalidHours).0, "BAD HOURS");
+    }
 }
@@ -4,12 +4,16 @@
 //! entry point for the Leptos application.
 
 use leptos::prelude::*;
+use longtime_core::DayBoundary;
 use wasm_bindgen::prelude::*;
 
 use crate::{
-    components::{ConfigModal, Header, TimeControls, TimezoneList},
+    components::{
+        BulkHoursModal, ClockBanner, ConfigBanner, ConfigModal, DiffMatrix, Header, HelpModal,
+        StatusAnnouncer, TimeControls, TimezoneList, WeekView,
+    },
     state::AppState,
-    storage::load_initial_config,
+    storage::{load_initial_config, load_initial_view},
 };
 
 /// Root application component
@@ -20,6 +24,9 @@ pub fn App() -> impl IntoView {
 
     // Create application state
     let state = AppState::new(config);
+    if let Some(view) = load_initial_view() {
+        state.apply_view_state(&view);
+    }
 
     // Provide state to all child components via context
     provide_context(state.clone());
@@ -35,6 +42,7 @@ pub fn App() -> impl IntoView {
                 // Trigger a re-render by updating the tick counter
                 state.tick.update(|t| *t += 1);
             }
+            state.fast_forward_tick();
         });
 
         // Keep interval alive by storing it
@@ -51,7 +59,8 @@ pub fn App() -> impl IntoView {
         let handler =
             Closure::<dyn Fn(web_sys::KeyboardEvent)>::new(move |event: web_sys::KeyboardEvent| {
                 // Skip if modal is open (except for Escape)
-                let modal_open = state.show_config_modal.get();
+                let modal_open =
+                    state.show_config_modal.get() || state.show_bulk_hours.get_untracked();
 
                 // Skip if focus is on an input element (allow typing)
                 if let Some(target) = event.target()
@@ -67,48 +76,127 @@ pub fn App() -> impl IntoView {
                 }
 
                 match event.key().as_str() {
-                    "Escape" => {
+                    "Escape" if modal_open => {
                         // Close modal if open
-                        if modal_open {
-                            state.show_config_modal.set(false);
-                            state.editing_index.set(None);
-                            event.prevent_default();
+                        state.show_config_modal.set(false);
+                        state.show_bulk_hours.set(false);
+                        state.editing_index.set(None);
+                        event.prevent_default();
+                    }
+                    "Escape" if state.show_help.get_untracked() => {
+                        // Close the shortcut overlay
+                        state.show_help.set(false);
+                        event.prevent_default();
+                    }
+                    "Escape" if state.present_mode.get_untracked() => {
+                        // Leave present mode
+                        state.present_mode.set(false);
+                        event.prevent_default();
+                    }
+                    "?" if !modal_open => {
+                        // Toggle the shortcut overlay
+                        state.show_help.update(|show| *show = !*show);
+                        event.prevent_default();
+                    }
+                    "[" if !modal_open => {
+                        // Re-base diffs on the previous card
+                        state.select_adjacent(false);
+                        event.prevent_default();
+                    }
+                    "b" if !modal_open => {
+                        // Flip diffs back to the previous reference
+                        state.swap_reference();
+                        event.prevent_default();
+                    }
+                    "]" if !modal_open => {
+                        // Re-base diffs on the next card
+                        state.select_adjacent(true);
+                        event.prevent_default();
+                    }
+                    "p" if !modal_open => {
+                        // Toggle present mode
+                        state.toggle_present_mode();
+                        event.prevent_default();
+                    }
+                    "x" if !modal_open => {
+                        // Toggle the diff matrix
+                        state.toggle_matrix();
+                        event.prevent_default();
+                    }
+                    "c" if !modal_open => {
+                        // Toggle work hours on the reference clock
+                        state.toggle_reference_hours();
+                        event.prevent_default();
+                    }
+                    "U" if !modal_open => {
+                        // Lock diffs to UTC
+                        state.toggle_utc_reference();
+                        event.prevent_default();
+                    }
+                    "G" if !modal_open => {
+                        // Group working zones first
+                        state.toggle_group_by_status();
+                        event.prevent_default();
+                    }
+                    "f" if !modal_open => {
+                        // Toggle focus mode
+                        state.toggle_focus_mode();
+                        event.prevent_default();
+                    }
+                    "t" if !modal_open => {
+                        // Switch between the card grid and the table
+                        state.toggle_table_view();
+                        event.prevent_default();
+                    }
+                    "v" if !modal_open => {
+                        // Toggle the 7-day view
+                        state.toggle_week();
+                        event.prevent_default();
+                    }
+                    key @ ("1" | "2" | "3" | "4" | "5" | "6" | "7" | "8" | "9") if !modal_open => {
+                        // Jump to a configured quick time in the reference zone
+                        let index = key.parse::<usize>().unwrap_or(1) - 1;
+                        if let Some(time) = state.config.get_untracked().quick_jumps().get(index) {
+                            state.quick_jump(*time);
                         }
+                        event.prevent_default();
                     }
-                    "ArrowLeft" | "h" => {
+                    "0" if !modal_open => {
+                        // Snap to midnight in the reference zone
+                        state.jump_to_day_boundary(DayBoundary::Start);
+                        event.prevent_default();
+                    }
+                    "e" if !modal_open => {
+                        // Snap to the end of the reference zone's day
+                        state.jump_to_day_boundary(DayBoundary::End);
+                        event.prevent_default();
+                    }
+                    "ArrowLeft" | "h" if !modal_open => {
                         // Decrease time by 15 minutes
-                        if !modal_open {
-                            state.time_offset.update(|offset| *offset -= 15 * 60);
-                            event.prevent_default();
-                        }
+                        state.adjust_time(-15 * 60);
+                        event.prevent_default();
                     }
-                    "ArrowRight" | "l" => {
+                    "ArrowRight" | "l" if !modal_open => {
                         // Increase time by 15 minutes
-                        if !modal_open {
-                            state.time_offset.update(|offset| *offset += 15 * 60);
-                            event.prevent_default();
-                        }
+                        state.adjust_time(15 * 60);
+                        event.prevent_default();
                     }
-                    "r" => {
+                    "r" if !modal_open => {
                         // Reset time offset
-                        if !modal_open {
-                            state.time_offset.set(0);
-                            event.prevent_default();
-                        }
+                        state.time_offset.set(0);
+                        event.prevent_default();
                     }
-                    " " => {
+                    " " if !modal_open => {
                         // Toggle play/pause
-                        if !modal_open {
-                            state.is_running.update(|running| *running = !*running);
-                            event.prevent_default();
-                        }
+                        state.toggle_running();
+                        event.prevent_default();
                     }
                     _ => {}
                 }
             });
 
         // Add event listener to window
-        if let Some(window) = web_sys::window() {
+        if let Ok(window) = crate::browser::window("listen for keyboard shortcuts") {
             let _ = window
                 .add_event_listener_with_callback("keydown", handler.as_ref().unchecked_ref());
         }
@@ -121,7 +209,9 @@ pub fn App() -> impl IntoView {
     let state_for_theme = state.clone();
     Effect::new(move || {
         let is_dark = state_for_theme.dark_mode.get();
-        if let Some(document) = web_sys::window().and_then(|w| w.document())
+        if let Some(document) = crate::browser::window("apply the theme")
+            .ok()
+            .and_then(|w| w.document())
             && let Some(body) = document.body()
         {
             let _ = body.class_list().remove_1("light");
@@ -131,17 +221,50 @@ pub fn App() -> impl IntoView {
         }
     });
 
+    let present_mode = state.present_mode;
+    let show_matrix = state.show_matrix;
+    let show_week = state.show_week;
+
     view! {
-      <div class="flex relative flex-col min-h-screen font-mono bg-surface text-text-primary">
+      <div class=move || {
+        if present_mode.get() {
+          "flex relative flex-col min-h-screen font-mono bg-surface text-text-primary present-mode"
+        } else {
+          "flex relative flex-col min-h-screen font-mono bg-surface text-text-primary"
+        }
+      }>
         // Scanline effect overlay
         <div class="scanlines"></div>
 
-        <Header />
+        <Show when=move || !present_mode.get()>
+          <Header />
+        </Show>
+        // Checks the device clock once and warns about a bad home zone;
+        // hidden in present mode
+        <ClockBanner />
+        <ConfigBanner />
         <main class="container relative z-10 flex-1 py-6 px-4 mx-auto">
-          <TimezoneList />
+          <Show
+            when=move || show_matrix.get()
+            fallback=move || {
+              view! {
+                <Show when=move || show_week.get() fallback=|| view! { <TimezoneList /> }>
+                  <WeekView />
+                </Show>
+              }
+            }
+          >
+            <DiffMatrix />
+          </Show>
         </main>
-        <TimeControls />
+        <Show when=move || !present_mode.get()>
+          <TimeControls />
+        </Show>
         <ConfigModal />
+        <HelpModal />
+        <BulkHoursModal />
+        // Screen reader announcements of zones starting or finishing work
+        <StatusAnnouncer />
       </div>
     }
 }
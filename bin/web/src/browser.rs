@@ -0,0 +1,61 @@
+//! Browser window access
+//!
+//! All access to `web_sys::window()` goes through [`window`], so a missing
+//! window (e.g. when rendering outside a browser) is reported once, with the
+//! reason it was needed, instead of being silently replaced by defaults.
+
+use std::fmt;
+
+/// Errors from browser APIs used by the app
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BrowserError {
+    /// There is no `window` object, e.g. outside a browser
+    NoWindow,
+}
+
+impl fmt::Display for BrowserError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BrowserError::NoWindow => write!(f, "browser window is unavailable"),
+        }
+    }
+}
+
+/// Get the browser window, logging a warning when it is missing
+///
+/// # Arguments
+///
+/// * `purpose` - What the window is needed for, included in the warning
+///
+/// # Returns
+///
+/// * `Result<web_sys::Window, BrowserError>` - The window, or why it is unavailable
+pub fn window(purpose: &str) -> Result<web_sys::Window, BrowserError> {
+    require_window(web_sys::window(), purpose)
+}
+
+/// Turn an optional window into a result, logging a warning when it is None
+fn require_window<W>(window: Option<W>, purpose: &str) -> Result<W, BrowserError> {
+    window.ok_or_else(|| {
+        leptos::logging::warn!("Cannot {purpose}: {}", BrowserError::NoWindow);
+        BrowserError::NoWindow
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_missing_window_is_an_error() {
+        assert_eq!(
+            require_window(None::<()>, "read the URL"),
+            Err(BrowserError::NoWindow)
+        );
+        assert_eq!(require_window(Some(1), "read the URL"), Ok(1));
+        assert_eq!(
+            BrowserError::NoWindow.to_string(),
+            "browser window is unavailable"
+        );
+    }
+}
@@ -0,0 +1,200 @@
+//! Bulk work hours modal component
+//!
+//! Sets the work hours of every configured timezone at once, optionally only
+//! for zones that currently work a given range.
+
+use leptos::prelude::*;
+use longtime_core::WorkHours;
+
+use crate::state::AppState;
+
+/// Builds work hours from the modal's start/end fields
+///
+/// Time inputs read as empty while cleared or incomplete, so both fields
+/// must hold a time.
+///
+/// # Arguments
+///
+/// * `label` - Which pair of fields is read, for the error message
+/// * `start` - Value of the start field
+/// * `end` - Value of the end field
+///
+/// # Returns
+///
+/// * `Result<WorkHours, String>` - The work hours, or a message describing the problem
+fn hours_from(label: &str, start: String, end: String) -> Result<WorkHours, String> {
+    if start.is_empty() || end.is_empty() {
+        return Err(format!("Enter both {label} times"));
+    }
+    let hours = WorkHours {
+        start,
+        end,
+        ..Default::default()
+    };
+    if hours.is_valid() {
+        Ok(hours)
+    } else {
+        Err(format!("The {label} times must be in HH:MM format"))
+    }
+}
+
+/// Bulk work hours modal component
+#[component]
+pub fn BulkHoursModal() -> impl IntoView {
+    let state = expect_context::<AppState>();
+
+    let start = RwSignal::new(String::from("09:00"));
+    let end = RwSignal::new(String::from("17:00"));
+    let only_matching = RwSignal::new(false);
+    let match_start = RwSignal::new(String::from("09:00"));
+    let match_end = RwSignal::new(String::from("17:00"));
+    let error = RwSignal::new(None::<String>);
+
+    let show = state.show_bulk_hours;
+    let close = move |_| {
+        error.set(None);
+        show.set(false);
+    };
+
+    view! {
+      <Show when=move || show.get()>
+        <div class="modal-backdrop" on:click=close></div>
+        <div class="flex fixed inset-0 z-50 justify-center items-center p-4">
+          <div
+            class="p-6 w-full max-w-md modal-content"
+            on:click=|e: web_sys::MouseEvent| e.stop_propagation()
+          >
+            <h2 class="mb-6 font-mono text-xl font-bold text-primary">
+              <span class="text-primary/50">"$ "</span>
+              "set-all-work-hours"
+            </h2>
+            <form class="space-y-4" on:submit=|e: web_sys::SubmitEvent| e.prevent_default()>
+              <div class="grid grid-cols-2 gap-4">
+                <div>
+                  <label class="block mb-1 font-mono text-sm text-text-secondary">
+                    <span class="text-primary/50">"# "</span>
+                    "work_start"
+                  </label>
+                  <input
+                    type="time"
+                    class="w-full input-terminal"
+                    prop:value=move || start.get()
+                    on:input=move |e| start.set(event_target_value(&e))
+                  />
+                </div>
+                <div>
+                  <label class="block mb-1 font-mono text-sm text-text-secondary">
+                    <span class="text-primary/50">"# "</span>
+                    "work_end"
+                  </label>
+                  <input
+                    type="time"
+                    class="w-full input-terminal"
+                    prop:value=move || end.get()
+                    on:input=move |e| end.set(event_target_value(&e))
+                  />
+                </div>
+              </div>
+
+              // Optional filter on the current hours
+              <label class="flex gap-2 items-center font-mono text-sm text-text-secondary">
+                <input
+                  type="checkbox"
+                  prop:checked=move || only_matching.get()
+                  on:change=move |e| only_matching.set(event_target_checked(&e))
+                />
+                "only zones currently working"
+              </label>
+              <Show when=move || only_matching.get()>
+                <div class="grid grid-cols-2 gap-4">
+                  <input
+                    type="time"
+                    class="w-full input-terminal"
+                    aria-label="Current start"
+                    prop:value=move || match_start.get()
+                    on:input=move |e| match_start.set(event_target_value(&e))
+                  />
+                  <input
+                    type="time"
+                    class="w-full input-terminal"
+                    aria-label="Current end"
+                    prop:value=move || match_end.get()
+                    on:input=move |e| match_end.set(event_target_value(&e))
+                  />
+                </div>
+              </Show>
+              {move || error.get().map(|message| view! { <p class="text-xs text-off">{message}</p> })}
+
+              <div class="flex gap-3 pt-4">
+                <button type="button" on:click=close class="flex-1 btn-terminal">
+                  "Cancel"
+                </button>
+                <button
+                  type="submit"
+                  on:click={
+                    let state = state.clone();
+                    move |_| {
+                      let parsed = hours_from("work", start.get(), end.get()).and_then(|hours| {
+                        let filter = only_matching
+                          .get()
+                          .then(|| hours_from("current", match_start.get(), match_end.get()))
+                          .transpose()?;
+                        Ok((hours, filter))
+                      });
+                      let (hours, filter) = match parsed {
+                        Ok(parsed) => parsed,
+                        Err(message) => {
+                          error.set(Some(message));
+                          return;
+                        }
+                      };
+                      error.set(None);
+                      let zones = state.count_work_hours_targets(filter.as_ref());
+                      let question = format!(
+                        "Set work hours of {zones} zones to {}-{}?",
+                        hours.start,
+                        hours.end,
+                      );
+                      let confirmed = crate::browser::window("confirm the change")
+                        .ok()
+                        .and_then(|window| window.confirm_with_message(&question).ok())
+                        .unwrap_or(false);
+                      if confirmed {
+                        state.set_all_work_hours(&hours, filter.as_ref());
+                        show.set(false);
+                      }
+                    }
+                  }
+                  class="flex-1 font-semibold btn-primary"
+                >
+                  "Apply to all"
+                </button>
+              </div>
+            </form>
+          </div>
+        </div>
+      </Show>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hours_from() {
+        let hours = hours_from("work", "09:00".to_string(), "17:00".to_string()).unwrap();
+        assert_eq!(
+            (hours.start.as_str(), hours.end.as_str()),
+            ("09:00", "17:00")
+        );
+        assert_eq!(
+            hours_from("work", String::new(), "17:00".to_string()),
+            Err("Enter both work times".to_string())
+        );
+        assert_eq!(
+            hours_from("current", "09:00".to_string(), "5pm".to_string()),
+            Err("The current times must be in HH:MM format".to_string())
+        );
+    }
+}
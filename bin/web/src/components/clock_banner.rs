@@ -0,0 +1,136 @@
+//! Clock warning banner component
+//!
+//! Compares the browser clock with the `Date` header of the page's own
+//! server once on load and shows a dismissible banner when they disagree,
+//! since every displayed time depends on the device clock. Failed requests
+//! are ignored.
+
+use chrono::{DateTime, Utc};
+use leptos::prelude::*;
+use longtime_core::format_hours_minutes;
+use wasm_bindgen::JsCast;
+
+use crate::state::AppState;
+
+/// Get how far the device clock is off, if beyond the tolerance
+///
+/// # Arguments
+///
+/// * `device` - The device's current time
+/// * `date_header` - The server's HTTP `Date` header, e.g.
+///   "Sun, 06 Nov 1994 08:49:37 GMT"
+/// * `tolerance_minutes` - Allowed difference; 0 disables the check
+///
+/// # Returns
+///
+/// * `Option<i64>` - Seconds the device is ahead (negative if behind), or
+///   None within the tolerance or if the header cannot be parsed
+fn clock_skew(device: DateTime<Utc>, date_header: &str, tolerance_minutes: u32) -> Option<i64> {
+    let server = DateTime::parse_from_rfc2822(date_header.trim()).ok()?;
+    let skew = (device - server.with_timezone(&Utc)).num_seconds();
+    (tolerance_minutes > 0 && skew.abs() > i64::from(tolerance_minutes) * 60).then_some(skew)
+}
+
+/// Describe a clock skew for the banner
+///
+/// # Arguments
+///
+/// * `skew` - Seconds the device clock is ahead (negative if behind)
+fn skew_message(skew: i64) -> String {
+    let direction = if skew > 0 { "ahead" } else { "behind" };
+    format!(
+        "Your device clock looks {} {direction}, so the times shown may be wrong",
+        format_hours_minutes(skew.abs())
+    )
+}
+
+/// Fetch the `Date` header of the page's own server
+///
+/// # Returns
+///
+/// * `Option<String>` - The header, or None if the request fails
+async fn fetch_server_date() -> Option<String> {
+    let window = crate::browser::window("check the device clock").ok()?;
+    let url = window.location().href().ok()?;
+    let init = web_sys::RequestInit::new();
+    init.set_method("HEAD");
+    init.set_cache(web_sys::RequestCache::NoStore);
+    let promise = window.fetch_with_str_and_init(&url, &init);
+    let response = wasm_bindgen_futures::JsFuture::from(promise).await.ok()?;
+    let response: web_sys::Response = response.dyn_into().ok()?;
+    response.headers().get("Date").ok().flatten()
+}
+
+/// Clock warning banner component
+#[component]
+pub fn ClockBanner() -> impl IntoView {
+    let state = expect_context::<AppState>();
+    let warning = RwSignal::new(None::<String>);
+
+    let tolerance = state.config.get_untracked().clock_check_minutes;
+    if tolerance > 0 {
+        let clock = state.clock.clone();
+        leptos::task::spawn_local(async move {
+            if let Some(date) = fetch_server_date().await
+                && let Some(skew) = clock_skew(clock.now(), &date, tolerance)
+            {
+                warning.set(Some(skew_message(skew)));
+            }
+        });
+    }
+
+    move || {
+        warning.get().map(|message| {
+            view! {
+              <div class="clock-banner" role="alert">
+                <span>{message}</span>
+                <button
+                  class="py-1 px-2 font-mono text-xs btn-terminal"
+                  title="Dismiss this warning"
+                  on:click=move |_| warning.set(None)
+                >
+                  "Dismiss"
+                </button>
+              </div>
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+
+    #[test]
+    fn test_clock_skew() {
+        let header = "Thu, 01 Jun 2023 12:00:00 GMT";
+        let server = Utc.with_ymd_and_hms(2023, 6, 1, 12, 0, 0).unwrap();
+
+        assert_eq!(clock_skew(server, header, 5), None);
+        assert_eq!(
+            clock_skew(server + chrono::Duration::minutes(4), header, 5),
+            None
+        );
+        assert_eq!(
+            clock_skew(server + chrono::Duration::minutes(12), header, 5),
+            Some(12 * 60)
+        );
+        assert_eq!(
+            clock_skew(server - chrono::Duration::hours(1), header, 5),
+            Some(-3600)
+        );
+        // Disabled, or an unreadable header
+        assert_eq!(
+            clock_skew(server + chrono::Duration::hours(1), header, 0),
+            None
+        );
+        assert_eq!(clock_skew(server, "yesterday", 5), None);
+
+        assert_eq!(
+            skew_message(-3900),
+            "Your device clock looks 1h 5m behind, so the times shown may be wrong"
+        );
+    }
+}
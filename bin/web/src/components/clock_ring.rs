@@ -0,0 +1,185 @@
+//! Clock ring component
+//!
+//! Draws a compact 24-hour ring for one zone: midnight at the top, the work
+//! hours as a highlighted arc, a hand at the current local time and a
+//! seconds hand.
+//!
+//! The seconds hand sweeps with a CSS animation rather than per-frame
+//! updates, so the browser interpolates it smoothly and throttles it in
+//! hidden tabs. Each render restarts the sweep at the exact local second,
+//! and pausing stops it in place.
+
+use chrono::{DateTime, FixedOffset, NaiveTime, Timelike};
+use leptos::prelude::*;
+use longtime_core::TimezoneConfig;
+
+/// Minutes in a day, one full turn of the ring
+const DAY_MINUTES: u32 = 24 * 60;
+
+/// Center of the ring in SVG units
+const CENTER: f64 = 50.0;
+
+/// Radius of the ring track and arc
+const RING_RADIUS: f64 = 40.0;
+
+/// Length of the hand
+const HAND_RADIUS: f64 = 30.0;
+
+/// Length of the seconds hand
+const SECONDS_RADIUS: f64 = 34.0;
+
+/// Returns the point on a circle at a time of day, clockwise from midnight at the top
+///
+/// # Arguments
+///
+/// * `minutes` - Minutes since midnight
+/// * `radius` - Distance from the center
+fn ring_point(minutes: u32, radius: f64) -> (f64, f64) {
+    let angle = f64::from(minutes) / f64::from(DAY_MINUTES) * std::f64::consts::TAU;
+    (CENTER + radius * angle.sin(), CENTER - radius * angle.cos())
+}
+
+/// Minutes since midnight of a time of day
+fn minutes_of_day(time: NaiveTime) -> u32 {
+    time.hour() * 60 + time.minute()
+}
+
+/// Seconds into the current minute, including the fraction
+///
+/// Used as a negative animation delay, so the sweep starts where the
+/// seconds hand should be.
+fn seconds_into_minute(time: NaiveTime) -> f64 {
+    f64::from(time.second()) + f64::from(time.nanosecond().min(999_999_999)) / 1e9
+}
+
+/// Builds the SVG path of the work hours arc
+///
+/// The arc runs clockwise from `start` to `end`, so ranges ending before they
+/// start wrap through midnight.
+///
+/// # Arguments
+///
+/// * `start` - Start of work
+/// * `end` - End of work
+///
+/// # Returns
+///
+/// * `Option<String>` - The path, or None for an empty range
+fn work_arc_path(start: NaiveTime, end: NaiveTime) -> Option<String> {
+    let (start, end) = (minutes_of_day(start), minutes_of_day(end));
+    let span = (end + DAY_MINUTES - start) % DAY_MINUTES;
+    if span == 0 {
+        return None;
+    }
+    let (x1, y1) = ring_point(start, RING_RADIUS);
+    let (x2, y2) = ring_point(end, RING_RADIUS);
+    let large_arc = u8::from(span > DAY_MINUTES / 2);
+    Some(format!(
+        "M {x1:.2} {y1:.2} A {RING_RADIUS} {RING_RADIUS} 0 {large_arc} 1 {x2:.2} {y2:.2}"
+    ))
+}
+
+/// Clock ring component
+#[component]
+pub fn ClockRing(
+    /// The zone whose work hours are highlighted
+    config: TimezoneConfig,
+    /// Current local time of the zone
+    local_time: DateTime<FixedOffset>,
+    /// Whether time is running; the seconds hand stops while paused
+    running: bool,
+) -> impl IntoView {
+    let arc = if config.always_working {
+        view! { <circle class="clock-ring-arc" cx=CENTER cy=CENTER r=RING_RADIUS /> }.into_any()
+    } else {
+        config
+            .work_hours
+            .start_time()
+            .zip(config.work_hours.end_time())
+            .and_then(|(start, end)| work_arc_path(start, end))
+            .map(|path| view! { <path class="clock-ring-arc" d=path /> })
+            .into_any()
+    };
+    let (hand_x, hand_y) = ring_point(minutes_of_day(local_time.time()), HAND_RADIUS);
+    let labels = [(0, "0"), (360, "6"), (720, "12"), (1080, "18")].map(|(minutes, label)| {
+        let (x, y) = ring_point(minutes, RING_RADIUS - 12.0);
+        view! {
+          <text class="clock-ring-label" x=format!("{x:.2}") y=format!("{y:.2}")>
+            {label}
+          </text>
+        }
+    });
+
+    view! {
+      <svg
+        class="clock-ring"
+        viewBox="0 0 100 100"
+        role="img"
+        aria-label=format!(
+          "24-hour clock at {}, work hours {}-{}",
+          local_time.format("%H:%M"),
+          config.work_hours.start,
+          config.work_hours.end,
+        )
+      >
+        <circle class="clock-ring-track" cx=CENTER cy=CENTER r=RING_RADIUS />
+        {arc}
+        {labels.into_iter().collect_view()}
+        <line
+          class="clock-ring-hand"
+          x1=CENTER
+          y1=CENTER
+          x2=format!("{hand_x:.2}")
+          y2=format!("{hand_y:.2}")
+        />
+        <line
+          class=if running { "clock-ring-seconds" } else { "clock-ring-seconds clock-ring-paused" }
+          x1=CENTER
+          y1=CENTER
+          x2=CENTER
+          y2=CENTER - SECONDS_RADIUS
+          style=format!("animation-delay: -{:.3}s", seconds_into_minute(local_time.time()))
+        />
+      </svg>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn time(hour: u32, minute: u32) -> NaiveTime {
+        NaiveTime::from_hms_opt(hour, minute, 0).unwrap()
+    }
+
+    #[test]
+    fn test_ring_point() {
+        assert_eq!(ring_point(0, 40.0), (50.0, 10.0));
+        let (x, y) = ring_point(360, 40.0);
+        assert!((x - 90.0).abs() < 1e-9 && (y - 50.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_seconds_into_minute() {
+        let time = NaiveTime::from_hms_milli_opt(9, 30, 15, 250).unwrap();
+        assert_eq!(seconds_into_minute(time), 15.25);
+        // A leap second stays within the minute's sweep
+        let leap = NaiveTime::from_hms_nano_opt(23, 59, 59, 1_500_000_000).unwrap();
+        assert!(seconds_into_minute(leap) < 60.0);
+    }
+
+    #[test]
+    fn test_work_arc_path() {
+        // 09:00-17:00 is a third of the day: the short arc
+        assert_eq!(
+            work_arc_path(time(9, 0), time(17, 0)).unwrap(),
+            "M 78.28 78.28 A 40 40 0 0 1 11.36 60.35"
+        );
+        // Overnight 18:00-08:00 wraps through midnight on the long arc
+        assert_eq!(
+            work_arc_path(time(18, 0), time(8, 0)).unwrap(),
+            "M 10.00 50.00 A 40 40 0 1 1 84.64 70.00"
+        );
+        assert_eq!(work_arc_path(time(9, 0), time(9, 0)), None);
+    }
+}
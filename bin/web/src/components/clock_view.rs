@@ -0,0 +1,171 @@
+//! Clock view component
+//!
+//! Renders a timezone's time, date, diff and work status from a
+//! `TimeDisplayInfo`, so every surface displays clocks consistently.
+
+use leptos::prelude::*;
+use longtime_core::{DiffStyle, TimeDisplayInfo, WorkStatus, format_diff, format_hours_minutes};
+
+/// Clock view component
+#[component]
+pub fn ClockView(
+    /// Display information for the timezone
+    info: TimeDisplayInfo,
+    /// Whether to show the date and diff line
+    #[prop(default = true)]
+    show_date: bool,
+    /// Whether to show the work status line
+    #[prop(default = true)]
+    show_status: bool,
+    /// Whether to show the diff next to the date
+    #[prop(default = true)]
+    show_diff: bool,
+    /// Day offset chip shown after the date, e.g. "+1d"
+    #[prop(default = None)]
+    day_chip: Option<String>,
+    /// How the diff is worded
+    #[prop(optional)]
+    diff_style: DiffStyle,
+    /// Name of the zone the diff is relative to
+    #[prop(optional, into)]
+    reference_label: Option<String>,
+    /// Name of the reference zone spelled out in the diff tooltip
+    #[prop(optional, into)]
+    reference_name: Option<String>,
+) -> impl IntoView {
+    let diff_title = reference_name.map(|name| diff_tooltip(info.diff_hours, &name));
+    let diff_str = match reference_label {
+        Some(label) => format!("{} vs {label}", format_diff(info.diff_hours, diff_style)),
+        None => format_diff(info.diff_hours, diff_style),
+    };
+    let (dot_class, text_class, label) = status_badge(info.status);
+
+    view! {
+      <div>
+        // Time
+        <div class="mb-2 text-4xl time-display">{info.time}</div>
+        // Date and diff
+        {show_date
+          .then(|| {
+            view! {
+              <div class="flex justify-between items-center font-mono text-sm">
+                <span class="text-text-secondary">
+                  {info.date}
+                  {day_chip
+                    .map(|chip| {
+                      view! {
+                        <span class="day-chip" title="Calendar days from the reference clock's date">
+                          {chip}
+                        </span>
+                      }
+                    })}
+                </span>
+                {show_diff.then(|| view! { <span class="text-accent" title=diff_title>{diff_str}</span> })}
+              </div>
+            }
+          })}
+        // Work status
+        {show_status
+          .then(|| {
+            view! {
+              <div class="flex gap-2 items-center mt-3 font-mono text-sm">
+                <span class=dot_class role="img" aria-label=status_text(info.status)></span>
+                <span class=text_class>{label}</span>
+              </div>
+            }
+          })}
+      </div>
+    }
+}
+
+/// Get the status dot class, text class and label of a work status
+///
+/// # Arguments
+///
+/// * `status` - The zone's work status
+///
+/// # Returns
+///
+/// * `(&'static str, &'static str, String)` - Dot class, text class and
+///   label, e.g. "[ONLINE]"
+pub fn status_badge(status: WorkStatus) -> (&'static str, &'static str, String) {
+    match status {
+        WorkStatus::Working => (
+            "status-dot status-online",
+            "text-working",
+            "[ONLINE]".to_string(),
+        ),
+        WorkStatus::EndingSoon => (
+            "status-dot status-ending",
+            "text-ending",
+            "[ENDING SOON]".to_string(),
+        ),
+        WorkStatus::Grace => (
+            "status-dot status-grace",
+            "text-grace",
+            "[GRACE]".to_string(),
+        ),
+        WorkStatus::Off(reason) => (
+            "status-dot status-offline",
+            "text-off",
+            format!("[OFFLINE · {}]", reason.label().to_uppercase()),
+        ),
+        WorkStatus::InvalidHours => (
+            "status-dot status-invalid",
+            "text-invalid",
+            "[BAD HOURS]".to_string(),
+        ),
+    }
+}
+
+/// Spells out a diff for the tooltip, e.g. "8h 0m ahead of London (your reference)"
+///
+/// # Arguments
+///
+/// * `diff_hours` - Difference from the reference zone in hours
+/// * `reference_name` - Name of the reference zone
+///
+/// # Returns
+///
+/// * `String` - The tooltip text
+pub fn diff_tooltip(diff_hours: f64, reference_name: &str) -> String {
+    let seconds = (diff_hours * 3600.0).round() as i64;
+    let reference = format!("{reference_name} (your reference)");
+    match seconds.signum() {
+        1 => format!("{} ahead of {reference}", format_hours_minutes(seconds)),
+        -1 => format!("{} behind {reference}", format_hours_minutes(seconds)),
+        _ => format!("Same time as {reference}"),
+    }
+}
+
+/// Describes a work status in words
+pub fn status_text(status: WorkStatus) -> String {
+    match status {
+        WorkStatus::Working => "working".to_string(),
+        WorkStatus::EndingSoon => "working, ending soon".to_string(),
+        WorkStatus::Grace => "reachable, in the grace period".to_string(),
+        WorkStatus::Off(reason) => format!("off ({})", reason.label()),
+        WorkStatus::InvalidHours => "work hours could not be read".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_diff_tooltip() {
+        assert_eq!(
+            diff_tooltip(8.0, "London"),
+            "8h 0m ahead of London (your reference)"
+        );
+        assert_eq!(
+            diff_tooltip(-3.5, "Tokyo"),
+            "3h 30m behind Tokyo (your reference)"
+        );
+        assert_eq!(
+            diff_tooltip(0.0, "UTC"),
+            "Same time as UTC (your reference)"
+        );
+    }
+}
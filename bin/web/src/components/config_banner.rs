@@ -0,0 +1,38 @@
+//! Configuration warning banner component
+//!
+//! Points out a `home_timezone` that cannot be used, since diffs then
+//! quietly follow the selected zone instead of the configured home.
+
+use leptos::prelude::*;
+
+use crate::state::AppState;
+
+/// Configuration warning banner component
+#[component]
+pub fn ConfigBanner() -> impl IntoView {
+    let state = expect_context::<AppState>();
+    // The dismissed warning; a different one is shown again
+    let dismissed = RwSignal::new(None::<String>);
+
+    move || {
+        state
+            .config
+            .with(|config| config.home_timezone_warning())
+            .filter(|warning| dismissed.with(|dismissed| dismissed.as_ref() != Some(warning)))
+            .map(|warning| {
+                let shown = warning.clone();
+                view! {
+                  <div class="config-banner" role="alert">
+                    <span>{warning}</span>
+                    <button
+                      class="py-1 px-2 font-mono text-xs btn-terminal"
+                      title="Dismiss this warning"
+                      on:click=move |_| dismissed.set(Some(shown.clone()))
+                    >
+                      "Dismiss"
+                    </button>
+                  </div>
+                }
+            })
+    }
+}
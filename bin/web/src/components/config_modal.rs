@@ -3,9 +3,16 @@
 //! Provides a modal dialog for adding or editing timezone configurations.
 
 use leptos::prelude::*;
-use longtime_core::{TimezoneConfig, WorkHours};
+use longtime_core::{
+    TimezoneConfig, canonicalize_timezone, is_valid_timezone, same_timezone, timezone_region,
+    timezone_regions, timezones_in_region,
+};
 
-use crate::{state::AppState, storage::save_config};
+use crate::{
+    focus::{active_element, focus_first, trap_tab},
+    state::AppState,
+    storage::{load_recent_timezones, remember_timezone, save_config},
+};
 
 /// Close/X SVG icon
 #[component]
@@ -52,6 +59,79 @@ const TIMEZONE_OPTIONS: &[(&str, &str)] = &[
     ("UTC", "UTC"),
 ];
 
+/// Maps a per-zone format override to its select value
+fn clock_format_value(use_12h_format: Option<bool>) -> &'static str {
+    match use_12h_format {
+        Some(true) => "12h",
+        Some(false) => "24h",
+        None => "",
+    }
+}
+
+/// Parses a select value back into a per-zone format override
+fn parse_clock_format(value: &str) -> Option<bool> {
+    match value {
+        "12h" => Some(true),
+        "24h" => Some(false),
+        _ => None,
+    }
+}
+
+/// Longest reminder the modal accepts, one day in minutes
+const MAX_REMIND_BEFORE: u32 = 24 * 60;
+
+/// Parses the reminder field, clamped to `0..=MAX_REMIND_BEFORE`
+///
+/// # Arguments
+///
+/// * `value` - The field contents; blank turns the reminder off
+/// * `current` - The value kept when the field does not hold a number
+fn parse_remind_before(value: &str, current: u32) -> u32 {
+    let value = value.trim();
+    if value.is_empty() {
+        return 0;
+    }
+    value.parse::<i64>().map_or(current, |minutes| {
+        minutes.clamp(0, i64::from(MAX_REMIND_BEFORE)) as u32
+    })
+}
+
+/// Checks the display name, which must not be blank
+fn name_problem(name: &str) -> Option<String> {
+    name.trim()
+        .is_empty()
+        .then(|| "Enter a display name, e.g. \"Shanghai Office\"".to_string())
+}
+
+/// Checks that the timezone is a known IANA identifier
+fn timezone_problem(timezone: &str) -> Option<String> {
+    (!is_valid_timezone(timezone)).then(|| format!("Unknown timezone \"{timezone}\""))
+}
+
+/// Finds another configured zone on the same timezone, aliases included
+///
+/// # Arguments
+///
+/// * `zones` - The configured zones
+/// * `editing` - Index of the zone being edited, which is not compared
+/// * `timezone` - The timezone entered in the form
+fn duplicate_notice(
+    zones: &[TimezoneConfig],
+    editing: Option<usize>,
+    timezone: &str,
+) -> Option<String> {
+    zones
+        .iter()
+        .enumerate()
+        .find(|(index, tz)| Some(*index) != editing && same_timezone(&tz.timezone, timezone))
+        .map(|(_, tz)| {
+            format!(
+                "\"{}\" already uses this timezone ({})",
+                tz.name, tz.timezone
+            )
+        })
+}
+
 /// Configuration modal component
 #[component]
 pub fn ConfigModal() -> impl IntoView {
@@ -62,12 +142,87 @@ pub fn ConfigModal() -> impl IntoView {
     let timezone = RwSignal::new(String::from("Asia/Shanghai"));
     let work_start = RwSignal::new(String::from("09:00"));
     let work_end = RwSignal::new(String::from("17:00"));
+    // Per-zone clock format: "" follows the global setting, otherwise "12h"/"24h"
+    let clock_format = RwSignal::new(String::new());
+    // Whether the zone is covered around the clock (24/7)
+    let always_working = RwSignal::new(false);
+    // Minutes before work starts in which the card pulses, 0 for never
+    let remind_before = RwSignal::new(0_u32);
+    // Recently added or edited timezone identifiers, most recent first
+    let recent = RwSignal::new(Vec::<String>::new());
+    // Region narrowing the timezone list; "" lists the common timezones
+    let region = RwSignal::new(String::new());
+
+    // Saves the entry, from the Save button or Enter in any field
+    let save = {
+        let state = state.clone();
+        move || {
+            if name_problem(&name.get()).is_some() || timezone_problem(&timezone.get()).is_some() {
+                return;
+            }
+            // Keep settings the form does not edit when updating an entry
+            let mut tz_config: TimezoneConfig = state
+                .editing_index
+                .get()
+                .and_then(|index| state.config.get().timezones.get(index).cloned())
+                .unwrap_or_default();
+            tz_config.name = name.get().trim().to_string();
+            // Deprecated aliases such as "Asia/Calcutta" are stored canonically
+            tz_config.timezone =
+                canonicalize_timezone(&timezone.get()).unwrap_or_else(|| timezone.get());
+            tz_config.work_hours.start = work_start.get();
+            tz_config.work_hours.end = work_end.get();
+            tz_config.use_12h_format = parse_clock_format(&clock_format.get());
+            tz_config.always_working = always_working.get();
+            tz_config.remind_before_minutes = remind_before.get();
+            remember_timezone(&tz_config.timezone);
+            state.config.update(|config| {
+                if let Some(index) = state.editing_index.get() {
+                    if index < config.timezones.len() {
+                        config.timezones[index] = tz_config;
+                    }
+                } else {
+                    config.timezones.push(tz_config);
+                }
+            });
+            save_config(&state.config.get());
+            state.close_modal();
+        }
+    };
+
+    // Focus stays inside the open modal and returns to the element that
+    // opened it once closed
+    let modal_ref = NodeRef::<leptos::html::Div>::new();
+    let opener = StoredValue::new_local(None::<web_sys::HtmlElement>);
+    {
+        let state = state.clone();
+        Effect::new(move |was_open: Option<bool>| {
+            let open = state.show_config_modal.get();
+            if open && was_open != Some(true) {
+                opener.set_value(active_element());
+                // Wait for the modal to be rendered
+                request_animation_frame(move || {
+                    if let Some(modal) = modal_ref.get_untracked() {
+                        focus_first(&modal);
+                    }
+                });
+            } else if !open && was_open == Some(true) {
+                if let Some(element) = opener.get_value() {
+                    let _ = element.focus();
+                }
+                opener.set_value(None);
+            }
+            open
+        });
+    }
 
     // Initialize form when modal opens
     {
         let state = state.clone();
         Effect::new(move || {
             if state.show_config_modal.get() {
+                recent.set(load_recent_timezones());
+                region.set(String::new());
                 if let Some(index) = state.editing_index.get() {
                     // Editing existing timezone
                     let config = state.config.get();
@@ -76,6 +231,9 @@ pub fn ConfigModal() -> impl IntoView {
                         timezone.set(tz.timezone.clone());
                         work_start.set(tz.work_hours.start.clone());
                         work_end.set(tz.work_hours.end.clone());
+                        clock_format.set(clock_format_value(tz.use_12h_format).to_string());
+                        always_working.set(tz.always_working);
+                        remind_before.set(tz.remind_before_minutes);
                     }
                 } else {
                     // Adding new timezone
@@ -83,6 +241,9 @@ pub fn ConfigModal() -> impl IntoView {
                     timezone.set(String::from("Asia/Shanghai"));
                     work_start.set(String::from("09:00"));
                     work_end.set(String::from("17:00"));
+                    clock_format.set(String::new());
+                    always_working.set(false);
+                    remind_before.set(0);
                 }
             }
         });
@@ -106,7 +267,15 @@ pub fn ConfigModal() -> impl IntoView {
         <div class="flex fixed inset-0 z-50 justify-center items-center p-4">
           <div
             class="w-full max-w-md modal-content"
+            role="dialog"
+            aria-modal="true"
+            node_ref=modal_ref
             on:click=|e: web_sys::MouseEvent| e.stop_propagation()
+            on:keydown=move |e: web_sys::KeyboardEvent| {
+              if let Some(modal) = modal_ref.get_untracked() {
+                trap_tab(&modal, &e);
+              }
+            }
           >
             // Header
             <div class="flex justify-between items-center mb-6">
@@ -135,7 +304,17 @@ pub fn ConfigModal() -> impl IntoView {
             </div>
 
             // Form
-            <form class="space-y-4" on:submit=|e: web_sys::SubmitEvent| e.prevent_default()>
+            <form
+              class="space-y-4"
+              on:submit={
+                let save = save.clone();
+                move |e: web_sys::SubmitEvent| {
+                  // Keep the page from reloading
+                  e.prevent_default();
+                  save();
+                }
+              }
+            >
               // Name input
               <div>
                 <label class="block mb-1 font-mono text-sm text-text-secondary">
@@ -149,6 +328,10 @@ pub fn ConfigModal() -> impl IntoView {
                   prop:value=move || name.get()
                   on:input=move |e| name.set(event_target_value(&e))
                 />
+                {move || {
+                  name_problem(&name.get())
+                    .map(|message| view! { <p class="mt-1 font-mono text-xs text-off">{message}</p> })
+                }}
               </div>
 
               // Timezone select
@@ -157,18 +340,91 @@ pub fn ConfigModal() -> impl IntoView {
                   <span class="text-primary/50">"# "</span>
                   "timezone"
                 </label>
+                // Quick picks from recently used timezones
+                {move || {
+                  let ids = recent.get();
+                  (!ids.is_empty())
+                    .then(|| {
+                      view! {
+                        <div class="flex flex-wrap gap-1 mb-2">
+                          {ids
+                            .into_iter()
+                            .map(|id| {
+                              let label = id.clone();
+                              view! {
+                                <button
+                                  type="button"
+                                  class="py-1 px-2 font-mono text-xs btn-terminal"
+                                  title="Use this recent timezone"
+                                  on:click=move |_| timezone.set(id.clone())
+                                >
+                                  {label}
+                                </button>
+                              }
+                            })
+                            .collect_view()}
+                        </div>
+                      }
+                    })
+                }}
+                // Optional region filter for the full list of timezones
+                <select
+                  class="mb-2 w-full input-terminal"
+                  aria-label="Region"
+                  prop:value=move || region.get()
+                  on:change=move |e| {
+                    let selected = event_target_value(&e);
+                    // Keep the timezone if it is in the region, otherwise pick the first one
+                    let current = timezone.get_untracked();
+                    if !selected.is_empty() && timezone_region(&current) != Some(selected.as_str())
+                      && let Some(first) = timezones_in_region(&selected).first()
+                    {
+                      timezone.set(first.to_string());
+                    }
+                    region.set(selected);
+                  }
+                >
+                  <option value="">"Common timezones"</option>
+                  {timezone_regions()
+                    .into_iter()
+                    .map(|name| view! { <option value=name>{name}</option> })
+                    .collect_view()}
+                </select>
                 <select
                   class="w-full input-terminal"
                   prop:value=move || timezone.get()
                   on:change=move |e| timezone.set(event_target_value(&e))
                 >
-                  {TIMEZONE_OPTIONS
-                    .iter()
-                    .map(|(value, label)| {
-                      view! { <option value=*value>{*label}</option> }
-                    })
-                    .collect_view()}
+                  {move || {
+                    let region = region.get();
+                    if region.is_empty() {
+                      TIMEZONE_OPTIONS
+                        .iter()
+                        .map(|(value, label)| {
+                          view! { <option value=*value>{*label}</option> }
+                        })
+                        .collect_view()
+                        .into_any()
+                    } else {
+                      timezones_in_region(&region)
+                        .into_iter()
+                        .map(|id| view! { <option value=id>{id.replace('_', " ")}</option> })
+                        .collect_view()
+                        .into_any()
+                    }
+                  }}
                 </select>
+                {move || {
+                  timezone_problem(&timezone.get())
+                    .map(|message| view! { <p class="mt-1 font-mono text-xs text-off">{message}</p> })
+                }}
+                {
+                  let state = state.clone();
+                  move || {
+                    duplicate_notice(&state.config.get().timezones, state.editing_index.get(), &timezone.get())
+                      .map(|message| view! { <p class="mt-1 font-mono text-xs text-ending">{message}</p> })
+                  }
+                }
               </div>
 
               // Work hours
@@ -199,6 +455,61 @@ pub fn ConfigModal() -> impl IntoView {
                 </div>
               </div>
 
+              // Clock format override
+              <div>
+                <label class="block mb-1 font-mono text-sm text-text-secondary">
+                  <span class="text-primary/50">"# "</span>
+                  "clock_format"
+                </label>
+                <select
+                  class="w-full input-terminal"
+                  prop:value=move || clock_format.get()
+                  on:change=move |e| clock_format.set(event_target_value(&e))
+                >
+                  <option value="">"Global setting"</option>
+                  <option value="12h">"12h"</option>
+                  <option value="24h">"24h"</option>
+                </select>
+              </div>
+
+              // Around-the-clock coverage
+              <div>
+                <label class="block mb-1 font-mono text-sm text-text-secondary">
+                  <span class="text-primary/50">"# "</span>
+                  "coverage"
+                </label>
+                <select
+                  class="w-full input-terminal"
+                  prop:value=move || if always_working.get() { "24/7" } else { "" }
+                  on:change=move |e| always_working.set(event_target_value(&e) == "24/7")
+                >
+                  <option value="">"Work hours"</option>
+                  <option value="24/7">"Always working (24/7)"</option>
+                </select>
+              </div>
+
+              // Work start reminder
+              <div>
+                <label class="block mb-1 font-mono text-sm text-text-secondary">
+                  <span class="text-primary/50">"# "</span>
+                  "remind_before_minutes"
+                </label>
+                <input
+                  type="number"
+                  min="0"
+                  max=MAX_REMIND_BEFORE
+                  class="w-full input-terminal"
+                  title="Pulse the card this many minutes before work starts (0 to turn off)"
+                  prop:value=move || remind_before.get().to_string()
+                  on:input=move |e| {
+                    remind_before
+                      .update(|minutes| {
+                        *minutes = parse_remind_before(&event_target_value(&e), *minutes);
+                      })
+                  }
+                />
+              </div>
+
               // Buttons
               <div class="flex gap-3 pt-4">
                 <button
@@ -213,31 +524,8 @@ pub fn ConfigModal() -> impl IntoView {
                 </button>
                 <button
                   type="submit"
-                  on:click={
-                    let state = state.clone();
-                    move |_| {
-                      let tz_config = TimezoneConfig {
-                        name: name.get(),
-                        timezone: timezone.get(),
-                        work_hours: WorkHours {
-                          start: work_start.get(),
-                          end: work_end.get(),
-                        },
-                      };
-                      state
-                        .config
-                        .update(|config| {
-                          if let Some(index) = state.editing_index.get() {
-                            if index < config.timezones.len() {
-                              config.timezones[index] = tz_config;
-                            }
-                          } else {
-                            config.timezones.push(tz_config);
-                          }
-                        });
-                      save_config(&state.config.get());
-                      state.close_modal();
-                    }
+                  prop:disabled=move || {
+                    name_problem(&name.get()).is_some() || timezone_problem(&timezone.get()).is_some()
                   }
                   class="flex-1 font-semibold btn-primary"
                 >
@@ -250,3 +538,44 @@ pub fn ConfigModal() -> impl IntoView {
       </Show>
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_form_checks() {
+        assert!(name_problem("").is_some());
+        assert!(name_problem("   ").is_some());
+        assert_eq!(name_problem(" Tokyo "), None);
+
+        assert_eq!(timezone_problem("Asia/Tokyo"), None);
+        assert_eq!(
+            timezone_problem("Mars/Olympus"),
+            Some("Unknown timezone \"Mars/Olympus\"".to_string())
+        );
+
+        let zones = [TimezoneConfig {
+            name: "Mumbai".to_string(),
+            timezone: "Asia/Calcutta".to_string(),
+            ..Default::default()
+        }];
+        assert_eq!(
+            duplicate_notice(&zones, None, "Asia/Kolkata"),
+            Some("\"Mumbai\" already uses this timezone (Asia/Calcutta)".to_string())
+        );
+        assert_eq!(duplicate_notice(&zones, Some(0), "Asia/Kolkata"), None);
+        assert_eq!(duplicate_notice(&zones, None, "Asia/Tokyo"), None);
+    }
+
+    #[test]
+    fn test_parse_remind_before() {
+        assert_eq!(parse_remind_before("15", 0), 15);
+        assert_eq!(parse_remind_before(" 30 ", 0), 30);
+        assert_eq!(parse_remind_before("", 15), 0);
+        assert_eq!(parse_remind_before("5000", 0), MAX_REMIND_BEFORE);
+        assert_eq!(parse_remind_before("-10", 15), 0);
+        // Not a number: the previous value stays
+        assert_eq!(parse_remind_before("1e3", 15), 15);
+    }
+}
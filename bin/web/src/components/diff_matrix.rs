@@ -0,0 +1,68 @@
+//! Diff matrix component
+//!
+//! Displays an N×N table of pairwise offset differences between the enabled
+//! timezones at the simulated instant.
+
+use leptos::prelude::*;
+use longtime_core::{format_time_diff, offset_matrix};
+
+use crate::state::AppState;
+
+/// Diff matrix component
+#[component]
+pub fn DiffMatrix() -> impl IntoView {
+    let state = expect_context::<AppState>();
+
+    view! {
+      <div class="card-terminal diff-matrix">
+        <p class="mb-3 font-mono text-xs text-text-secondary">
+          <span class="text-primary/40">"# "</span>
+          "column zone relative to row zone"
+        </p>
+        {move || {
+          let config = state.config.get();
+          let zones: Vec<_> = config.timezones.iter().filter(|tz| tz.enabled).collect();
+          let matrix = offset_matrix(state.current_time(), &zones);
+          view! {
+            <table class="w-full font-mono text-sm">
+              <thead>
+                <tr>
+                  <th></th>
+                  {zones
+                    .iter()
+                    .map(|tz| view! { <th class="text-primary">{tz.name.clone()}</th> })
+                    .collect_view()}
+                </tr>
+              </thead>
+              <tbody>
+                {zones
+                  .iter()
+                  .zip(matrix)
+                  .enumerate()
+                  .map(|(i, (tz, row))| {
+                    view! {
+                      <tr>
+                        <th class="text-primary">{tz.name.clone()}</th>
+                        {row
+                          .into_iter()
+                          .enumerate()
+                          .map(|(j, diff)| {
+                            let text = match diff {
+                              _ if i == j => String::new(),
+                              Some(seconds) => format_time_diff(f64::from(seconds) / 3600.0),
+                              None => "?".to_string(),
+                            };
+                            view! { <td class="text-accent">{text}</td> }
+                          })
+                          .collect_view()}
+                      </tr>
+                    }
+                  })
+                  .collect_view()}
+              </tbody>
+            </table>
+          }
+        }}
+      </div>
+    }
+}
@@ -3,8 +3,12 @@
 //! Displays the application title, share button, and settings controls.
 
 use leptos::prelude::*;
+use longtime_core::{MAX_GRID_COLUMNS, PRESETS};
 
-use crate::{state::AppState, storage::generate_share_url};
+use crate::{
+    state::AppState,
+    storage::{generate_share_url, generate_view_url},
+};
 
 /// Clock SVG icon
 #[component]
@@ -118,6 +122,28 @@ fn MoonIcon() -> impl IntoView {
     }
 }
 
+/// Monitor SVG icon (for present mode)
+#[component]
+fn PresentIcon() -> impl IntoView {
+    view! {
+      <svg
+        xmlns="http://www.w3.org/2000/svg"
+        width="16"
+        height="16"
+        viewBox="0 0 24 24"
+        fill="none"
+        stroke="currentColor"
+        stroke-width="2"
+        stroke-linecap="round"
+        stroke-linejoin="round"
+      >
+        <rect width="20" height="14" x="2" y="3" rx="2" />
+        <line x1="8" y1="21" x2="16" y2="21" />
+        <line x1="12" y1="17" x2="12" y2="21" />
+      </svg>
+    }
+}
+
 /// Header component with title and action buttons
 #[component]
 pub fn Header() -> impl IntoView {
@@ -131,7 +157,10 @@ pub fn Header() -> impl IntoView {
             <ClockIcon />
             <h1 class="font-mono text-xl font-bold tracking-wider text-primary">
               <span class="text-primary/60">">"</span>
-              "LongTime"
+              {
+                let state = state.clone();
+                move || state.config.get().display_title().to_string()
+              }
               <span class="terminal-cursor">"_"</span>
             </h1>
           </div>
@@ -149,7 +178,195 @@ pub fn Header() -> impl IntoView {
             >
               {
                 let state = state.clone();
-                move || if state.config.get().use_12h_format { "12h" } else { "24h" }
+                move || if state.use_12h_format() { "12h" } else { "24h" }
+              }
+            </button>
+
+            // Diff visibility toggle
+            <button
+              on:click={
+                let state = state.clone();
+                move |_| state.toggle_diff()
+              }
+              class="font-mono text-sm btn-terminal"
+              title="Show/hide the diff on each card"
+            >
+              {
+                let state = state.clone();
+                move || if state.config.get().show_diff { "Diff on" } else { "Diff off" }
+              }
+            </button>
+
+            // Diff wording toggle
+            <button
+              on:click={
+                let state = state.clone();
+                move |_| state.toggle_diff_style()
+              }
+              class="font-mono text-sm btn-terminal"
+              title="Show diffs as numbers (+8) or words (8h ahead)"
+            >
+              {
+                let state = state.clone();
+                move || state.config.get().diff_style.label()
+              }
+            </button>
+
+            // Date style toggle
+            <button
+              on:click={
+                let state = state.clone();
+                move |_| state.cycle_date_style()
+              }
+              class="font-mono text-sm btn-terminal"
+              title="Cycle date format (ISO / US / EU / Long)"
+            >
+              {
+                let state = state.clone();
+                move || state.config.get().date_style.label()
+              }
+            </button>
+
+            // Grid column count
+            <select
+              class="font-mono text-sm input-terminal preset-select"
+              title="Number of card columns"
+              prop:value={
+                let state = state.clone();
+                move || {
+                  state.config.get().grid_columns.map(|columns| columns.to_string()).unwrap_or_default()
+                }
+              }
+              on:change={
+                let state = state.clone();
+                move |e| state.set_grid_columns(event_target_value(&e).parse().ok())
+              }
+            >
+              <option value="">"Cols: auto"</option>
+              {(1..=MAX_GRID_COLUMNS)
+                .map(|columns| {
+                  view! { <option value=columns.to_string()>{format!("Cols: {columns}")}</option> }
+                })
+                .collect_view()}
+            </select>
+
+            // Offset sort toggle
+            <button
+              on:click={
+                let state = state.clone();
+                move |_| state.toggle_sort_by_offset()
+              }
+              class="font-mono text-sm btn-terminal"
+              title="Toggle sorting by UTC offset"
+            >
+              {
+                let state = state.clone();
+                move || if state.sort_by_offset.get() { "By offset" } else { "As listed" }
+              }
+            </button>
+
+            // Status grouping toggle
+            <button
+              on:click={
+                let state = state.clone();
+                move |_| state.toggle_group_by_status()
+              }
+              class="font-mono text-sm btn-terminal"
+              title="Group working zones ahead of the others (G)"
+            >
+              {
+                let state = state.clone();
+                move || if state.group_by_status.get() { "Grouped" } else { "Ungrouped" }
+              }
+            </button>
+
+            // Card grid / table layout toggle
+            <button
+              on:click={
+                let state = state.clone();
+                move |_| state.toggle_table_view()
+              }
+              class="font-mono text-sm btn-terminal"
+              title="Switch between the card grid and the compact table (t)"
+            >
+              {
+                let state = state.clone();
+                move || if state.table_view.get() { "Show cards" } else { "Show table" }
+              }
+            </button>
+
+            // Diff matrix toggle
+            <button
+              on:click={
+                let state = state.clone();
+                move |_| state.toggle_matrix()
+              }
+              class="font-mono text-sm btn-terminal"
+              title="Toggle pairwise diff matrix (x)"
+            >
+              {
+                let state = state.clone();
+                move || if state.show_matrix.get() { "Cards" } else { "Matrix" }
+              }
+            </button>
+
+            // Reference clock hours toggle
+            <button
+              on:click={
+                let state = state.clone();
+                move |_| state.toggle_reference_hours()
+              }
+              class="font-mono text-sm btn-terminal"
+              title="Show each zone's work hours on the reference clock (c)"
+            >
+              {
+                let state = state.clone();
+                move || if state.show_reference_hours.get() { "Hours: ref" } else { "Hours: local" }
+              }
+            </button>
+
+            // UTC reference lock
+            <button
+              on:click={
+                let state = state.clone();
+                move |_| state.toggle_utc_reference()
+              }
+              class="font-mono text-sm btn-terminal"
+              title="Measure diffs against UTC instead of the reference zone (U)"
+            >
+              {
+                let state = state.clone();
+                move || if state.utc_reference.get() { "vs UTC" } else { "vs ref" }
+              }
+            </button>
+
+            // Focus mode toggle
+            <button
+              on:click={
+                let state = state.clone();
+                move |_| state.toggle_focus_mode()
+              }
+              class="font-mono text-sm btn-terminal"
+              title="Dim zones outside work hours (f)"
+            >
+              {
+                let state = state.clone();
+                move || if state.focus_mode.get() { "Focus on" } else { "Focus off" }
+              }
+            </button>
+
+            // Week view toggle
+            <button
+              on:click={
+                let state = state.clone();
+                move |_| state.toggle_week()
+              }
+              class="font-mono text-sm btn-terminal"
+              title="Toggle 7-day availability at this time (v)"
+            >
+              {
+                let state = state.clone();
+                move || if state.show_week.get() { "Cards" } else { "Week" }
               }
             </button>
 
@@ -166,26 +383,142 @@ pub fn Header() -> impl IntoView {
               <span class="hidden sm:inline">"Add"</span>
             </button>
 
+            // Bulk work hours
+            <button
+              on:click={
+                let state = state.clone();
+                move |_| state.show_bulk_hours.set(true)
+              }
+              class="font-mono text-sm btn-terminal"
+              title="Set the work hours of all zones at once"
+            >
+              "Set all hours"
+            </button>
+
+            // Preset picker
+            <select
+              class="font-mono text-sm input-terminal preset-select"
+              title="Add a group of common timezones"
+              prop:value=""
+              on:change={
+                let state = state.clone();
+                move |e| {
+                  let preset = event_target_value(&e).parse::<usize>().ok().and_then(|i| PRESETS.get(i));
+                  if let Some(preset) = preset {
+                    let added = state.apply_preset(preset);
+                    let message = format!("Added {added} zones from {}", preset.name);
+                    if let Ok(window) = crate::browser::window("show the preset result") {
+                      let _ = window.alert_with_message(&message);
+                    }
+                  }
+                  event_target::<web_sys::HtmlSelectElement>(&e).set_value("");
+                }
+              }
+            >
+              <option value="">"+ Preset"</option>
+              {PRESETS
+                .iter()
+                .enumerate()
+                .map(|(i, preset)| view! { <option value=i.to_string()>{preset.name}</option> })
+                .collect_view()}
+            </select>
+
             // Share button
+            <button
+              on:click={
+                let state = state.clone();
+                move |_| {
+                  let config = state.shared_config();
+                  // A missing window is already logged by the browser helper
+                  if let Ok(url) = generate_share_url(&config, state.share_muted.get()) {
+                    leptos::task::spawn_local(async move {
+                      crate::storage::copy_with_fallback(&url, "Link copied to clipboard!").await;
+                    });
+                  }
+                }
+              }
+              class="flex gap-1 items-center text-sm btn-terminal"
+              title="Copy shareable link (only the marked zones, if any)"
+            >
+              <ShareIcon />
+              <span class="hidden sm:inline">
+                {
+                  let state = state.clone();
+                  move || match state.marked.get().len() {
+                    0 => "Share".to_string(),
+                    marked => format!("Share {marked}"),
+                  }
+                }
+              </span>
+            </button>
+
+            // Whether the share link includes muted zones
+            <label
+              class="flex gap-1 items-center font-mono text-sm text-text-secondary"
+              title="Include muted zones in the share link"
+            >
+              <input
+                type="checkbox"
+                prop:checked={
+                  let state = state.clone();
+                  move || state.share_muted.get()
+                }
+                on:change={
+                  let state = state.clone();
+                  move |e| state.share_muted.set(event_target_checked(&e))
+                }
+              />
+              "muted"
+            </label>
+
+            // View link button
             <button
               on:click={
                 let state = state.clone();
                 move |_| {
                   let config = state.config.get();
-                  let url = generate_share_url(&config);
+                  if let Ok(url) = generate_view_url(&config, &state.view_state()) {
+                    leptos::task::spawn_local(async move {
+                      crate::storage::copy_with_fallback(&url, "View link copied to clipboard!").await;
+                    });
+                  }
+                }
+              }
+              class="flex gap-1 items-center text-sm btn-terminal"
+              title="Copy a link to this view (time offset, reference, sorting and grouping)"
+            >
+              <ShareIcon />
+              <span class="hidden sm:inline">"View"</span>
+            </button>
+
+            // Overlap report button
+            <button
+              on:click={
+                let state = state.clone();
+                move |_| {
+                  let report = state.overlap_report();
                   leptos::task::spawn_local(async move {
-                    if crate::storage::copy_to_clipboard(&url).await.is_ok() {
-                      let _ = web_sys::window()
-                        .and_then(|w| w.alert_with_message("Link copied to clipboard!").ok());
-                    }
+                    crate::storage::copy_with_fallback(&report, "Overlap report copied to clipboard!").await;
                   });
                 }
               }
+              class="font-mono text-sm btn-terminal"
+              title="Copy the work hour overlap of every pair of zones as Markdown"
+            >
+              "Overlaps"
+            </button>
+
+            // Present mode button
+            <button
+              on:click={
+                let state = state.clone();
+                move |_| state.toggle_present_mode()
+              }
               class="flex gap-1 items-center text-sm btn-terminal"
-              title="Copy shareable link"
+              title="Present mode for clean screenshots (p, Esc to exit)"
             >
-              <ShareIcon />
-              <span class="hidden sm:inline">"Share"</span>
+              <PresentIcon />
+              <span class="hidden sm:inline">"Present"</span>
             </button>
 
             // Theme toggle button
@@ -0,0 +1,78 @@
+//! Keyboard shortcut overlay
+//!
+//! Lists the shortcuts handled by the global keydown listener in `app.rs`.
+
+use leptos::prelude::*;
+
+use crate::state::AppState;
+
+/// Shortcut keys and what they do, in display order
+const SHORTCUTS: &[(&str, &str)] = &[
+    ("← / h", "Back 15 minutes"),
+    ("→ / l", "Forward 15 minutes"),
+    ("r", "Reset to now"),
+    ("Space", "Play/pause"),
+    ("[ / ]", "Select previous/next card as the reference"),
+    ("b", "Swap back to the previously selected card"),
+    ("1-9", "Jump to a quick time in the reference zone"),
+    ("0 / e", "Jump to 00:00 / 23:59 in the reference zone"),
+    ("t", "Switch between cards and the table"),
+    ("x", "Toggle the diff matrix"),
+    ("v", "Toggle the 7-day view"),
+    ("c", "Toggle work hours on the reference clock"),
+    ("f", "Toggle focus mode"),
+    ("U", "Lock diffs to UTC"),
+    ("G", "Group working zones first"),
+    ("p", "Toggle present mode"),
+    ("?", "Toggle this help"),
+    ("Esc", "Close dialog / exit present mode"),
+];
+
+/// Keyboard shortcut overlay component
+#[component]
+pub fn HelpModal() -> impl IntoView {
+    let state = expect_context::<AppState>();
+
+    view! {
+      <Show when={
+        let state = state.clone();
+        move || state.show_help.get()
+      }>
+        <div
+          class="modal-backdrop"
+          on:click={
+            let state = state.clone();
+            move |_| state.show_help.set(false)
+          }
+        ></div>
+        <div class="flex fixed inset-0 z-50 justify-center items-center p-4">
+          <div
+            class="p-6 w-full max-w-md modal-content"
+            role="dialog"
+            aria-label="Keyboard shortcuts"
+            on:click=|e: web_sys::MouseEvent| e.stop_propagation()
+          >
+            <h2 class="mb-6 font-mono text-xl font-bold text-primary">
+              <span class="text-primary/50">"$ "</span>
+              "shortcuts"
+            </h2>
+            <table class="w-full font-mono text-sm">
+              <tbody>
+                {SHORTCUTS
+                  .iter()
+                  .map(|(key, action)| {
+                    view! {
+                      <tr>
+                        <td class="pr-4 text-accent">{*key}</td>
+                        <td class="text-text-secondary">{*action}</td>
+                      </tr>
+                    }
+                  })
+                  .collect_view()}
+              </tbody>
+            </table>
+          </div>
+        </div>
+      </Show>
+    }
+}
@@ -2,14 +2,34 @@
 //!
 //! This module exports all UI components used in the application.
 
+pub mod bulk_hours_modal;
+pub mod clock_banner;
+pub mod clock_ring;
+pub mod clock_view;
+pub mod config_banner;
 pub mod config_modal;
+pub mod diff_matrix;
 pub mod header;
+pub mod help_modal;
+pub mod status_announcer;
 pub mod time_controls;
 pub mod timezone_card;
 pub mod timezone_list;
+pub mod timezone_row;
+pub mod week_view;
 
+pub use bulk_hours_modal::BulkHoursModal;
+pub use clock_banner::ClockBanner;
+pub use clock_ring::ClockRing;
+pub use clock_view::ClockView;
+pub use config_banner::ConfigBanner;
 pub use config_modal::ConfigModal;
+pub use diff_matrix::DiffMatrix;
 pub use header::Header;
+pub use help_modal::HelpModal;
+pub use status_announcer::StatusAnnouncer;
 pub use time_controls::TimeControls;
 pub use timezone_card::TimezoneCard;
 pub use timezone_list::TimezoneList;
+pub use timezone_row::TimezoneRow;
+pub use week_view::WeekView;
@@ -0,0 +1,103 @@
+//! Status announcer component
+//!
+//! Announces zones starting or finishing work through a visually hidden
+//! ARIA live region, so screen reader users hear about transitions as they
+//! happen.
+
+use leptos::prelude::*;
+use longtime_core::is_work_hours;
+
+use crate::state::AppState;
+
+/// Describes the zones whose work status changed between two checks
+///
+/// Zones are matched by name; zones only present in one of the checks (added,
+/// removed or renamed in between) are not announced.
+///
+/// # Arguments
+///
+/// * `previous` - Name and work status of each zone at the last check
+/// * `current` - Name and work status of each zone now
+///
+/// # Returns
+///
+/// * `Vec<String>` - One message per change, e.g. "New York is now working"
+fn status_changes(previous: &[(String, bool)], current: &[(String, bool)]) -> Vec<String> {
+    current
+        .iter()
+        .filter(|(name, working)| {
+            previous
+                .iter()
+                .any(|(before, was_working)| before == name && was_working != working)
+        })
+        .map(|(name, working)| {
+            let status = if *working { "working" } else { "off" };
+            format!("{name} is now {status}")
+        })
+        .collect()
+}
+
+/// Status announcer component
+///
+/// Only live time is announced: while time traveling or frozen the last
+/// statuses are forgotten, so returning to live time does not announce
+/// either. The first check after loading only records the statuses.
+#[component]
+pub fn StatusAnnouncer() -> impl IntoView {
+    let state = expect_context::<AppState>();
+    let previous = StoredValue::new(None::<Vec<(String, bool)>>);
+    let message = RwSignal::new(String::new());
+
+    Effect::new(move || {
+        let now = state.current_time();
+        if !state.is_live() {
+            previous.set_value(None);
+            return;
+        }
+        let current: Vec<_> = state
+            .config
+            .get()
+            .timezones
+            .iter()
+            .filter(|tz| tz.enabled)
+            .map(|tz| (tz.name.clone(), is_work_hours(now, tz)))
+            .collect();
+        if let Some(before) = previous.get_value() {
+            let changes = status_changes(&before, &current);
+            if !changes.is_empty() {
+                message.set(changes.join(". "));
+            }
+        }
+        previous.set_value(Some(current));
+    });
+
+    view! {
+      <div class="sr-only" aria-live="polite" aria-atomic="true">
+        {move || message.get()}
+      </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn statuses(zones: &[(&str, bool)]) -> Vec<(String, bool)> {
+        zones
+            .iter()
+            .map(|(name, working)| (name.to_string(), *working))
+            .collect()
+    }
+
+    #[test]
+    fn test_status_changes() {
+        let before = statuses(&[("New York", false), ("London", true), ("Tokyo", false)]);
+        let after = statuses(&[("New York", true), ("London", false), ("Sydney", true)]);
+        assert_eq!(
+            status_changes(&before, &after),
+            ["New York is now working", "London is now off"]
+        );
+        assert!(status_changes(&before, &before).is_empty());
+        assert!(status_changes(&[], &after).is_empty());
+    }
+}
@@ -3,8 +3,9 @@
 //! Provides controls for adjusting the time offset and pausing/resuming updates.
 
 use leptos::prelude::*;
+use longtime_core::{DayBoundary, format_signed_duration, parse_signed_duration};
 
-use crate::state::AppState;
+use crate::state::{AppState, FAST_FORWARD_STEPS};
 
 /// Reset/Refresh SVG icon
 #[component]
@@ -68,6 +69,78 @@ fn PauseIcon() -> impl IntoView {
     }
 }
 
+/// Fast-forward SVG icon
+#[component]
+fn FastForwardIcon() -> impl IntoView {
+    view! {
+      <svg
+        xmlns="http://www.w3.org/2000/svg"
+        width="14"
+        height="14"
+        viewBox="0 0 24 24"
+        fill="none"
+        stroke="currentColor"
+        stroke-width="2"
+        stroke-linecap="round"
+        stroke-linejoin="round"
+        aria-hidden="true"
+      >
+        <polygon points="13 19 22 12 13 5 13 19" />
+        <polygon points="2 19 11 12 2 5 2 19" />
+      </svg>
+    }
+}
+
+/// Pin SVG icon
+#[component]
+fn PinIcon() -> impl IntoView {
+    view! {
+      <svg
+        xmlns="http://www.w3.org/2000/svg"
+        width="14"
+        height="14"
+        viewBox="0 0 24 24"
+        fill="none"
+        stroke="currentColor"
+        stroke-width="2"
+        stroke-linecap="round"
+        stroke-linejoin="round"
+        aria-hidden="true"
+      >
+        <line x1="12" y1="17" x2="12" y2="22" />
+        <path d="M5 17h14v-1.76a2 2 0 0 0-1.11-1.79l-1.78-.9A2 2 0 0 1 15 10.76V6h1a2 2 0 0 0 0-4H8a2 2 0 0 0 0 4h1v4.76a2 2 0 0 1-1.11 1.79l-1.78.9A2 2 0 0 0 5 15.24Z" />
+      </svg>
+    }
+}
+
+/// Format a time offset for the offset display
+///
+/// Seconds are only shown when nonzero, and minutes only when either they
+/// or the seconds are.
+///
+/// # Arguments
+///
+/// * `offset_secs` - The offset in seconds
+///
+/// # Returns
+///
+/// * `String` - e.g. "[ NOW ]", "[+2h]", "[-0h 30m]" or "[+1h 05m 30s]"
+fn offset_label(offset_secs: i64) -> String {
+    if offset_secs == 0 {
+        return "[ NOW ]".to_string();
+    }
+    let sign = if offset_secs < 0 { '-' } else { '+' };
+    let total = offset_secs.unsigned_abs();
+    let (hours, minutes, seconds) = (total / 3600, total % 3600 / 60, total % 60);
+    if seconds != 0 {
+        format!("[{sign}{hours}h {minutes:02}m {seconds:02}s]")
+    } else if minutes != 0 {
+        format!("[{sign}{hours}h {minutes:02}m]")
+    } else {
+        format!("[{sign}{hours}h]")
+    }
+}
+
 /// Time controls component
 #[component]
 pub fn TimeControls() -> impl IntoView {
@@ -76,22 +149,14 @@ pub fn TimeControls() -> impl IntoView {
     // Format offset for display
     let offset_display = {
         let state = state.clone();
-        move || {
-            let offset_secs = state.time_offset.get();
-            if offset_secs == 0 {
-                "[ NOW ]".to_string()
-            } else {
-                let hours = offset_secs / 3600;
-                let minutes = (offset_secs.abs() % 3600) / 60;
-                if minutes == 0 {
-                    format!("[{hours:+}h]")
-                } else {
-                    format!("[{hours:+}h {minutes:02}m]")
-                }
-            }
-        }
+        move || offset_label(state.time_offset.get())
     };
 
+    // Validation message for the typed offset
+    let offset_error = RwSignal::new(None::<String>);
+    // Validation message for the typed time expression
+    let jump_error = RwSignal::new(None::<String>);
+
     view! {
       <footer class="sticky bottom-0 py-3 px-4 border-t bg-surface-alt border-primary/30 backdrop-blur-sm">
         <div class="container flex gap-2 justify-center items-center mx-auto sm:gap-4">
@@ -113,7 +178,7 @@ pub fn TimeControls() -> impl IntoView {
             <button
               on:click={
                 let state = state.clone();
-                move |_| state.adjust_time(-60)
+                move |_| state.adjust_time(-3600)
               }
               class="font-mono text-sm btn-terminal"
               title="-1 hour"
@@ -123,13 +188,33 @@ pub fn TimeControls() -> impl IntoView {
             <button
               on:click={
                 let state = state.clone();
-                move |_| state.adjust_time(-15)
+                move |_| state.adjust_time(-15 * 60)
               }
               class="font-mono text-sm btn-terminal"
               title="-15 minutes"
             >
               "-15m"
             </button>
+            <button
+              on:click={
+                let state = state.clone();
+                move |_| state.adjust_time(-60)
+              }
+              class="font-mono text-sm btn-terminal"
+              title="-1 minute"
+            >
+              "-1m"
+            </button>
+            <button
+              on:click={
+                let state = state.clone();
+                move |_| state.adjust_time(-10)
+              }
+              class="font-mono text-sm btn-terminal"
+              title="-10 seconds"
+            >
+              "-10s"
+            </button>
 
             // Current offset display
             <div class="py-2 px-4 font-mono text-center min-w-28 text-primary text-glow">
@@ -139,7 +224,27 @@ pub fn TimeControls() -> impl IntoView {
             <button
               on:click={
                 let state = state.clone();
-                move |_| state.adjust_time(15)
+                move |_| state.adjust_time(10)
+              }
+              class="font-mono text-sm btn-terminal"
+              title="+10 seconds"
+            >
+              "+10s"
+            </button>
+            <button
+              on:click={
+                let state = state.clone();
+                move |_| state.adjust_time(60)
+              }
+              class="font-mono text-sm btn-terminal"
+              title="+1 minute"
+            >
+              "+1m"
+            </button>
+            <button
+              on:click={
+                let state = state.clone();
+                move |_| state.adjust_time(15 * 60)
               }
               class="font-mono text-sm btn-terminal"
               title="+15 minutes"
@@ -149,7 +254,7 @@ pub fn TimeControls() -> impl IntoView {
             <button
               on:click={
                 let state = state.clone();
-                move |_| state.adjust_time(60)
+                move |_| state.adjust_time(3600)
               }
               class="font-mono text-sm btn-terminal"
               title="+1 hour"
@@ -158,6 +263,238 @@ pub fn TimeControls() -> impl IntoView {
             </button>
           </div>
 
+          // Jump to the selected zone's next work start
+          <button
+            on:click={
+              let state = state.clone();
+              move |_| state.jump_to_next_work_start()
+            }
+            class="font-mono text-sm btn-terminal"
+            title="Jump to the selected zone's next work start"
+          >
+            "Next start"
+          </button>
+
+          // Bookmarks of named moments
+          <div class="flex gap-1 items-center">
+            <button
+              on:click={
+                let state = state.clone();
+                move |_| {
+                  let Ok(window) = crate::browser::window("ask for a bookmark name") else {
+                    return;
+                  };
+                  // Cancelling the prompt adds nothing
+                  if let Ok(Some(name)) = window.prompt_with_message("Bookmark name:") {
+                    state.add_bookmark(&name);
+                  }
+                }
+              }
+              class="font-mono text-sm btn-terminal"
+              title="Bookmark the displayed time"
+            >
+              "Bookmark"
+            </button>
+            {
+              let state = state.clone();
+              move || {
+                let bookmarks = state.bookmarks.get();
+                let state = state.clone();
+                (!bookmarks.is_empty())
+                  .then(|| {
+                    view! {
+                      <select
+                        class="font-mono text-sm input-terminal preset-select"
+                        title="Jump to a bookmarked time"
+                        prop:value=""
+                        on:change=move |e| {
+                          if let Ok(index) = event_target_value(&e).parse() {
+                            state.jump_to_bookmark(index);
+                          }
+                          event_target::<web_sys::HtmlSelectElement>(&e).set_value("");
+                        }
+                      >
+                        <option value="">"Jump to..."</option>
+                        {bookmarks
+                          .into_iter()
+                          .enumerate()
+                          .map(|(index, bookmark)| {
+                            let title = bookmark.at.format("%Y-%m-%d %H:%M UTC").to_string();
+                            view! {
+                              <option value=index.to_string() title=title>
+                                {bookmark.name}
+                              </option>
+                            }
+                          })
+                          .collect_view()}
+                      </select>
+                    }
+                  })
+              }
+            }
+          </div>
+
+          // Named offsets from now, e.g. "+8h" for the end of the day in India
+          <div class="flex gap-1 items-center">
+            <button
+              on:click={
+                let state = state.clone();
+                move |_| {
+                  let Ok(window) = crate::browser::window("ask for an offset name") else {
+                    return;
+                  };
+                  if let Ok(Some(name)) = window.prompt_with_message("Offset name:") {
+                    state.save_offset(&name);
+                  }
+                }
+              }
+              class="font-mono text-sm btn-terminal"
+              title="Save the current offset from now under a name"
+            >
+              "Save offset"
+            </button>
+            {
+              let state = state.clone();
+              move || {
+                let saved_offsets = state.config.get().saved_offsets;
+                let state = state.clone();
+                (!saved_offsets.is_empty())
+                  .then(|| {
+                    view! {
+                      <select
+                        class="font-mono text-sm input-terminal preset-select"
+                        title="Move to a saved offset from now"
+                        prop:value=""
+                        on:change=move |e| {
+                          if let Ok(index) = event_target_value(&e).parse() {
+                            state.apply_saved_offset(index);
+                          }
+                          event_target::<web_sys::HtmlSelectElement>(&e).set_value("");
+                        }
+                      >
+                        <option value="">"Offsets..."</option>
+                        {saved_offsets
+                          .into_iter()
+                          .enumerate()
+                          .map(|(index, saved)| {
+                            view! {
+                              <option value=index.to_string()>
+                                {format!("{} ({})", saved.name, saved.offset)}
+                              </option>
+                            }
+                          })
+                          .collect_view()}
+                      </select>
+                    }
+                  })
+              }
+            }
+          </div>
+
+          // Quick jumps to local times in the reference zone
+          <div class="flex gap-1 items-center">
+            <button
+              on:click={
+                let state = state.clone();
+                move |_| state.jump_to_day_boundary(DayBoundary::Start)
+              }
+              class="font-mono text-sm btn-terminal"
+              title="Jump to midnight in the reference zone"
+            >
+              "00:00"
+            </button>
+            <button
+              on:click={
+                let state = state.clone();
+                move |_| state.jump_to_day_boundary(DayBoundary::End)
+              }
+              class="font-mono text-sm btn-terminal"
+              title="Jump to the end of the reference zone's day"
+            >
+              "23:59"
+            </button>
+            {
+              let state = state.clone();
+              move || {
+                state
+                  .config
+                  .get()
+                  .quick_jumps()
+                  .into_iter()
+                  .map(|time| {
+                    let state = state.clone();
+                    let label = time.format("%H:%M").to_string();
+                    view! {
+                      <button
+                        on:click=move |_| state.quick_jump(time)
+                        class="font-mono text-sm btn-terminal"
+                        title="Jump to this local time in the reference zone"
+                      >
+                        {label}
+                      </button>
+                    }
+                  })
+                  .collect_view()
+              }
+            }
+          </div>
+
+          // Direct offset entry
+          <div class="flex flex-col items-center">
+            <input
+              type="text"
+              class="font-mono text-sm text-center input-terminal offset-input"
+              placeholder="-3:30"
+              title="Set offset as [+-]H:MM or minutes, then press Enter"
+              prop:value={
+                let state = state.clone();
+                move || format_signed_duration(state.time_offset.get())
+              }
+              on:change={
+                let state = state.clone();
+                move |e| {
+                  match parse_signed_duration(&event_target_value(&e)) {
+                    Ok(seconds) => {
+                      state.set_time_offset(seconds);
+                      offset_error.set(None);
+                    }
+                    Err(message) => offset_error.set(Some(message)),
+                  }
+                }
+              }
+            />
+            {move || {
+              offset_error
+                .get()
+                .map(|message| view! { <span class="mt-1 text-xs text-off">{message}</span> })
+            }}
+          </div>
+
+          // Time expression entry
+          <div class="flex flex-col items-center">
+            <input
+              type="text"
+              class="font-mono text-sm text-center input-terminal jump-input"
+              placeholder="tomorrow 9am"
+              title="Jump by +2h, in 90m, 14:00 or tomorrow 9am, then press Enter"
+              on:change={
+                let state = state.clone();
+                move |e| {
+                  match state.jump_to_expression(&event_target_value(&e)) {
+                    Ok(()) => {
+                      jump_error.set(None);
+                      event_target::<web_sys::HtmlInputElement>(&e).set_value("");
+                    }
+                    Err(message) => jump_error.set(Some(message)),
+                  }
+                }
+              }
+            />
+            {move || {
+              jump_error.get().map(|message| view! { <span class="mt-1 text-xs text-off">{message}</span> })
+            }}
+          </div>
+
           // Play/Pause button
           <button
             on:click={
@@ -189,7 +526,114 @@ pub fn TimeControls() -> impl IntoView {
               }
             }
           </button>
+
+          // Fast-forward toggle and rate
+          <div class="flex gap-1 items-center">
+            <button
+              on:click={
+                let state = state.clone();
+                move |_| state.toggle_fast_forward()
+              }
+              class={
+                let state = state.clone();
+                move || {
+                  if state.fast_forward.get() {
+                    "flex gap-1 items-center text-sm btn-primary"
+                  } else {
+                    "flex gap-1 items-center text-sm btn-terminal"
+                  }
+                }
+              }
+              title={
+                let state = state.clone();
+                move || {
+                  if state.fast_forward.get() {
+                    "Stop fast-forwarding (keeps the current offset)"
+                  } else {
+                    "Advance the offset every second"
+                  }
+                }
+              }
+            >
+              <FastForwardIcon />
+              <span class="hidden sm:inline">"Fast"</span>
+            </button>
+            <select
+              class="font-mono text-sm input-terminal preset-select"
+              title="Minutes to advance per second"
+              prop:value={
+                let state = state.clone();
+                move || state.fast_forward_minutes.get().to_string()
+              }
+              on:change={
+                let state = state.clone();
+                move |e| {
+                  if let Ok(minutes) = event_target_value(&e).parse() {
+                    state.fast_forward_minutes.set(minutes);
+                  }
+                }
+              }
+            >
+              {FAST_FORWARD_STEPS
+                .iter()
+                .map(|minutes| {
+                  view! { <option value=minutes.to_string()>{format!("{minutes}m/s")}</option> }
+                })
+                .collect_view()}
+            </select>
+          </div>
+
+          // Freeze/Unfreeze button
+          <button
+            on:click={
+              let state = state.clone();
+              move |_| state.toggle_frozen()
+            }
+            class={
+              let state = state.clone();
+              move || {
+                if state.frozen_at.get().is_some() {
+                  "flex gap-1 items-center text-sm btn-primary"
+                } else {
+                  "flex gap-1 items-center text-sm btn-terminal"
+                }
+              }
+            }
+            title={
+              let state = state.clone();
+              move || {
+                if state.frozen_at.get().is_some() {
+                  "Unfreeze and resume live time"
+                } else {
+                  "Freeze at the displayed instant"
+                }
+              }
+            }
+          >
+            <PinIcon />
+            <span class="hidden sm:inline">
+              {
+                let state = state.clone();
+                move || if state.frozen_at.get().is_some() { "Unfreeze" } else { "Freeze" }
+              }
+            </span>
+          </button>
         </div>
       </footer>
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_offset_label() {
+        assert_eq!(offset_label(0), "[ NOW ]");
+        assert_eq!(offset_label(2 * 3600), "[+2h]");
+        assert_eq!(offset_label(-(3600 + 15 * 60)), "[-1h 15m]");
+        assert_eq!(offset_label(-30 * 60), "[-0h 30m]");
+        assert_eq!(offset_label(3600 + 5 * 60 + 30), "[+1h 05m 30s]");
+        assert_eq!(offset_label(-10), "[-0h 00m 10s]");
+    }
+}
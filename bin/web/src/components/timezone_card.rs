@@ -2,14 +2,23 @@
 //!
 //! Displays a single timezone with its current time, date, and work status.
 
+use chrono::{DateTime, Timelike, Utc};
 use leptos::prelude::*;
-use longtime_core::{TimezoneConfig, get_time_display_info};
+use longtime_core::{
+    DayPhase, TimeDisplayInfo, TimezoneConfig, baseline_label, day_offset, dst_notice,
+    format_day_offset, format_hours_minutes, format_signed_duration, get_time_display_info,
+    is_within_remind_window, reference_zone, remaining_week_work, work_by_hour,
+    work_hours_in_reference, work_overlap_by_hour, workday_progress, zone_local_time,
+};
 
-use crate::state::AppState;
+use crate::{
+    components::{ClockRing, ClockView, clock_view::status_text},
+    state::AppState,
+};
 
 /// Edit/Pencil SVG icon
 #[component]
-fn EditIcon() -> impl IntoView {
+pub fn EditIcon() -> impl IntoView {
     view! {
       <svg
         xmlns="http://www.w3.org/2000/svg"
@@ -21,6 +30,7 @@ fn EditIcon() -> impl IntoView {
         stroke-width="2"
         stroke-linecap="round"
         stroke-linejoin="round"
+        aria-hidden="true"
       >
         <path d="M17 3a2.85 2.83 0 1 1 4 4L7.5 20.5 2 22l1.5-5.5Z" />
         <path d="m15 5 4 4" />
@@ -30,7 +40,7 @@ fn EditIcon() -> impl IntoView {
 
 /// Trash/Delete SVG icon
 #[component]
-fn TrashIcon() -> impl IntoView {
+pub fn TrashIcon() -> impl IntoView {
     view! {
       <svg
         xmlns="http://www.w3.org/2000/svg"
@@ -42,6 +52,7 @@ fn TrashIcon() -> impl IntoView {
         stroke-width="2"
         stroke-linecap="round"
         stroke-linejoin="round"
+        aria-hidden="true"
       >
         <path d="M3 6h18" />
         <path d="M19 6v14c0 1-1 2-2 2H7c-1 0-2-1-2-2V6" />
@@ -52,6 +63,95 @@ fn TrashIcon() -> impl IntoView {
     }
 }
 
+/// Message SVG icon
+#[component]
+fn InviteIcon() -> impl IntoView {
+    view! {
+      <svg
+        xmlns="http://www.w3.org/2000/svg"
+        width="14"
+        height="14"
+        viewBox="0 0 24 24"
+        fill="none"
+        stroke="currentColor"
+        stroke-width="2"
+        stroke-linecap="round"
+        stroke-linejoin="round"
+        aria-hidden="true"
+      >
+        <path d="M21 15a2 2 0 0 1-2 2H7l-4 4V5a2 2 0 0 1 2-2h14a2 2 0 0 1 2 2z" />
+      </svg>
+    }
+}
+
+/// Copy/Clipboard SVG icon
+#[component]
+fn CopyIcon() -> impl IntoView {
+    view! {
+      <svg
+        xmlns="http://www.w3.org/2000/svg"
+        width="14"
+        height="14"
+        viewBox="0 0 24 24"
+        fill="none"
+        stroke="currentColor"
+        stroke-width="2"
+        stroke-linecap="round"
+        stroke-linejoin="round"
+        aria-hidden="true"
+      >
+        <rect width="14" height="14" x="8" y="8" rx="2" ry="2" />
+        <path d="M4 16c-1.1 0-2-.9-2-2V4c0-1.1.9-2 2-2h10c1.1 0 2 .9 2 2" />
+      </svg>
+    }
+}
+
+/// Ring SVG icon
+#[component]
+fn RingIcon() -> impl IntoView {
+    view! {
+      <svg
+        xmlns="http://www.w3.org/2000/svg"
+        width="14"
+        height="14"
+        viewBox="0 0 24 24"
+        fill="none"
+        stroke="currentColor"
+        stroke-width="2"
+        stroke-linecap="round"
+        stroke-linejoin="round"
+        aria-hidden="true"
+      >
+        <circle cx="12" cy="12" r="10" />
+        <path d="M12 12 12 6" />
+      </svg>
+    }
+}
+
+/// Muted bell SVG icon
+#[component]
+fn MuteIcon() -> impl IntoView {
+    view! {
+      <svg
+        xmlns="http://www.w3.org/2000/svg"
+        width="14"
+        height="14"
+        viewBox="0 0 24 24"
+        fill="none"
+        stroke="currentColor"
+        stroke-width="2"
+        stroke-linecap="round"
+        stroke-linejoin="round"
+        aria-hidden="true"
+      >
+        <path d="M8.7 3A6 6 0 0 1 18 8a21.3 21.3 0 0 0 .6 5" />
+        <path d="M17 17H3s3-2 3-9a4.67 4.67 0 0 1 .3-1.7" />
+        <path d="M10.3 21a1.94 1.94 0 0 0 3.4 0" />
+        <path d="m2 2 20 20" />
+      </svg>
+    }
+}
+
 /// Timezone card component
 #[component]
 pub fn TimezoneCard(
@@ -59,20 +159,76 @@ pub fn TimezoneCard(
     config: TimezoneConfig,
     /// Index of this timezone in the list
     index: usize,
-    /// Reference timezone offset in seconds
+    /// Reference timezone offset in seconds, computed at `now`
     reference_offset: i32,
+    /// Simulated instant the list was rendered at
+    ///
+    /// Cards use it instead of reading the clock themselves, so every card
+    /// and the reference offset agree even across a DST change.
+    now: DateTime<Utc>,
+    /// Badge marking the zone furthest ahead or behind
+    extreme_badge: Option<&'static str>,
+    /// Whether the zone is in work hours at `now`, from `work_statuses`
+    working: bool,
 ) -> impl IntoView {
     let state = expect_context::<AppState>();
 
     // Clone config for the closure
     let config_for_view = config.clone();
 
+    // Spoken summary, e.g. "New York, 09:15 Monday, working"
+    let aria_label = {
+        let state = state.clone();
+        let config = config.clone();
+        move || {
+            let options = state.display_options(&state.config.get());
+            let weekday =
+                zone_local_time(now, &config).map(|local_time| local_time.format("%A").to_string());
+            match (
+                get_time_display_info(now, &config, reference_offset, options),
+                weekday,
+            ) {
+                (Some(info), Some(weekday)) => card_aria_label(&config.name, &info, &weekday),
+                _ => format!("{}, invalid timezone", config.name),
+            }
+        }
+    };
+    let name = config.name.clone();
+    // Whether this card shows its 24-hour clock ring
+    let show_ring = RwSignal::new(false);
+
+    // The selected card is highlighted; in focus mode, zones outside work
+    // hours fade into the background
+    let card_class = {
+        let state = state.clone();
+        let config = config.clone();
+        move || {
+            let mut class = String::from("cursor-pointer card-terminal group");
+            if state.selected_index.get() == index {
+                class.push_str(" card-selected");
+            }
+            if state.marked.get().contains(&index) {
+                class.push_str(" card-marked");
+            }
+            if state.focus_mode.get() && !working {
+                class.push_str(" card-dimmed");
+            }
+            // Zones about to start work ask for attention, in live time only
+            if state.is_live() && is_within_remind_window(now, &config) {
+                class.push_str(" card-remind");
+            }
+            class
+        }
+    };
+
     view! {
       <div
-        class="cursor-pointer card-terminal group"
+        class=card_class
+        role="group"
+        aria-label=aria_label
         on:click={
           let state = state.clone();
-          move |_| state.selected_index.set(index)
+          move |_| state.select(index)
         }
       >
         // Header with name and actions
@@ -81,13 +237,135 @@ pub fn TimezoneCard(
             <h3 class="font-mono text-lg font-bold text-primary">
               <span class="text-primary/50">"$ "</span>
               {config_for_view.name.clone()}
+              {config_for_view
+                .offset_override
+                .map(|offset| {
+                  view! {
+                    <span class="text-xs text-accent" title=format!("Offset overridden to UTC{}", format_signed_duration(i64::from(offset)))>
+                      " (override)"
+                    </span>
+                  }
+                })}
+              {config_for_view
+                .always_working
+                .then(|| {
+                  view! {
+                    <span class="text-xs text-accent" title="Always working (24/7 coverage)">
+                      " [24/7]"
+                    </span>
+                  }
+                })}
+              {
+                let state = state.clone();
+                move || {
+                  let is_reference = state.selected_index.get() == index
+                    && baseline_label(&state.config.get()).is_none()
+                    && !state.utc_reference.get();
+                  is_reference
+                    .then(|| {
+                      view! {
+                        <span class="text-xs text-accent" title="Diffs are relative to this zone">
+                          " [ref]"
+                        </span>
+                      }
+                    })
+                }
+              }
             </h3>
+            {extreme_badge
+              .map(|badge| {
+                view! {
+                  <span class="font-mono text-xs text-accent" title="Local time furthest ahead or behind among the listed zones">
+                    {badge}
+                  </span>
+                }
+              })}
             <p class="mt-1 font-mono text-xs text-text-secondary">
               <span class="text-primary/40">"# "</span>
               {config_for_view.timezone.clone()}
             </p>
           </div>
-          <div class="flex gap-1 opacity-0 transition-opacity group-hover:opacity-100">
+          <div class="flex gap-1 opacity-0 transition-opacity group-hover:opacity-100 card-actions">
+            // Marks the zone for "Share"
+            <input
+              type="checkbox"
+              class="self-center"
+              title="Mark for sharing"
+              aria-label=format!("Mark {name} for sharing")
+              prop:checked={
+                let state = state.clone();
+                move || state.marked.get().contains(&index)
+              }
+              on:click=|e: web_sys::MouseEvent| e.stop_propagation()
+              on:change={
+                let state = state.clone();
+                move |_| state.toggle_marked(index)
+              }
+            />
+            <button
+              on:click={
+                let state = state.clone();
+                let config = config_for_view.clone();
+                move |e: web_sys::MouseEvent| {
+                  e.stop_propagation();
+                  if let Some(text) = state.invite_text(&config) {
+                    leptos::task::spawn_local(async move {
+                      crate::storage::copy_with_fallback(&text, "Invite text copied to clipboard!").await;
+                    });
+                  }
+                }
+              }
+              class="p-1.5 rounded border border-transparent transition-colors text-text-secondary hover:border-primary/50 hover:text-primary"
+              title="Copy invite text in this timezone"
+              aria-label=format!("Copy invite text for {name}")
+            >
+              <InviteIcon />
+            </button>
+            <button
+              on:click={
+                let id = config_for_view.timezone.clone();
+                move |e: web_sys::MouseEvent| {
+                  // Copying must not change the selected (reference) zone
+                  e.stop_propagation();
+                  let id = id.clone();
+                  leptos::task::spawn_local(async move {
+                    let message = format!("Copied {id}");
+                    crate::storage::copy_with_fallback(&id, &message).await;
+                  });
+                }
+              }
+              class="p-1.5 rounded border border-transparent transition-colors text-text-secondary hover:border-primary/50 hover:text-primary"
+              title="Copy IANA timezone id"
+              aria-label=format!("Copy the timezone id of {name}")
+            >
+              <CopyIcon />
+            </button>
+            <button
+              on:click=move |e: web_sys::MouseEvent| {
+                e.stop_propagation();
+                show_ring.update(|show| *show = !*show);
+              }
+              class="p-1.5 rounded border border-transparent transition-colors text-text-secondary hover:border-primary/50 hover:text-primary"
+              title="Show/hide the 24-hour clock ring"
+              aria-label=format!("Toggle the clock ring of {name}")
+              aria-pressed=move || show_ring.get().to_string()
+            >
+              <RingIcon />
+            </button>
+            <button
+              on:click={
+                let state = state.clone();
+                move |e: web_sys::MouseEvent| {
+                  e.stop_propagation();
+                  state.toggle_enabled(index);
+                }
+              }
+              class="p-1.5 rounded border border-transparent transition-colors text-text-secondary hover:border-primary/50 hover:text-primary"
+              title="Mute timezone (hide without deleting)"
+              aria-label=format!("Mute {name}")
+            >
+              <MuteIcon />
+            </button>
             <button
               on:click={
                 let state = state.clone();
@@ -98,6 +376,7 @@ pub fn TimezoneCard(
               }
               class="p-1.5 rounded border border-transparent transition-colors text-text-secondary hover:border-primary/50 hover:text-primary"
               title="Edit timezone"
+              aria-label=format!("Edit {name}")
             >
               <EditIcon />
             </button>
@@ -111,6 +390,7 @@ pub fn TimezoneCard(
               }
               class="p-1.5 rounded border border-transparent transition-colors hover:text-red-400 text-text-secondary hover:border-red-500/50"
               title="Delete timezone"
+              aria-label=format!("Delete {name}")
             >
               <TrashIcon />
             </button>
@@ -122,42 +402,149 @@ pub fn TimezoneCard(
           let config = config_for_view.clone();
           let state = state.clone();
           move || {
-            let now = state.current_time();
-            let use_12h = state.config.get().use_12h_format;
-            let info = get_time_display_info(now, &config, reference_offset, use_12h);
+            let app_config = state.config.get();
+            let options = state.display_options(&app_config);
+            let reference_label = if state.utc_reference.get() {
+              "UTC".to_string()
+            } else {
+              baseline_label(&app_config).unwrap_or_else(|| "selected".to_string())
+            };
+            // Spelled-out name of the reference zone for tooltips and labels
+            let reference_name = state.reference_name(&app_config, app_config.timezones.get(state.selected_index.get()));
+            let info = get_time_display_info(now, &config, reference_offset, options);
             match info {
               Some(info) => {
-                let diff_str = if info.diff_hours == 0.0 {
-                  "=".to_string()
-                } else if info.diff_hours > 0.0 {
-                  format!("+{}", info.diff_hours)
-                } else {
-                  format!("{}", info.diff_hours)
+                let phase = zone_local_time(now, &config)
+                  .map(|local_time| DayPhase::from_hour(local_time.hour()))
+                  .map(|phase| format!("{} {}", phase.icon(), phase.label()));
+                // This zone's work hours (filled) and the hours shared with the
+                // reference zone's work hours (outlined)
+                let own_hours = work_by_hour(now, &config);
+                let overlap_zone = reference_zone(now, &app_config, app_config.timezones.get(state.selected_index.get()));
+                let overlap = overlap_zone
+                  .as_ref()
+                  .and_then(|reference| work_overlap_by_hour(now, &config, reference))
+                  .unwrap_or([false; 24]);
+                let overlap_name = overlap_zone.map_or_else(|| "reference".to_string(), |zone| zone.name);
+                let overlap_strip = own_hours
+                  .map(|own_hours| {
+                    view! {
+                      <div
+                        class="overlap-strip"
+                        title=format!("Filled: this zone's work hours. Outlined: overlap with {overlap_name}'s work hours")
+                        role="img"
+                        aria-label=format!(
+                          "{} work hours, {} overlapping {overlap_name}'s work hours",
+                          own_hours.iter().filter(|on| **on).count(),
+                          overlap.iter().filter(|on| **on).count(),
+                        )
+                      >
+                        {own_hours
+                          .iter()
+                          .zip(overlap)
+                          .map(|(own, shared)| {
+                            let class = match (*own, shared) {
+                              (_, true) => "overlap-cell overlap-work overlap-on",
+                              (true, false) => "overlap-cell overlap-work",
+                              (false, false) => "overlap-cell",
+                            };
+                            view! { <span class=class aria-hidden="true"></span> }
+                          })
+                          .collect_view()}
+                      </div>
+                    }
+                  });
+                // This zone's work hours on the reference clock
+                let reference_hours = state
+                  .show_reference_hours
+                  .get()
+                  .then(|| work_hours_in_reference(now, &config, reference_offset))
+                  .flatten()
+                  .map(|hours| {
+                    let reference_name = reference_name.clone();
+                    view! {
+                      <div class="mt-1 font-mono text-xs text-text-secondary">
+                        <span class="text-primary/40">"# "</span>
+                        {format!("hours in {reference_name}: {}", hours.label())}
+                      </div>
+                    }
+                  });
+                // The configured window on this zone's own clock
+                // Shown on demand as the hours line's tooltip
+                let week_left = match remaining_week_work(now, &config) {
+                  Some(remaining) => {
+                    format!(
+                      "Configured work hours; {} of work left this week",
+                      format_hours_minutes(remaining.num_seconds()),
+                    )
+                  }
+                  None => "Configured work hours".to_string(),
                 };
-
+                let work_window = options
+                  .format_work_hours(&config.work_hours)
+                  .map(|window| {
+                    view! {
+                      <div class="mt-1 font-mono text-xs text-text-secondary" title=week_left.clone()>
+                        <span class="text-primary/40">"# "</span>
+                        {format!("hours: {window}")}
+                      </div>
+                    }
+                  });
+                // Calendar days ahead of or behind the reference clock
+                let day_chip = app_config
+                  .show_day_offset
+                  .then(|| day_offset(now, &config, reference_offset))
+                  .flatten()
+                  .and_then(format_day_offset);
+                // How far through the work day the zone is, while working
+                let workday_bar = workday_progress(now, &config)
+                  .map(|fraction| {
+                    let percent = (fraction * 100.0).round();
+                    view! {
+                      <div
+                        class="workday-bar"
+                        role="progressbar"
+                        aria-label="Work day elapsed"
+                        aria-valuemin="0"
+                        aria-valuemax="100"
+                        aria-valuenow=percent.to_string()
+                        title=format!("{percent}% of the work day elapsed")
+                      >
+                        <div class="workday-bar-fill" style=format!("width: {percent}%")></div>
+                      </div>
+                    }
+                  });
                 view! {
-                  <div>
-                    // Time
-                    <div class="mb-2 text-4xl time-display">{info.time}</div>
-                    // Date and diff
-                    <div class="flex justify-between items-center font-mono text-sm">
-                      <span class="text-text-secondary">{info.date}</span>
-                      <span class="text-accent">{diff_str}</span>
-                    </div>
-                    // Work status
-                    <div class="flex gap-2 items-center mt-3 font-mono text-sm">
-                      <span class=if info.is_working {
-                        "status-dot status-online"
-                      } else {
-                        "status-dot status-offline"
-                      }></span>
-                      <span class=if info.is_working {
-                        "text-working"
-                      } else {
-                        "text-off"
-                      }>{if info.is_working { "[ONLINE]" } else { "[OFFLINE]" }}</span>
-                    </div>
+                  <ClockView
+                    info=info
+                    show_diff=app_config.show_diff
+                    day_chip=day_chip
+                    diff_style=app_config.diff_style
+                    reference_label=reference_label
+                    reference_name=reference_name
+                  />
+                  {work_window}
+                  {workday_bar}
+                  {reference_hours}
+                  {overlap_strip}
+                  {show_ring
+                    .get()
+                    .then(|| zone_local_time(now, &config))
+                    .flatten()
+                    .map(|local_time| view! { <ClockRing config=config.clone() local_time=local_time running=state.is_running.get() /> })}
+                  <div class="mt-1 font-mono text-xs text-text-secondary" title="Local time of day">
+                    {phase}
                   </div>
+                  // Fixed override offsets never change
+                  {dst_notice(&config.timezone, now)
+                    .filter(|_| config.offset_override.is_none())
+                    .map(|notice| {
+                      view! {
+                        <div class="mt-1 font-mono text-xs text-accent" title="Upcoming daylight saving change">
+                          {notice}
+                        </div>
+                      }
+                    })}
                 }
                   .into_any()
               }
@@ -171,3 +558,43 @@ pub fn TimezoneCard(
       </div>
     }
 }
+
+/// Builds the screen reader summary of a card, e.g. "New York, 09:15 Monday, working"
+fn card_aria_label(name: &str, info: &TimeDisplayInfo, weekday: &str) -> String {
+    format!(
+        "{name}, {} {weekday}, {}",
+        info.time,
+        status_text(info.status)
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use longtime_core::{OffReason, WorkStatus};
+
+    use super::*;
+
+    #[test]
+    fn test_card_aria_label() {
+        let info = TimeDisplayInfo {
+            time: "9:15 AM".to_string(),
+            date: "2024-03-04".to_string(),
+            diff_hours: 0.0,
+            is_working: true,
+            status: WorkStatus::Working,
+        };
+        assert_eq!(
+            card_aria_label("New York", &info, "Monday"),
+            "New York, 9:15 AM Monday, working"
+        );
+
+        let info = TimeDisplayInfo {
+            status: WorkStatus::Off(OffReason::Weekend),
+            ..info
+        };
+        assert_eq!(
+            card_aria_label("New York", &info, "Saturday"),
+            "New York, 9:15 AM Saturday, off (weekend)"
+        );
+    }
+}
@@ -1,30 +1,43 @@
 //! Timezone list component
 //!
-//! Displays a grid of timezone cards.
+//! Displays a grid of timezone cards, or a compact table with one row per
+//! zone, optionally ordered by UTC offset with separators where the local
+//! date changes and grouped into working and off sections, followed by a row
+//! of muted timezones that can be unmuted.
 
 use leptos::prelude::*;
-use longtime_core::get_timezone_offset;
+use longtime_core::{earliest_zone, latest_zone, sort_by_offset, work_statuses, zone_local_time};
 
-use crate::{components::TimezoneCard, state::AppState};
+use crate::{
+    components::{TimezoneCard, TimezoneRow},
+    state::AppState,
+};
 
 /// Timezone list component
 #[component]
 pub fn TimezoneList() -> impl IntoView {
     let state = expect_context::<AppState>();
 
+    let grid_class = {
+        let state = state.clone();
+        move || {
+            if state.table_view.get() {
+                "timezone-table-wrap"
+            } else {
+                grid_class(state.config.get().grid_columns)
+            }
+        }
+    };
+
     view! {
-      <div class="grid grid-cols-1 gap-4 sm:grid-cols-2 lg:grid-cols-3 xl:grid-cols-4">
+      <div class=grid_class>
         {
           let state = state.clone();
           move || {
             let config = state.config.get();
             let now = state.current_time();
             let selected_idx = state.selected_index.get();
-            let reference_offset = config
-              .timezones
-              .get(selected_idx)
-              .and_then(|tz| get_timezone_offset(now, &tz.timezone))
-              .unwrap_or(0);
+            let reference_offset = state.reference_offset_at(now, &config, config.timezones.get(selected_idx));
             if config.timezones.is_empty() {
               let state = state.clone();
 
@@ -43,16 +56,180 @@ pub fn TimezoneList() -> impl IntoView {
               }
                 .into_any()
             } else {
-              config
+              let mut enabled: Vec<_> = config
                 .timezones
                 .iter()
                 .enumerate()
+                .filter(|(_, tz)| tz.enabled)
+                .collect();
+              let sorted = state.sort_by_offset.get();
+              if sorted {
+                sort_by_offset(now, &mut enabled, |(_, tz)| *tz);
+              }
+              // Checked once per tick and shared by the grouping and the cards
+              let statuses = work_statuses(now, &config.timezones);
+              let grouped = state.group_by_status.get();
+              let working = if grouped {
+                enabled.sort_by_key(|(index, _)| !statuses[*index]);
+                enabled.iter().filter(|(index, _)| statuses[*index]).count()
+              } else {
+                0
+              };
+              let total = enabled.len();
+              // Badge the zones furthest ahead and behind, unless all agree
+              let visible: Vec<_> = enabled.iter().map(|(_, tz)| (*tz).clone()).collect();
+              let earliest = earliest_zone(now, &visible).map(|i| enabled[i].0);
+              let latest = latest_zone(now, &visible).map(|i| enabled[i].0);
+              let badge_for = move |index: usize| {
+                if earliest == latest {
+                  None
+                } else if latest == Some(index) {
+                  Some("⏩ ahead")
+                } else if earliest == Some(index) {
+                  Some("⏪ behind")
+                } else {
+                  None
+                }
+              };
+              let date_style = config.date_style;
+              let table = state.table_view.get();
+              // Name, time, date, the optional diff, status and actions
+              let columns = if config.show_diff { 6 } else { 5 };
+              let separator = move |class: &'static str, text: String| {
+                if table {
+                  view! {
+                    <tr class="table-separator">
+                      <td colspan=columns class=format!("font-mono text-xs text-text-secondary {class}")>{text}</td>
+                    </tr>
+                  }
+                    .into_any()
+                } else {
+                  view! { <div class=format!("col-span-full font-mono text-xs text-text-secondary {class}")>{text}</div> }
+                    .into_any()
+                }
+              };
+              let mut previous_date = None;
+              let cards = enabled
+                .into_iter()
+                .enumerate()
+                .map(|(position, (index, tz))| {
+                  // Head the working and off groups, which are listed in that order
+                  let group_header = (grouped && (position == 0 || position == working))
+                    .then(|| {
+                      previous_date = None;
+                      let label = if position < working {
+                        format!("# working ({working})")
+                      } else {
+                        format!("# off ({})", total - working)
+                      };
+                      separator("", label)
+                    });
+                  // Mark where the local date flips between neighbouring zones
+                  let date = zone_local_time(now, tz).map(|local_time| local_time.date_naive());
+                  let date_line = match (previous_date, date) {
+                    (Some(previous), Some(date)) if sorted && previous != date => {
+                      Some(separator("date-line", format!("{} {}", date.format("%a"), date.format(date_style.format_str()))))
+                    }
+                    _ => None,
+                  };
+                  previous_date = date.or(previous_date);
+                  let entry = if table {
+                    view! {
+                      <TimezoneRow
+                        config=tz.clone()
+                        index=index
+                        reference_offset=reference_offset
+                        now=now
+                        extreme_badge=badge_for(index)
+                        working=statuses[index]
+                      />
+                    }
+                      .into_any()
+                  } else {
+                    view! {
+                      <TimezoneCard
+                        config=tz.clone()
+                        index=index
+                        reference_offset=reference_offset
+                        now=now
+                        extreme_badge=badge_for(index)
+                        working=statuses[index]
+                      />
+                    }
+                      .into_any()
+                  };
+                  view! {
+                    {group_header}
+                    {date_line}
+                    {entry}
+                  }
+                })
+                .collect_view();
+              let cards = if table {
+                let diff_header = config
+                  .show_diff
+                  .then(|| {
+                    let reference = state.reference_name(&config, config.timezones.get(selected_idx));
+                    view! { <th scope="col">{format!("Diff (vs {reference})")}</th> }
+                  });
+                view! {
+                  <table class="font-mono text-sm timezone-table">
+                    <thead>
+                      <tr>
+                        <th scope="col">"Name"</th>
+                        <th scope="col">"Time"</th>
+                        <th scope="col">"Date"</th>
+                        {diff_header}
+                        <th scope="col">"Status"</th>
+                        <th scope="col">
+                          <span class="sr-only">"Actions"</span>
+                        </th>
+                      </tr>
+                    </thead>
+                    <tbody>{cards}</tbody>
+                  </table>
+                }
+                  .into_any()
+              } else {
+                cards.into_any()
+              };
+              let muted = config
+                .timezones
+                .iter()
+                .enumerate()
+                .filter(|(_, tz)| !tz.enabled)
                 .map(|(index, tz)| {
+                  let state = state.clone();
                   view! {
-                    <TimezoneCard config=tz.clone() index=index reference_offset=reference_offset />
+                    <button
+                      on:click=move |_| state.toggle_enabled(index)
+                      class="text-xs btn-terminal"
+                      title="Unmute timezone"
+                    >
+                      {tz.name.clone()}
+                    </button>
                   }
                 })
-                .collect_view()
+                .collect::<Vec<_>>();
+              let muted_section = (!muted.is_empty())
+                .then(|| {
+                  view! {
+                    <div class=if table {
+                      "flex flex-wrap gap-2 items-center mt-4"
+                    } else {
+                      "flex flex-wrap gap-2 items-center col-span-full"
+                    }>
+                      <span class="font-mono text-xs text-text-secondary">
+                        {format!("# muted ({}):", muted.len())}
+                      </span>
+                      {muted}
+                    </div>
+                  }
+                });
+              view! {
+                {cards}
+                {muted_section}
+              }
                 .into_any()
             }
           }
@@ -60,3 +237,29 @@ pub fn TimezoneList() -> impl IntoView {
       </div>
     }
 }
+
+/// Grid classes for a fixed column count, or the responsive grid for None
+fn grid_class(columns: Option<u8>) -> &'static str {
+    match columns {
+        Some(1) => "grid gap-4 grid-cols-1",
+        Some(2) => "grid gap-4 grid-cols-2",
+        Some(3) => "grid gap-4 grid-cols-3",
+        Some(4) => "grid gap-4 grid-cols-4",
+        Some(5) => "grid gap-4 grid-cols-5",
+        Some(6..) => "grid gap-4 grid-cols-6",
+        Some(0) | None => "timezone-grid",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_grid_class() {
+        assert_eq!(grid_class(None), "timezone-grid");
+        assert_eq!(grid_class(Some(1)), "grid gap-4 grid-cols-1");
+        assert_eq!(grid_class(Some(6)), "grid gap-4 grid-cols-6");
+        assert_eq!(grid_class(Some(9)), "grid gap-4 grid-cols-6");
+    }
+}
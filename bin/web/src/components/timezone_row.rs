@@ -0,0 +1,188 @@
+//! Timezone table row component
+//!
+//! Displays a single timezone as a compact table row (name, time, date,
+//! diff and work status), the dense alternative to a timezone card.
+
+use chrono::{DateTime, Utc};
+use leptos::prelude::*;
+use longtime_core::{
+    TimezoneConfig, baseline_label, day_offset, format_day_offset, format_diff,
+    get_time_display_info, is_within_remind_window,
+};
+
+use crate::{
+    components::{
+        clock_view::{diff_tooltip, status_badge, status_text},
+        timezone_card::{EditIcon, TrashIcon},
+    },
+    state::AppState,
+};
+
+/// Timezone table row component
+#[component]
+pub fn TimezoneRow(
+    /// The timezone configuration
+    config: TimezoneConfig,
+    /// Index of this timezone in the list
+    index: usize,
+    /// Reference timezone offset in seconds, computed at `now`
+    reference_offset: i32,
+    /// Simulated instant the list was rendered at
+    now: DateTime<Utc>,
+    /// Badge marking the zone furthest ahead or behind
+    extreme_badge: Option<&'static str>,
+    /// Whether the zone is in work hours at `now`, from `work_statuses`
+    working: bool,
+) -> impl IntoView {
+    let state = expect_context::<AppState>();
+    let name = config.name.clone();
+
+    // Same highlighting as the cards: selected, marked, focus-dimmed and
+    // about to start work
+    let row_class = {
+        let state = state.clone();
+        let config = config.clone();
+        move || {
+            let mut class = String::new();
+            if state.selected_index.get() == index {
+                class.push_str(" row-selected");
+            }
+            if state.marked.get().contains(&index) {
+                class.push_str(" row-marked");
+            }
+            if state.focus_mode.get() && !working {
+                class.push_str(" card-dimmed");
+            }
+            if state.is_live() && is_within_remind_window(now, &config) {
+                class.push_str(" card-remind");
+            }
+            class
+        }
+    };
+
+    let cells = {
+        let state = state.clone();
+        let config = config.clone();
+        move || {
+            let app_config = state.config.get();
+            let options = state.display_options(&app_config);
+            let Some(info) = get_time_display_info(now, &config, reference_offset, options) else {
+                let columns = if app_config.show_diff { 4 } else { 3 };
+                return view! {
+                  <td colspan=columns class="text-red-400">"[ERROR] Invalid timezone"</td>
+                }
+                .into_any();
+            };
+            let reference_name = state.reference_name(
+                &app_config,
+                app_config.timezones.get(state.selected_index.get()),
+            );
+            let day_chip = app_config
+                .show_day_offset
+                .then(|| day_offset(now, &config, reference_offset))
+                .flatten()
+                .and_then(format_day_offset);
+            let diff = app_config.show_diff.then(|| {
+                view! {
+                  <td class="text-accent" title=diff_tooltip(info.diff_hours, &reference_name)>
+                    {format_diff(info.diff_hours, app_config.diff_style)}
+                  </td>
+                }
+            });
+            let (dot_class, text_class, label) = status_badge(info.status);
+            view! {
+              <td class="table-time">{info.time}</td>
+              <td class="text-text-secondary">
+                {info.date}
+                {day_chip
+                  .map(|chip| {
+                    view! {
+                      <span class="day-chip" title="Calendar days from the reference clock's date">
+                        {chip}
+                      </span>
+                    }
+                  })}
+              </td>
+              {diff}
+              <td>
+                <span class="flex gap-2 items-center">
+                  <span class=dot_class role="img" aria-label=status_text(info.status)></span>
+                  <span class=text_class>{label}</span>
+                </span>
+              </td>
+            }
+            .into_any()
+        }
+    };
+
+    view! {
+      <tr
+        class=row_class
+        on:click={
+          let state = state.clone();
+          move |_| state.select(index)
+        }
+      >
+        <th scope="row" class="text-primary" title=config.timezone.clone()>
+          {config.name.clone()}
+          {
+            let state = state.clone();
+            move || {
+              let is_reference = state.selected_index.get() == index
+                && baseline_label(&state.config.get()).is_none()
+                && !state.utc_reference.get();
+              is_reference
+                .then(|| {
+                  view! {
+                    <span class="text-xs text-accent" title="Diffs are relative to this zone">
+                      " [ref]"
+                    </span>
+                  }
+                })
+            }
+          }
+          {extreme_badge
+            .map(|badge| {
+              view! {
+                <span class="ml-1 text-xs text-accent" title="Local time furthest ahead or behind among the listed zones">
+                  {badge}
+                </span>
+              }
+            })}
+        </th>
+        {cells}
+        <td class="table-actions">
+          <span class="flex gap-1">
+            <button
+              on:click={
+                let state = state.clone();
+                move |e: web_sys::MouseEvent| {
+                  e.stop_propagation();
+                  state.open_edit_modal(index);
+                }
+              }
+              class="p-1.5 rounded border border-transparent transition-colors text-text-secondary hover:border-primary/50 hover:text-primary"
+              title="Edit timezone"
+              aria-label=format!("Edit {name}")
+            >
+              <EditIcon />
+            </button>
+            <button
+              on:click={
+                let state = state.clone();
+                move |e: web_sys::MouseEvent| {
+                  e.stop_propagation();
+                  state.delete_timezone(index);
+                }
+              }
+              class="p-1.5 rounded border border-transparent transition-colors hover:text-red-400 text-text-secondary hover:border-red-500/50"
+              title="Delete timezone"
+              aria-label=format!("Delete {name}")
+            >
+              <TrashIcon />
+            </button>
+          </span>
+        </td>
+      </tr>
+    }
+}
@@ -0,0 +1,82 @@
+//! Week view component
+//!
+//! Shows whether each enabled timezone is working at the reference zone's
+//! simulated time of day on each of the next 7 days, for planning recurring
+//! meetings.
+
+use leptos::prelude::*;
+use longtime_core::{PLANNING_DAYS, week_availability, zone_local_time};
+
+use crate::state::AppState;
+
+/// Week view component
+#[component]
+pub fn WeekView() -> impl IntoView {
+    let state = expect_context::<AppState>();
+
+    view! {
+      <div class="card-terminal diff-matrix">
+        {move || {
+          let config = state.config.get();
+          let now = state.current_time();
+          let selected = config.timezones.get(state.selected_index.get());
+          let reference = state.reference_zone_at(now, &config, selected);
+          let reference_name = state.reference_name(&config, selected);
+          let Some(local) = zone_local_time(now, &reference) else {
+            return ().into_any();
+          };
+          let zones: Vec<_> = config.timezones.iter().filter(|tz| tz.enabled).collect();
+          let week = week_availability(&reference, local.date_naive(), local.time(), &zones);
+          view! {
+            <p class="mb-3 font-mono text-xs text-text-secondary">
+              <span class="text-primary/40">"# "</span>
+              {format!(
+                "working at {} {} on each day",
+                local.format("%H:%M"),
+                reference_name,
+              )}
+            </p>
+            <table class="w-full font-mono text-sm">
+              <thead>
+                <tr>
+                  <th></th>
+                  {local
+                    .date_naive()
+                    .iter_days()
+                    .take(PLANNING_DAYS)
+                    .map(|date| {
+                      view! { <th class="text-primary">{date.format("%a %d").to_string()}</th> }
+                    })
+                    .collect_view()}
+                </tr>
+              </thead>
+              <tbody>
+                {zones
+                  .iter()
+                  .zip(week)
+                  .map(|(tz, days)| {
+                    view! {
+                      <tr>
+                        <th class="text-primary">{tz.name.clone()}</th>
+                        {days
+                          .into_iter()
+                          .map(|working| {
+                            if working {
+                              view! { <td class="text-working">"WORK"</td> }
+                            } else {
+                              view! { <td class="text-off">"off"</td> }
+                            }
+                          })
+                          .collect_view()}
+                      </tr>
+                    }
+                  })
+                  .collect_view()}
+              </tbody>
+            </table>
+          }
+            .into_any()
+        }}
+      </div>
+    }
+}
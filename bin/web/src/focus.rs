@@ -0,0 +1,104 @@
+//! Keyboard focus management
+//!
+//! Keeps Tab and Shift+Tab cycling inside an open modal dialog, so focus
+//! cannot reach the page behind it.
+
+use wasm_bindgen::JsCast;
+use web_sys::{Element, HtmlElement, KeyboardEvent};
+
+/// Elements that take part in the Tab order
+const FOCUSABLE: &str = "a[href], button:not([disabled]), input:not([disabled]), \
+    select:not([disabled]), textarea:not([disabled]), [tabindex]:not([tabindex=\"-1\"])";
+
+/// Get the element to move focus to when Tab would leave a container
+///
+/// # Arguments
+///
+/// * `current` - Position of the focused element among the focusable ones,
+///   or None if focus is outside the container
+/// * `count` - Number of focusable elements in the container
+/// * `backward` - Whether Shift+Tab was pressed
+///
+/// # Returns
+///
+/// * `Option<usize>` - Position to focus instead, or None to let the browser
+///   move focus as usual
+fn wrap_target(current: Option<usize>, count: usize, backward: bool) -> Option<usize> {
+    let last = count.checked_sub(1)?;
+    match current {
+        None => Some(if backward { last } else { 0 }),
+        Some(0) if backward => Some(last),
+        Some(index) if !backward && index >= last => Some(0),
+        _ => None,
+    }
+}
+
+/// Get the focusable elements of a container, in document order
+pub fn focusable_elements(container: &Element) -> Vec<HtmlElement> {
+    let Ok(nodes) = container.query_selector_all(FOCUSABLE) else {
+        return Vec::new();
+    };
+    (0..nodes.length())
+        .filter_map(|index| nodes.item(index))
+        .filter_map(|node| node.dyn_into::<HtmlElement>().ok())
+        .collect()
+}
+
+/// Get the element that currently has focus
+pub fn active_element() -> Option<HtmlElement> {
+    crate::browser::window("read the focused element")
+        .ok()?
+        .document()?
+        .active_element()?
+        .dyn_into()
+        .ok()
+}
+
+/// Move focus to the first focusable element of a container
+pub fn focus_first(container: &Element) {
+    if let Some(first) = focusable_elements(container).first() {
+        let _ = first.focus();
+    }
+}
+
+/// Keep a Tab key press within a container
+///
+/// Call from the container's keydown handler; other keys are ignored.
+///
+/// # Arguments
+///
+/// * `container` - The element focus must stay in
+/// * `event` - The keydown event
+pub fn trap_tab(container: &Element, event: &KeyboardEvent) {
+    if event.key() != "Tab" {
+        return;
+    }
+    let elements = focusable_elements(container);
+    let active = active_element();
+    let current = elements
+        .iter()
+        .position(|element| Some(element) == active.as_ref());
+    if let Some(target) = wrap_target(current, elements.len(), event.shift_key()) {
+        event.prevent_default();
+        let _ = elements[target].focus();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wrap_target() {
+        // Tab from the last element returns to the first, Shift+Tab the reverse
+        assert_eq!(wrap_target(Some(2), 3, false), Some(0));
+        assert_eq!(wrap_target(Some(0), 3, true), Some(2));
+        // Within the container the browser moves focus
+        assert_eq!(wrap_target(Some(0), 3, false), None);
+        assert_eq!(wrap_target(Some(2), 3, true), None);
+        // Focus outside is pulled back in
+        assert_eq!(wrap_target(None, 3, false), Some(0));
+        assert_eq!(wrap_target(None, 3, true), Some(2));
+        assert_eq!(wrap_target(None, 0, false), None);
+    }
+}
@@ -4,7 +4,9 @@
 //! time information across multiple time zones.
 
 pub mod app;
+pub mod browser;
 pub mod components;
+pub mod focus;
 pub mod state;
 pub mod storage;
 
@@ -3,9 +3,64 @@
 //! This module defines the reactive state used throughout the application,
 //! including timezone configuration, time offset, and UI state.
 
-use chrono::{DateTime, Duration, Utc};
+use std::sync::Arc;
+
+use chrono::{DateTime, Duration, NaiveTime, Utc};
 use leptos::prelude::*;
-use longtime_core::Config;
+use longtime_core::{
+    Clock, Config, DayBoundary, DisplayOptions, MAX_GRID_COLUMNS, Preset, SavedOffset, SystemClock,
+    TimezoneConfig, WorkHours, clamp_time_offset, day_boundary_offset, format_invite_text,
+    format_overlap_report, format_relative_offset, get_time_display_info, group_by_status,
+    local_time_instant, local_zone, next_work_start, offset_to_reach, parse_time_expression,
+    reference_name, reference_offset, reference_zone, sort_by_offset, system_offset,
+};
+use serde::{Deserialize, Serialize};
+
+/// A named moment the user can jump back to
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct Bookmark {
+    /// Label shown in the bookmark menu
+    pub name: String,
+    /// The bookmarked instant
+    pub at: DateTime<Utc>,
+}
+
+/// Version written into encoded view states; others are ignored on load
+pub const VIEW_STATE_VERSION: u8 = 1;
+
+/// UI state that can be restored from a bookmarkable URL
+///
+/// Field names are kept short because the state travels in the URL.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ViewState {
+    /// Encoding version, `VIEW_STATE_VERSION` when written
+    #[serde(rename = "v")]
+    pub version: u8,
+    /// Time offset in seconds
+    #[serde(rename = "o", default)]
+    pub time_offset: i64,
+    /// Index of the selected (reference) timezone
+    #[serde(rename = "r", default)]
+    pub selected_index: usize,
+    /// Whether cards are ordered by UTC offset
+    #[serde(rename = "s", default)]
+    pub sort_by_offset: bool,
+    /// Whether working cards are grouped first
+    #[serde(rename = "g", default)]
+    pub group_by_status: bool,
+    /// Whether cards outside work hours are dimmed
+    #[serde(rename = "f", default)]
+    pub focus_mode: bool,
+    /// Whether diffs are measured against UTC
+    #[serde(rename = "u", default)]
+    pub utc_reference: bool,
+    /// Whether times use the 12-hour clock
+    #[serde(rename = "h", default)]
+    pub use_12h_format: bool,
+}
+
+/// Minutes per second offered by the fast-forward rate control
+pub const FAST_FORWARD_STEPS: [i64; 4] = [5, 15, 30, 60];
 
 /// Main application state
 ///
@@ -15,27 +70,70 @@ use longtime_core::Config;
 pub struct AppState {
     /// Current timezone configuration
     pub config: RwSignal<Config>,
+    /// 12/24-hour clock restored from a view link; overrides the config
+    /// without being saved to LocalStorage
+    pub format_override: RwSignal<Option<bool>>,
     /// Time offset for time-travel simulation (in seconds)
     pub time_offset: RwSignal<i64>,
     /// Whether time is auto-updating
     pub is_running: RwSignal<bool>,
+    /// Wall-clock instant captured when the view was frozen ("pin now")
+    pub frozen_at: RwSignal<Option<DateTime<Utc>>>,
+    /// Saved moments to jump back to
+    pub bookmarks: RwSignal<Vec<Bookmark>>,
+    /// Whether the offset advances by `fast_forward_minutes` every second
+    pub fast_forward: RwSignal<bool>,
+    /// Minutes added to the offset per second while fast-forwarding
+    pub fast_forward_minutes: RwSignal<i64>,
     /// Whether to show the configuration modal
     pub show_config_modal: RwSignal<bool>,
     /// Index of timezone being edited (None for adding new)
     pub editing_index: RwSignal<Option<usize>>,
     /// Currently selected timezone index (for reference calculations)
     pub selected_index: RwSignal<usize>,
+    /// Index of the timezone selected before the current one
+    pub previous_index: RwSignal<Option<usize>>,
     /// Tick counter to trigger time updates
     pub tick: RwSignal<u64>,
     /// Dark mode state (true = dark, false = light)
     pub dark_mode: RwSignal<bool>,
+    /// Screenshot-friendly layout without controls
+    pub present_mode: RwSignal<bool>,
+    /// Whether cards are ordered by UTC offset instead of config order
+    pub sort_by_offset: RwSignal<bool>,
+    /// Whether working zones are grouped ahead of zones outside work hours
+    pub group_by_status: RwSignal<bool>,
+    /// Whether the pairwise diff matrix replaces the card grid
+    pub show_matrix: RwSignal<bool>,
+    /// Whether the 7-day availability grid replaces the card grid
+    pub show_week: RwSignal<bool>,
+    /// Whether zones are listed as a compact table instead of cards
+    pub table_view: RwSignal<bool>,
+    /// Whether cards show their work hours on the reference clock
+    pub show_reference_hours: RwSignal<bool>,
+    /// Whether cards of zones outside work hours are dimmed
+    pub focus_mode: RwSignal<bool>,
+    /// Whether diffs are measured against UTC instead of the home or
+    /// selected zone
+    pub utc_reference: RwSignal<bool>,
+    /// Whether the keyboard shortcut overlay is open
+    pub show_help: RwSignal<bool>,
+    /// Whether the bulk work hours modal is open
+    pub show_bulk_hours: RwSignal<bool>,
+    /// Whether share links include muted zones
+    pub share_muted: RwSignal<bool>,
+    /// Indices of the zones marked for sharing; empty shares every zone
+    pub marked: RwSignal<Vec<usize>>,
+    /// Source of the current wall-clock time
+    pub clock: Arc<dyn Clock>,
 }
 
 impl AppState {
     /// Create a new AppState with the given configuration
     pub fn new(config: Config) -> Self {
         // Load dark mode preference from localStorage or default to true (dark)
-        let dark_mode = web_sys::window()
+        let dark_mode = crate::browser::window("load the theme")
+            .ok()
             .and_then(|w| w.local_storage().ok().flatten())
             .and_then(|s| s.get_item("longtime_dark_mode").ok().flatten())
             .map(|v| v != "false")
@@ -43,26 +141,197 @@ impl AppState {
 
         Self {
             config: RwSignal::new(config),
+            format_override: RwSignal::new(None),
             time_offset: RwSignal::new(0),
             is_running: RwSignal::new(true),
+            frozen_at: RwSignal::new(None),
+            bookmarks: RwSignal::new(crate::storage::load_bookmarks()),
+            fast_forward: RwSignal::new(false),
+            fast_forward_minutes: RwSignal::new(FAST_FORWARD_STEPS[1]),
             show_config_modal: RwSignal::new(false),
             editing_index: RwSignal::new(None),
             selected_index: RwSignal::new(0),
+            previous_index: RwSignal::new(None),
             tick: RwSignal::new(0),
             dark_mode: RwSignal::new(dark_mode),
+            present_mode: RwSignal::new(false),
+            sort_by_offset: RwSignal::new(false),
+            group_by_status: RwSignal::new(false),
+            show_matrix: RwSignal::new(false),
+            show_week: RwSignal::new(false),
+            table_view: RwSignal::new(crate::storage::load_table_view()),
+            show_reference_hours: RwSignal::new(false),
+            focus_mode: RwSignal::new(false),
+            utc_reference: RwSignal::new(false),
+            show_help: RwSignal::new(false),
+            show_bulk_hours: RwSignal::new(false),
+            share_muted: RwSignal::new(true),
+            marked: RwSignal::new(Vec::new()),
+            clock: Arc::new(SystemClock),
         }
     }
 
     /// Get the current time with offset applied
+    ///
+    /// While frozen, the captured instant is used instead of the wall clock.
     pub fn current_time(&self) -> DateTime<Utc> {
         // Read tick to create dependency for reactivity
         let _ = self.tick.get();
-        Utc::now() + Duration::seconds(self.time_offset.get())
+        let base = self.frozen_at.get().unwrap_or_else(|| self.clock.now());
+        base + Duration::seconds(self.time_offset.get())
+    }
+
+    /// Whether the displayed time is the live wall-clock time, neither
+    /// shifted by an offset nor frozen
+    pub fn is_live(&self) -> bool {
+        self.time_offset.get() == 0 && self.frozen_at.get().is_none()
+    }
+
+    /// Set the time offset so that the simulated time reads `target`
+    pub fn jump_to(&self, target: DateTime<Utc>) {
+        let base = self
+            .frozen_at
+            .get_untracked()
+            .unwrap_or_else(|| self.clock.now());
+        self.set_time_offset(offset_to_reach(target, base));
+    }
+
+    /// Bookmark the displayed instant under the given name
+    ///
+    /// Blank names fall back to the instant itself, e.g. "2024-07-10 15:00 UTC".
+    pub fn add_bookmark(&self, name: &str) {
+        let at = self.current_time();
+        let name = match name.trim() {
+            "" => at.format("%Y-%m-%d %H:%M UTC").to_string(),
+            name => name.to_string(),
+        };
+        self.bookmarks
+            .update(|bookmarks| bookmarks.push(Bookmark { name, at }));
+        crate::storage::save_bookmarks(&self.bookmarks.get_untracked());
+    }
+
+    /// Jump to the bookmark at the given position
+    pub fn jump_to_bookmark(&self, index: usize) {
+        if let Some(bookmark) = self.bookmarks.get_untracked().get(index) {
+            self.jump_to(bookmark.at);
+        }
+    }
+
+    /// Save the time offset from now under the given name, e.g. "India EOD"
+    ///
+    /// Blank names fall back to the offset itself, e.g. "+8h". Saved offsets
+    /// are part of the configuration.
+    pub fn save_offset(&self, name: &str) {
+        let offset = format_relative_offset(self.time_offset.get_untracked());
+        let name = match name.trim() {
+            "" => offset.clone(),
+            name => name.to_string(),
+        };
+        self.config
+            .update(|config| config.saved_offsets.push(SavedOffset { name, offset }));
+        crate::storage::save_config(&self.config.get_untracked());
+    }
+
+    /// Set the time offset to the saved offset at the given position
+    pub fn apply_saved_offset(&self, index: usize) {
+        let seconds = self
+            .config
+            .get_untracked()
+            .saved_offsets
+            .get(index)
+            .and_then(|saved| saved.seconds().ok());
+        if let Some(seconds) = seconds {
+            self.set_time_offset(seconds);
+        }
+    }
+
+    /// Jump to the selected timezone's next work start
+    pub fn jump_to_next_work_start(&self) {
+        let config = self.config.get_untracked();
+        let target = config
+            .timezones
+            .get(self.selected_index.get_untracked())
+            .and_then(|tz| next_work_start(self.current_time(), tz));
+        if let Some(target) = target {
+            self.jump_to(target);
+        }
+    }
+
+    /// Jump so the reference zone reads `time` on its current local date
+    pub fn quick_jump(&self, time: NaiveTime) {
+        let config = self.config.get_untracked();
+        let now = self.current_time();
+        let selected = config.timezones.get(self.selected_index.get_untracked());
+        let reference = self.reference_zone_at(now, &config, selected);
+        if let Some(target) = local_time_instant(now, &reference, time) {
+            self.jump_to(target);
+        }
+    }
+
+    /// Snap the simulated time to the start or end of the reference zone's day
+    pub fn jump_to_day_boundary(&self, boundary: DayBoundary) {
+        let config = self.config.get_untracked();
+        let now = self.current_time();
+        let selected = config.timezones.get(self.selected_index.get_untracked());
+        let reference = self.reference_zone_at(now, &config, selected);
+        if let Some(seconds) = day_boundary_offset(now, &reference, boundary) {
+            self.set_time_offset(self.time_offset.get_untracked().saturating_add(seconds));
+        }
+    }
+
+    /// Jump by a human time expression such as "+2h" or "tomorrow 9am"
+    ///
+    /// Absolute times are read in the reference zone of the diff baseline,
+    /// or UTC without one.
+    pub fn jump_to_expression(&self, expr: &str) -> Result<(), String> {
+        let config = self.config.get_untracked();
+        let now = self.current_time();
+        let selected = config.timezones.get(self.selected_index.get_untracked());
+        let reference = self.reference_zone_at(now, &config, selected);
+        let seconds = parse_time_expression(expr, now, &reference)?;
+        self.set_time_offset(self.time_offset.get_untracked().saturating_add(seconds));
+        Ok(())
+    }
+
+    /// Build an invite sentence phrased in the target timezone
+    ///
+    /// The sender side is the diff reference (see `reference_zone_at`), or
+    /// the browser's local time when the target is the reference itself,
+    /// the same way the TUI phrases invites.
+    pub fn invite_text(&self, target: &TimezoneConfig) -> Option<String> {
+        let config = self.config.get();
+        let now = self.current_time();
+        let selected = config.timezones.get(self.selected_index.get());
+        let mut sender = self.reference_zone_at(now, &config, selected);
+        if sender == *target {
+            sender = local_zone(system_offset(now));
+        }
+        let options = self.display_options(&config);
+        let mine = get_time_display_info(now, &sender, 0, options)?;
+        let theirs = get_time_display_info(now, target, 0, options)?;
+        Some(format_invite_text(&theirs, &mine))
+    }
+
+    /// Build a Markdown report of the work hour overlap of every pair of
+    /// enabled zones at the simulated time
+    pub fn overlap_report(&self) -> String {
+        let config = self.config.get();
+        let zones: Vec<_> = config.timezones.iter().filter(|tz| tz.enabled).collect();
+        format_overlap_report(self.current_time(), &zones)
     }
 
-    /// Adjust time offset by the given number of minutes
-    pub fn adjust_time(&self, minutes: i64) {
-        self.time_offset.update(|offset| *offset += minutes * 60);
+    /// Adjust time offset by the given number of seconds
+    ///
+    /// The offset saturates at the supported range instead of overflowing.
+    pub fn adjust_time(&self, seconds: i64) {
+        self.time_offset.update(|offset| {
+            *offset = clamp_time_offset(offset.saturating_add(seconds));
+        });
+    }
+
+    /// Set the time offset in seconds, clamped to the supported range
+    pub fn set_time_offset(&self, seconds: i64) {
+        self.time_offset.set(clamp_time_offset(seconds));
     }
 
     /// Reset time offset to zero
@@ -71,10 +340,47 @@ impl AppState {
     }
 
     /// Toggle whether time is running
+    ///
+    /// Resuming a frozen view unfreezes it.
     pub fn toggle_running(&self) {
+        if self.frozen_at.get_untracked().is_some() {
+            self.toggle_frozen();
+            return;
+        }
         self.is_running.update(|running| *running = !*running);
     }
 
+    /// Start or stop fast-forwarding
+    ///
+    /// Stopping keeps the offset reached so far.
+    pub fn toggle_fast_forward(&self) {
+        self.fast_forward.update(|on| *on = !*on);
+    }
+
+    /// Advance the offset by one fast-forward step, if fast-forwarding
+    ///
+    /// Called once per second by the update interval, independently of
+    /// whether the clock itself is running.
+    pub fn fast_forward_tick(&self) {
+        if self.fast_forward.get_untracked() {
+            self.adjust_time(self.fast_forward_minutes.get_untracked().saturating_mul(60));
+        }
+    }
+
+    /// Freeze the view at the displayed instant, or resume live updates
+    ///
+    /// Unlike pause, a frozen view keeps showing exactly the captured instant
+    /// until it is unfrozen; offset adjustments still apply on top of it.
+    pub fn toggle_frozen(&self) {
+        if self.frozen_at.get_untracked().is_some() {
+            self.frozen_at.set(None);
+            self.is_running.set(true);
+        } else {
+            self.frozen_at.set(Some(self.clock.now()));
+            self.is_running.set(false);
+        }
+    }
+
     /// Open modal to add a new timezone
     pub fn open_add_modal(&self) {
         self.editing_index.set(None);
@@ -87,6 +393,20 @@ impl AppState {
         self.show_config_modal.set(true);
     }
 
+    /// Count the zones `set_all_work_hours` would change
+    pub fn count_work_hours_targets(&self, only_matching: Option<&WorkHours>) -> usize {
+        self.config
+            .with_untracked(|config| config.count_work_hours_targets(only_matching))
+    }
+
+    /// Set the work hours of every zone (or only those matching a range) and save
+    pub fn set_all_work_hours(&self, hours: &WorkHours, only_matching: Option<&WorkHours>) {
+        self.config.update(|config| {
+            config.set_all_work_hours(hours, only_matching);
+        });
+        crate::storage::save_config(&self.config.get_untracked());
+    }
+
     /// Close the configuration modal
     pub fn close_modal(&self) {
         self.show_config_modal.set(false);
@@ -100,23 +420,335 @@ impl AppState {
                 config.timezones.remove(index);
             }
         });
+        // Indices after the removed zone shift down
+        self.previous_index.set(None);
+        self.marked.set(Vec::new());
         // Trigger storage save
         crate::storage::save_config(&self.config.get());
     }
 
+    /// Append a preset group of timezones, skipping ones already configured
+    ///
+    /// Returns the number of zones added.
+    pub fn apply_preset(&self, preset: &Preset) -> usize {
+        let mut added = 0;
+        self.config.update(|config| added = preset.apply_to(config));
+        crate::storage::save_config(&self.config.get());
+        added
+    }
+
+    /// Mute or unmute a timezone without removing it from the config
+    pub fn toggle_enabled(&self, index: usize) {
+        self.config.update(|config| {
+            if let Some(tz) = config.timezones.get_mut(index) {
+                tz.enabled = !tz.enabled;
+            }
+        });
+        crate::storage::save_config(&self.config.get());
+    }
+
+    /// Whether times use the 12-hour clock, including a view link override
+    pub fn use_12h_format(&self) -> bool {
+        self.format_override
+            .get()
+            .unwrap_or_else(|| self.config.with(|config| config.use_12h_format))
+    }
+
+    /// Get the time and date rendering options in effect
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - The configuration, usually already read by the caller
+    ///
+    /// # Returns
+    ///
+    /// * `DisplayOptions` - The config's options with the clock override applied
+    pub fn display_options(&self, config: &Config) -> DisplayOptions {
+        DisplayOptions {
+            use_12h_format: self.format_override.get().unwrap_or(config.use_12h_format),
+            ..DisplayOptions::from_config(config)
+        }
+    }
+
     /// Toggle 12/24 hour format
+    ///
+    /// Toggling is a choice of the user, so it replaces a view link
+    /// override and is saved.
     pub fn toggle_format(&self) {
+        let use_12h_format = !self.use_12h_format();
+        self.format_override.set(None);
         self.config.update(|config| {
-            config.use_12h_format = !config.use_12h_format;
+            config.use_12h_format = use_12h_format;
         });
         crate::storage::save_config(&self.config.get());
     }
 
+    /// Show or hide the diff on each card
+    pub fn toggle_diff(&self) {
+        self.config.update(|config| {
+            config.show_diff = !config.show_diff;
+        });
+        crate::storage::save_config(&self.config.get());
+    }
+
+    /// Fix the number of card columns, or None for the responsive grid
+    ///
+    /// Values are clamped to `1..=MAX_GRID_COLUMNS`.
+    pub fn set_grid_columns(&self, columns: Option<u8>) {
+        self.config.update(|config| {
+            config.grid_columns = columns.map(|columns| columns.clamp(1, MAX_GRID_COLUMNS));
+        });
+        crate::storage::save_config(&self.config.get());
+    }
+
+    /// Switch diffs between signed numbers and ahead/behind words
+    pub fn toggle_diff_style(&self) {
+        self.config.update(|config| {
+            config.diff_style = config.diff_style.next();
+        });
+        crate::storage::save_config(&self.config.get());
+    }
+
+    /// Cycle to the next date rendering style
+    pub fn cycle_date_style(&self) {
+        self.config.update(|config| {
+            config.date_style = config.date_style.next();
+        });
+        crate::storage::save_config(&self.config.get());
+    }
+
+    /// Toggle the screenshot-friendly present mode
+    pub fn toggle_present_mode(&self) {
+        self.present_mode.update(|present| *present = !*present);
+    }
+
+    /// Capture the restorable parts of the current view
+    pub fn view_state(&self) -> ViewState {
+        ViewState {
+            version: VIEW_STATE_VERSION,
+            time_offset: self.time_offset.get_untracked(),
+            selected_index: self.selected_index.get_untracked(),
+            sort_by_offset: self.sort_by_offset.get_untracked(),
+            group_by_status: self.group_by_status.get_untracked(),
+            focus_mode: self.focus_mode.get_untracked(),
+            utc_reference: self.utc_reference.get_untracked(),
+            use_12h_format: self
+                .format_override
+                .get_untracked()
+                .unwrap_or_else(|| self.config.get_untracked().use_12h_format),
+        }
+    }
+
+    /// Restore a view captured by `view_state`
+    ///
+    /// The offset is clamped to the supported range and a selection beyond
+    /// the configured zones falls back to the first one. The clock format
+    /// is kept in `format_override`, so opening a view link does not change
+    /// the saved configuration.
+    pub fn apply_view_state(&self, view: &ViewState) {
+        self.set_time_offset(view.time_offset);
+        let zones = self.config.get_untracked().timezones.len();
+        self.selected_index.set(if view.selected_index < zones {
+            view.selected_index
+        } else {
+            0
+        });
+        self.sort_by_offset.set(view.sort_by_offset);
+        self.group_by_status.set(view.group_by_status);
+        self.focus_mode.set(view.focus_mode);
+        self.utc_reference.set(view.utc_reference);
+        self.format_override.set(Some(view.use_12h_format));
+    }
+
+    /// Toggle ordering cards by UTC offset
+    pub fn toggle_sort_by_offset(&self) {
+        self.sort_by_offset.update(|sorted| *sorted = !*sorted);
+    }
+
+    /// Toggle grouping working cards ahead of the others
+    pub fn toggle_group_by_status(&self) {
+        self.group_by_status.update(|grouped| *grouped = !*grouped);
+    }
+
+    /// Select the previous or next card, wrapping around
+    ///
+    /// Follows the on-screen order of the enabled zones, so diffs are
+    /// re-based on the neighbouring card.
+    ///
+    /// # Arguments
+    ///
+    /// * `forward` - Move to the next card if true, the previous one otherwise
+    pub fn select_adjacent(&self, forward: bool) {
+        let config = self.config.get_untracked();
+        let mut cards: Vec<_> = config
+            .timezones
+            .iter()
+            .enumerate()
+            .filter(|(_, tz)| tz.enabled)
+            .collect();
+        let now = self.current_time();
+        if self.sort_by_offset.get_untracked() {
+            sort_by_offset(now, &mut cards, |(_, tz)| *tz);
+        }
+        if self.group_by_status.get_untracked() {
+            group_by_status(now, &mut cards, |(_, tz)| *tz);
+        }
+        let order: Vec<usize> = cards.into_iter().map(|(index, _)| index).collect();
+        if let Some(index) = adjacent_index(&order, self.selected_index.get_untracked(), forward) {
+            self.select(index);
+        }
+    }
+
+    /// Mark or unmark a zone for sharing
+    pub fn toggle_marked(&self, index: usize) {
+        self.marked.update(|marked| {
+            if let Some(position) = marked.iter().position(|&i| i == index) {
+                marked.remove(position);
+            } else {
+                marked.push(index);
+            }
+        });
+    }
+
+    /// Get the configuration to share: the marked zones, or all of them
+    pub fn shared_config(&self) -> Config {
+        let config = self.config.get();
+        let marked = self.marked.get();
+        if marked.is_empty() {
+            config
+        } else {
+            config.with_zones(&marked)
+        }
+    }
+
+    /// Select a card, remembering the previous selection
+    pub fn select(&self, index: usize) {
+        let current = self.selected_index.get_untracked();
+        if current != index {
+            self.previous_index.set(Some(current));
+            self.selected_index.set(index);
+        }
+    }
+
+    /// Swap the selection with the previously selected card
+    ///
+    /// Flips the reference of the diffs between the two most recently
+    /// selected zones.
+    pub fn swap_reference(&self) {
+        let zones = self.config.get_untracked().timezones.len();
+        if let Some(previous) = self.previous_index.get_untracked()
+            && previous < zones
+        {
+            self.select(previous);
+        }
+    }
+
+    /// Toggle showing each zone's work hours on the reference clock
+    pub fn toggle_reference_hours(&self) {
+        self.show_reference_hours.update(|show| *show = !*show);
+    }
+
+    /// Toggle dimming of zones outside work hours
+    pub fn toggle_focus_mode(&self) {
+        self.focus_mode.update(|focus| *focus = !*focus);
+    }
+
+    /// Toggle measuring diffs against UTC
+    pub fn toggle_utc_reference(&self) {
+        self.utc_reference.update(|locked| *locked = !*locked);
+    }
+
+    /// Get the offset diffs and conversions are relative to
+    ///
+    /// # Arguments
+    ///
+    /// * `now` - Current (simulated) UTC time
+    /// * `config` - Configuration holding the home timezone
+    /// * `selected` - The selected zone, used without a home zone
+    ///
+    /// # Returns
+    ///
+    /// * `i32` - Offset in seconds; 0 while locked to UTC
+    pub fn reference_offset_at(
+        &self,
+        now: DateTime<Utc>,
+        config: &Config,
+        selected: Option<&TimezoneConfig>,
+    ) -> i32 {
+        if self.utc_reference.get() {
+            0
+        } else {
+            reference_offset(now, config, selected)
+        }
+    }
+
+    /// Get the zone diffs are relative to
+    ///
+    /// # Arguments
+    ///
+    /// * `now` - Simulated instant, fixing the browser's offset
+    /// * `config` - Configuration holding the home timezone and baseline
+    /// * `selected` - The selected zone
+    ///
+    /// # Returns
+    ///
+    /// * `TimezoneConfig` - UTC while locked or without a reference,
+    ///   otherwise the diff baseline's zone
+    pub fn reference_zone_at(
+        &self,
+        now: DateTime<Utc>,
+        config: &Config,
+        selected: Option<&TimezoneConfig>,
+    ) -> TimezoneConfig {
+        let zone = (!self.utc_reference.get())
+            .then(|| reference_zone(now, config, selected))
+            .flatten();
+        zone.unwrap_or_else(|| TimezoneConfig {
+            name: "UTC".to_string(),
+            timezone: "UTC".to_string(),
+            ..Default::default()
+        })
+    }
+
+    /// Get the name of the zone diffs are relative to
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - Configuration holding the home timezone
+    /// * `selected` - The selected zone, used without a home zone
+    ///
+    /// # Returns
+    ///
+    /// * `String` - "UTC" while locked, otherwise the home or selected zone's
+    ///   name, or "Local" for the browser timezone
+    pub fn reference_name(&self, config: &Config, selected: Option<&TimezoneConfig>) -> String {
+        reference_name(config, selected, self.utc_reference.get())
+    }
+
+    /// Toggle between the card grid and the diff matrix
+    pub fn toggle_matrix(&self) {
+        self.show_matrix.update(|show| *show = !*show);
+        self.show_week.set(false);
+    }
+
+    /// Toggle between the card grid and the 7-day availability grid
+    pub fn toggle_week(&self) {
+        self.show_week.update(|show| *show = !*show);
+        self.show_matrix.set(false);
+    }
+
+    /// Switch the zone list between the card grid and the table, saving
+    /// the choice
+    pub fn toggle_table_view(&self) {
+        self.table_view.update(|table| *table = !*table);
+        crate::storage::save_table_view(self.table_view.get_untracked());
+    }
+
     /// Toggle dark/light mode
     pub fn toggle_theme(&self) {
         self.dark_mode.update(|dark| *dark = !*dark);
         // Save preference to localStorage
-        if let Some(window) = web_sys::window()
+        if let Ok(window) = crate::browser::window("save the theme")
             && let Ok(Some(storage)) = window.local_storage()
         {
             let _ = storage.set_item(
@@ -130,3 +762,44 @@ impl AppState {
         }
     }
 }
+
+/// Find the entry before or after `current` in a cyclic order
+///
+/// When `current` is not in the order, the first (or last) entry is used.
+///
+/// # Arguments
+///
+/// * `order` - Indices in display order
+/// * `current` - The currently selected index
+/// * `forward` - Step forward if true, backward otherwise
+///
+/// # Returns
+///
+/// * `Option<usize>` - The adjacent index, or None if `order` is empty
+fn adjacent_index(order: &[usize], current: usize, forward: bool) -> Option<usize> {
+    let len = order.len();
+    let next = match order.iter().position(|index| *index == current) {
+        Some(position) if forward => (position + 1) % len,
+        Some(position) => (position + len - 1) % len,
+        None if forward => 0,
+        None => len.checked_sub(1)?,
+    };
+    order.get(next).copied()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_adjacent_index_wraps() {
+        let order = [2, 0, 3];
+        assert_eq!(adjacent_index(&order, 0, true), Some(3));
+        assert_eq!(adjacent_index(&order, 3, true), Some(2));
+        assert_eq!(adjacent_index(&order, 2, false), Some(3));
+        // A muted selection starts from either end
+        assert_eq!(adjacent_index(&order, 1, true), Some(2));
+        assert_eq!(adjacent_index(&order, 1, false), Some(3));
+        assert_eq!(adjacent_index(&[], 0, true), None);
+    }
+}
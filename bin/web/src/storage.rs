@@ -7,9 +7,26 @@ use base64::{Engine, engine::general_purpose::URL_SAFE_NO_PAD};
 use gloo_storage::{LocalStorage, Storage};
 use longtime_core::Config;
 
+use crate::{
+    browser::{self, BrowserError},
+    state::{Bookmark, VIEW_STATE_VERSION, ViewState},
+};
+
 /// LocalStorage key for configuration
 const STORAGE_KEY: &str = "longtime_config";
 
+/// LocalStorage key for time bookmarks
+const BOOKMARKS_KEY: &str = "longtime_bookmarks";
+
+/// LocalStorage key for recently used timezone identifiers
+const RECENT_TIMEZONES_KEY: &str = "longtime_recent_timezones";
+
+/// LocalStorage key for the card grid / table layout choice
+const TABLE_VIEW_KEY: &str = "longtime_table_view";
+
+/// Number of recently used timezones kept
+pub const MAX_RECENT_TIMEZONES: usize = 8;
+
 /// Save configuration to LocalStorage
 pub fn save_config(config: &Config) {
     let _ = LocalStorage::set(STORAGE_KEY, config);
@@ -20,6 +37,46 @@ pub fn load_config_from_storage() -> Option<Config> {
     LocalStorage::get(STORAGE_KEY).ok()
 }
 
+/// Save time bookmarks to LocalStorage
+pub fn save_bookmarks(bookmarks: &[Bookmark]) {
+    let _ = LocalStorage::set(BOOKMARKS_KEY, bookmarks);
+}
+
+/// Load time bookmarks from LocalStorage, empty when none are saved
+pub fn load_bookmarks() -> Vec<Bookmark> {
+    LocalStorage::get(BOOKMARKS_KEY).unwrap_or_default()
+}
+
+/// Save whether zones are listed as a table instead of cards
+pub fn save_table_view(table_view: bool) {
+    let _ = LocalStorage::set(TABLE_VIEW_KEY, table_view);
+}
+
+/// Load the saved layout choice, cards when none is saved
+pub fn load_table_view() -> bool {
+    LocalStorage::get(TABLE_VIEW_KEY).unwrap_or_default()
+}
+
+/// Load recently used timezone identifiers, most recent first
+pub fn load_recent_timezones() -> Vec<String> {
+    LocalStorage::get(RECENT_TIMEZONES_KEY).unwrap_or_default()
+}
+
+/// Record a timezone identifier as the most recently used one
+pub fn remember_timezone(timezone: &str) {
+    let mut recent = load_recent_timezones();
+    push_recent(&mut recent, timezone);
+    let _ = LocalStorage::set(RECENT_TIMEZONES_KEY, &recent);
+}
+
+/// Move `timezone` to the front of `recent`, dropping duplicates and
+/// anything beyond `MAX_RECENT_TIMEZONES`
+fn push_recent(recent: &mut Vec<String>, timezone: &str) {
+    recent.retain(|id| id != timezone);
+    recent.insert(0, timezone.to_string());
+    recent.truncate(MAX_RECENT_TIMEZONES);
+}
+
 /// Clear configuration from LocalStorage
 #[allow(dead_code)]
 pub fn clear_config() {
@@ -32,6 +89,13 @@ pub fn encode_config_to_url(config: &Config) -> String {
     URL_SAFE_NO_PAD.encode(json.as_bytes())
 }
 
+/// Copy a configuration without its muted (`enabled == false`) zones
+pub fn without_muted(config: &Config) -> Config {
+    let mut shared = config.clone();
+    shared.timezones.retain(|tz| tz.enabled);
+    shared
+}
+
 /// Decode configuration from a URL-safe Base64 string
 pub fn decode_config_from_url(encoded: &str) -> Option<Config> {
     let bytes = URL_SAFE_NO_PAD.decode(encoded).ok()?;
@@ -39,27 +103,60 @@ pub fn decode_config_from_url(encoded: &str) -> Option<Config> {
     serde_json::from_str(&json).ok()
 }
 
+/// Encode a view state to a URL-safe Base64 string
+pub fn encode_view_state(view: &ViewState) -> String {
+    let json = serde_json::to_string(view).unwrap_or_default();
+    URL_SAFE_NO_PAD.encode(json.as_bytes())
+}
+
+/// Decode a view state from a URL-safe Base64 string
+///
+/// States written by another encoding version are rejected.
+pub fn decode_view_state(encoded: &str) -> Option<ViewState> {
+    let bytes = URL_SAFE_NO_PAD.decode(encoded).ok()?;
+    let view: ViewState = serde_json::from_slice(&bytes).ok()?;
+    (view.version == VIEW_STATE_VERSION).then_some(view)
+}
+
+/// Generate a bookmarkable URL with the configuration and the current view
+///
+/// All zones are kept so the selected index in the view still matches.
+pub fn generate_view_url(config: &Config, view: &ViewState) -> Result<String, BrowserError> {
+    let share_url = generate_share_url(config, true)?;
+    Ok(format!("{share_url}&view={}", encode_view_state(view)))
+}
+
+/// Load the view state from the URL (?view=<Base64>), if there is one
+pub fn load_initial_view() -> Option<ViewState> {
+    get_query_param("view").and_then(|encoded| decode_view_state(&encoded))
+}
+
 /// Generate a shareable URL with the current configuration
-pub fn generate_share_url(config: &Config) -> String {
-    let encoded = encode_config_to_url(config);
-    let base_url = get_base_url();
-    format!("{base_url}?config={encoded}")
+///
+/// Muted zones are left out unless `include_muted` is set.
+pub fn generate_share_url(config: &Config, include_muted: bool) -> Result<String, BrowserError> {
+    let encoded = if include_muted {
+        encode_config_to_url(config)
+    } else {
+        encode_config_to_url(&without_muted(config))
+    };
+    let base_url = get_base_url()?;
+    Ok(format!("{base_url}?config={encoded}"))
 }
 
 /// Get the base URL without query parameters
-fn get_base_url() -> String {
-    web_sys::window()
-        .and_then(|w| w.location().href().ok())
-        .unwrap_or_default()
-        .split('?')
-        .next()
-        .unwrap_or("")
-        .to_string()
+fn get_base_url() -> Result<String, BrowserError> {
+    let href = browser::window("build the share URL")?
+        .location()
+        .href()
+        .unwrap_or_default();
+    Ok(href.split('?').next().unwrap_or("").to_string())
 }
 
 /// Get query parameter value from the current URL
 fn get_query_param(key: &str) -> Option<String> {
-    web_sys::window()
+    browser::window("read the URL query")
+        .ok()
         .and_then(|w| w.location().search().ok())
         .and_then(|search| {
             let search = search.trim_start_matches('?');
@@ -102,7 +199,7 @@ pub fn load_initial_config() -> Config {
 
 /// Copy text to clipboard
 pub async fn copy_to_clipboard(text: &str) -> Result<(), String> {
-    let window = web_sys::window().ok_or("No window")?;
+    let window = browser::window("copy to the clipboard").map_err(|e| e.to_string())?;
     let navigator = window.navigator();
     let clipboard = navigator.clipboard();
 
@@ -114,6 +211,29 @@ pub async fn copy_to_clipboard(text: &str) -> Result<(), String> {
     Ok(())
 }
 
+/// Copy text to clipboard, falling back to a manual copy on failure
+///
+/// On success `success_message` is shown. Clipboard access can be denied
+/// (permissions, insecure context), in which case the failure is logged and
+/// the text is shown pre-selected in a prompt so it can be copied by hand.
+pub async fn copy_with_fallback(text: &str, success_message: &str) {
+    let Ok(window) = browser::window("show the copy result") else {
+        return;
+    };
+    match copy_to_clipboard(text).await {
+        Ok(()) => {
+            let _ = window.alert_with_message(success_message);
+        }
+        Err(err) => {
+            leptos::logging::warn!("Clipboard copy failed: {err}");
+            let _ = window.prompt_with_message_and_default(
+                &format!("{err}. Copy it manually (Ctrl+C / Cmd+C):"),
+                text,
+            );
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -127,4 +247,80 @@ mod tests {
         assert!(decoded.is_some());
         assert_eq!(decoded.unwrap(), config);
     }
+
+    #[test]
+    fn test_encode_without_muted() {
+        let mut config = Config::default();
+        config.timezones[1].enabled = false;
+
+        let decoded =
+            decode_config_from_url(&encode_config_to_url(&without_muted(&config))).unwrap();
+        let names: Vec<_> = decoded
+            .timezones
+            .iter()
+            .map(|tz| tz.name.as_str())
+            .collect();
+        assert_eq!(names, ["Shanghai", "New York"]);
+        assert_eq!(decoded.title, config.title);
+
+        // Sharing everything keeps the muted zone
+        let decoded = decode_config_from_url(&encode_config_to_url(&config)).unwrap();
+        assert_eq!(decoded.timezones.len(), 3);
+    }
+
+    #[test]
+    fn test_view_state_roundtrip() {
+        let view = ViewState {
+            version: VIEW_STATE_VERSION,
+            time_offset: -5400,
+            selected_index: 2,
+            sort_by_offset: true,
+            group_by_status: false,
+            focus_mode: true,
+            utc_reference: true,
+            use_12h_format: true,
+        };
+        let encoded = encode_view_state(&view);
+        assert_eq!(decode_view_state(&encoded), Some(view.clone()));
+
+        let future = ViewState {
+            version: VIEW_STATE_VERSION + 1,
+            ..view
+        };
+        assert_eq!(decode_view_state(&encode_view_state(&future)), None);
+        assert_eq!(decode_view_state("not base64!"), None);
+    }
+
+    #[test]
+    fn test_push_recent_dedupes_and_caps() {
+        let mut recent = Vec::new();
+        for id in ["Asia/Tokyo", "Europe/London", "Asia/Tokyo"] {
+            push_recent(&mut recent, id);
+        }
+        assert_eq!(recent, ["Asia/Tokyo", "Europe/London"]);
+
+        for n in 0..10 {
+            push_recent(&mut recent, &format!("Etc/GMT+{n}"));
+        }
+        assert_eq!(recent.len(), MAX_RECENT_TIMEZONES);
+        assert_eq!(recent[0], "Etc/GMT+9");
+        assert!(!recent.contains(&"Asia/Tokyo".to_string()));
+    }
+
+    #[test]
+    fn test_grid_columns_persist() {
+        let config = Config {
+            grid_columns: Some(5),
+            ..Config::default()
+        };
+        let json = serde_json::to_string(&config).unwrap();
+        let restored: Config = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.grid_columns, Some(5));
+
+        // Configs saved before the setting existed start on the responsive grid
+        let mut value = serde_json::to_value(Config::default()).unwrap();
+        value.as_object_mut().unwrap().remove("grid_columns");
+        let restored: Config = serde_json::from_value(value).unwrap();
+        assert_eq!(restored.grid_columns, None);
+    }
 }
@@ -0,0 +1,191 @@
+//! Timezone identifier aliases
+//!
+//! The tz database keeps deprecated names (e.g. "Asia/Calcutta") as links to
+//! their canonical zones. `chrono_tz` accepts both spellings but does not
+//! expose which one is canonical, so the common links are listed here.
+
+use std::str::FromStr;
+
+use chrono_tz::Tz;
+
+/// Deprecated identifiers and the canonical identifiers they link to
+pub const TIMEZONE_ALIASES: &[(&str, &str)] = &[
+    ("Africa/Asmera", "Africa/Asmara"),
+    ("Africa/Timbuktu", "Africa/Bamako"),
+    ("America/Buenos_Aires", "America/Argentina/Buenos_Aires"),
+    ("America/Ensenada", "America/Tijuana"),
+    ("America/Fort_Wayne", "America/Indiana/Indianapolis"),
+    ("America/Godthab", "America/Nuuk"),
+    ("America/Indianapolis", "America/Indiana/Indianapolis"),
+    ("America/Knox_IN", "America/Indiana/Knox"),
+    ("America/Louisville", "America/Kentucky/Louisville"),
+    ("America/Montreal", "America/Toronto"),
+    ("America/Shiprock", "America/Denver"),
+    ("Asia/Ashkhabad", "Asia/Ashgabat"),
+    ("Asia/Calcutta", "Asia/Kolkata"),
+    ("Asia/Chongqing", "Asia/Shanghai"),
+    ("Asia/Chungking", "Asia/Shanghai"),
+    ("Asia/Dacca", "Asia/Dhaka"),
+    ("Asia/Harbin", "Asia/Shanghai"),
+    ("Asia/Istanbul", "Europe/Istanbul"),
+    ("Asia/Kashgar", "Asia/Urumqi"),
+    ("Asia/Katmandu", "Asia/Kathmandu"),
+    ("Asia/Macao", "Asia/Macau"),
+    ("Asia/Rangoon", "Asia/Yangon"),
+    ("Asia/Saigon", "Asia/Ho_Chi_Minh"),
+    ("Asia/Tel_Aviv", "Asia/Jerusalem"),
+    ("Asia/Thimbu", "Asia/Thimphu"),
+    ("Asia/Ujung_Pandang", "Asia/Makassar"),
+    ("Asia/Ulan_Bator", "Asia/Ulaanbaatar"),
+    ("Atlantic/Faeroe", "Atlantic/Faroe"),
+    ("Australia/ACT", "Australia/Sydney"),
+    ("Australia/Canberra", "Australia/Sydney"),
+    ("Australia/NSW", "Australia/Sydney"),
+    ("Australia/North", "Australia/Darwin"),
+    ("Australia/Queensland", "Australia/Brisbane"),
+    ("Australia/South", "Australia/Adelaide"),
+    ("Australia/Tasmania", "Australia/Hobart"),
+    ("Australia/Victoria", "Australia/Melbourne"),
+    ("Australia/West", "Australia/Perth"),
+    ("Brazil/East", "America/Sao_Paulo"),
+    ("Canada/Atlantic", "America/Halifax"),
+    ("Canada/Central", "America/Winnipeg"),
+    ("Canada/Eastern", "America/Toronto"),
+    ("Canada/Mountain", "America/Edmonton"),
+    ("Canada/Newfoundland", "America/St_Johns"),
+    ("Canada/Pacific", "America/Vancouver"),
+    ("Chile/Continental", "America/Santiago"),
+    ("Cuba", "America/Havana"),
+    ("Egypt", "Africa/Cairo"),
+    ("Eire", "Europe/Dublin"),
+    ("Etc/UCT", "Etc/UTC"),
+    ("Etc/Universal", "Etc/UTC"),
+    ("Etc/Zulu", "Etc/UTC"),
+    ("Europe/Belfast", "Europe/London"),
+    ("Europe/Kiev", "Europe/Kyiv"),
+    ("Europe/Nicosia", "Asia/Nicosia"),
+    ("GB", "Europe/London"),
+    ("Hongkong", "Asia/Hong_Kong"),
+    ("Iran", "Asia/Tehran"),
+    ("Israel", "Asia/Jerusalem"),
+    ("Jamaica", "America/Jamaica"),
+    ("Japan", "Asia/Tokyo"),
+    ("Libya", "Africa/Tripoli"),
+    ("Mexico/General", "America/Mexico_City"),
+    ("Navajo", "America/Denver"),
+    ("NZ", "Pacific/Auckland"),
+    ("Pacific/Enderbury", "Pacific/Kanton"),
+    ("Pacific/Ponape", "Pacific/Pohnpei"),
+    ("Pacific/Truk", "Pacific/Chuuk"),
+    ("Poland", "Europe/Warsaw"),
+    ("Portugal", "Europe/Lisbon"),
+    ("PRC", "Asia/Shanghai"),
+    ("ROC", "Asia/Taipei"),
+    ("ROK", "Asia/Seoul"),
+    ("Singapore", "Asia/Singapore"),
+    ("Turkey", "Europe/Istanbul"),
+    ("UCT", "Etc/UTC"),
+    ("Universal", "Etc/UTC"),
+    ("UTC", "Etc/UTC"),
+    ("US/Alaska", "America/Anchorage"),
+    ("US/Arizona", "America/Phoenix"),
+    ("US/Central", "America/Chicago"),
+    ("US/Eastern", "America/New_York"),
+    ("US/Hawaii", "Pacific/Honolulu"),
+    ("US/Mountain", "America/Denver"),
+    ("US/Pacific", "America/Los_Angeles"),
+    ("Zulu", "Etc/UTC"),
+];
+
+/// Map a timezone identifier to its canonical name
+///
+/// Surrounding whitespace is ignored. Known deprecated aliases are replaced
+/// by the zone they link to; other identifiers are returned as spelled by
+/// `chrono_tz`.
+///
+/// # Arguments
+///
+/// * `tz_str` - IANA timezone identifier, e.g. "Asia/Calcutta"
+///
+/// # Returns
+///
+/// * `Option<String>` - The canonical identifier, e.g. "Asia/Kolkata", or
+///   None if the identifier is unknown
+///
+/// # Example
+///
+/// ```
+/// use longtime_core::canonicalize_timezone;
+///
+/// assert_eq!(
+///     canonicalize_timezone("Asia/Calcutta").as_deref(),
+///     Some("Asia/Kolkata")
+/// );
+/// ```
+pub fn canonicalize_timezone(tz_str: &str) -> Option<String> {
+    let tz = Tz::from_str(tz_str.trim()).ok()?;
+    let name = tz.name();
+    let canonical = TIMEZONE_ALIASES
+        .iter()
+        .find(|(alias, _)| *alias == name)
+        .map_or(name, |(_, canonical)| *canonical);
+    Some(canonical.to_string())
+}
+
+/// Check whether two identifiers name the same canonical zone
+///
+/// # Arguments
+///
+/// * `a` - First IANA timezone identifier
+/// * `b` - Second IANA timezone identifier
+///
+/// # Returns
+///
+/// * `bool` - True if both are known and canonicalize to the same zone
+pub fn same_timezone(a: &str, b: &str) -> bool {
+    match (canonicalize_timezone(a), canonicalize_timezone(b)) {
+        (Some(a), Some(b)) => a == b,
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_canonicalize_timezone() {
+        assert_eq!(
+            canonicalize_timezone("Asia/Calcutta").as_deref(),
+            Some("Asia/Kolkata")
+        );
+        assert_eq!(
+            canonicalize_timezone(" US/Eastern ").as_deref(),
+            Some("America/New_York")
+        );
+        assert_eq!(
+            canonicalize_timezone("Asia/Kolkata").as_deref(),
+            Some("Asia/Kolkata")
+        );
+        assert_eq!(canonicalize_timezone("Mars/Base"), None);
+        for alias in ["UTC", "Etc/UCT", "Etc/Universal", "Etc/Zulu", "Zulu"] {
+            assert_eq!(canonicalize_timezone(alias).as_deref(), Some("Etc/UTC"));
+        }
+
+        assert!(same_timezone("Asia/Calcutta", "Asia/Kolkata"));
+        assert!(!same_timezone("Asia/Calcutta", "Asia/Dhaka"));
+        assert!(!same_timezone("Mars/Base", "Mars/Base"));
+    }
+
+    #[test]
+    fn test_aliases_link_to_known_canonical_zones() {
+        for (alias, canonical) in TIMEZONE_ALIASES {
+            assert!(Tz::from_str(alias).is_ok(), "{alias}");
+            assert!(Tz::from_str(canonical).is_ok(), "{canonical}");
+            assert!(
+                TIMEZONE_ALIASES.iter().all(|(other, _)| other != canonical),
+                "{canonical} is itself an alias"
+            );
+        }
+    }
+}
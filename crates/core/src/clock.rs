@@ -0,0 +1,50 @@
+//! Time sources
+//!
+//! This module abstracts "what time is it now" behind the `Clock` trait so
+//! that application state can be driven by the system clock in production
+//! and by a fixed instant in tests.
+
+use std::fmt::Debug;
+
+use chrono::{DateTime, Utc};
+
+/// A source of the current UTC time
+pub trait Clock: Debug + Send + Sync {
+    /// Returns the current instant according to this clock
+    fn now(&self) -> DateTime<Utc>;
+}
+
+/// Clock backed by the system time
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
+
+/// Clock that always reports the same instant
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FixedClock(pub DateTime<Utc>);
+
+impl Clock for FixedClock {
+    fn now(&self) -> DateTime<Utc> {
+        self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+
+    #[test]
+    fn test_fixed_clock() {
+        let instant = Utc.with_ymd_and_hms(2024, 3, 1, 12, 0, 0).unwrap();
+        let clock = FixedClock(instant);
+        assert_eq!(clock.now(), instant);
+        assert_eq!(clock.now(), instant);
+    }
+}
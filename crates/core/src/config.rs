@@ -3,10 +3,19 @@
 //! This module defines the configuration structures used to represent
 //! timezone information and work hours settings.
 
-use chrono::NaiveTime;
+use std::str::FromStr;
+
+use chrono::{FixedOffset, NaiveDate, NaiveTime, Timelike, Weekday};
+use chrono_tz::Tz;
 use serde::{Deserialize, Serialize};
 
+use crate::expression::parse_relative_offset;
+
 /// The main configuration struct that holds all timezone information
+///
+/// Fields serialize in declaration order, so saved files and shared links
+/// list keys in the same order across versions; options that are unset or
+/// left at their default are left out rather than written out.
 #[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 pub struct Config {
     /// List of timezone configurations
@@ -14,6 +23,436 @@ pub struct Config {
     /// Whether to use 12-hour format (default: false)
     #[serde(default)]
     pub use_12h_format: bool,
+    /// Whether 12-hour times drop the leading zero of the hour, e.g.
+    /// "9:05 AM" instead of "09:05 AM" (default: false)
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub compact_12h: bool,
+    /// How dates are rendered (default: ISO)
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub date_style: DateStyle,
+    /// Minutes before the end of work hours highlighted as "ending soon"
+    /// (default: 30, 0 disables the highlight)
+    #[serde(
+        default = "default_ending_soon_minutes",
+        skip_serializing_if = "is_default_ending_soon_minutes"
+    )]
+    pub ending_soon_minutes: u32,
+    /// Minutes the browser clock may differ from the web server's before a
+    /// warning is shown (default: 5, 0 disables the check)
+    #[serde(
+        default = "default_clock_check_minutes",
+        skip_serializing_if = "is_default_clock_check_minutes"
+    )]
+    pub clock_check_minutes: u32,
+    /// IANA timezone identifier that diffs are measured against; when unset,
+    /// the selected zone is used
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub home_timezone: Option<String>,
+    /// What diffs are measured against (default: `home` when
+    /// `home_timezone` is set, otherwise `selected`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub diff_baseline: Option<DiffBaseline>,
+    /// IANA timezone identifier whose current time is shown in the TUI
+    /// title (default: unset, plain title)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title_timezone: Option<String>,
+    /// Local times ("HH:MM") in the reference zone offered as quick jumps
+    /// (default: 09:00, 12:00, 17:00)
+    #[serde(
+        default = "default_quick_jump_times",
+        skip_serializing_if = "is_default_quick_jump_times"
+    )]
+    pub quick_jump_times: Vec<String>,
+    /// Named time offsets from now, e.g. "+8h" for "India EOD" (default: none)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub saved_offsets: Vec<SavedOffset>,
+    /// Round displayed times to the nearest multiple of this many minutes
+    /// (default: unset, exact minutes)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub round_minutes: Option<u32>,
+    /// Whether the diff column is shown (default: true)
+    #[serde(
+        default = "default_show_diff",
+        skip_serializing_if = "is_default_show_diff"
+    )]
+    pub show_diff: bool,
+    /// Whether zones on another calendar day than the reference clock get a
+    /// "+1d"/"-1d" chip (default: false)
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub show_day_offset: bool,
+    /// How diffs are worded (default: numeric, e.g. "+8")
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub diff_style: DiffStyle,
+    /// Application title shown in both UIs (default: `DEFAULT_TITLE`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// Fixed number of card columns in the web grid, 1 to `MAX_GRID_COLUMNS`
+    /// (default: unset, responsive to the screen width)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub grid_columns: Option<u8>,
+}
+
+/// Title used when `Config::title` is unset or blank
+pub const DEFAULT_TITLE: &str = "LongTime";
+
+/// Largest supported `grid_columns` value
+pub const MAX_GRID_COLUMNS: u8 = 6;
+
+/// Keys a serialized `Config` may contain, in order
+pub const CONFIG_KEYS: &[&str] = &[
+    "timezones",
+    "use_12h_format",
+    "compact_12h",
+    "date_style",
+    "ending_soon_minutes",
+    "clock_check_minutes",
+    "home_timezone",
+    "diff_baseline",
+    "title_timezone",
+    "quick_jump_times",
+    "saved_offsets",
+    "round_minutes",
+    "show_diff",
+    "show_day_offset",
+    "diff_style",
+    "title",
+    "grid_columns",
+];
+
+fn default_ending_soon_minutes() -> u32 {
+    30
+}
+
+fn is_default_ending_soon_minutes(minutes: &u32) -> bool {
+    *minutes == default_ending_soon_minutes()
+}
+
+fn default_clock_check_minutes() -> u32 {
+    5
+}
+
+fn is_default_clock_check_minutes(minutes: &u32) -> bool {
+    *minutes == default_clock_check_minutes()
+}
+
+fn default_show_diff() -> bool {
+    true
+}
+
+fn is_default_show_diff(show: &bool) -> bool {
+    *show == default_show_diff()
+}
+
+fn default_quick_jump_times() -> Vec<String> {
+    ["09:00", "12:00", "17:00"].map(String::from).to_vec()
+}
+
+fn is_default_quick_jump_times(times: &[String]) -> bool {
+    times == default_quick_jump_times()
+}
+
+fn is_zero(minutes: &u32) -> bool {
+    *minutes == 0
+}
+
+fn is_default<T: Default + PartialEq>(value: &T) -> bool {
+    *value == T::default()
+}
+
+/// Date rendering style
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DateStyle {
+    /// ISO 8601, e.g. "2023-06-01"
+    #[default]
+    Iso,
+    /// US month-first, e.g. "06/01/2023"
+    Us,
+    /// European day-first, e.g. "01/06/2023"
+    Eu,
+    /// Short month name and day, e.g. "Jun 1"
+    Long,
+}
+
+impl DateStyle {
+    /// All styles, in the order UIs cycle through them
+    pub const ALL: [DateStyle; 4] = [
+        DateStyle::Iso,
+        DateStyle::Us,
+        DateStyle::Eu,
+        DateStyle::Long,
+    ];
+
+    /// The `chrono` format string for this style
+    pub fn format_str(self) -> &'static str {
+        match self {
+            DateStyle::Iso => "%Y-%m-%d",
+            DateStyle::Us => "%m/%d/%Y",
+            DateStyle::Eu => "%d/%m/%Y",
+            DateStyle::Long => "%b %-d",
+        }
+    }
+
+    /// Short label for display
+    pub fn label(self) -> &'static str {
+        match self {
+            DateStyle::Iso => "ISO",
+            DateStyle::Us => "US",
+            DateStyle::Eu => "EU",
+            DateStyle::Long => "Long",
+        }
+    }
+
+    /// The style following this one, wrapping around
+    pub fn next(self) -> Self {
+        let index = Self::ALL.iter().position(|&s| s == self).unwrap_or(0);
+        Self::ALL[(index + 1) % Self::ALL.len()]
+    }
+}
+
+/// How time differences are written
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DiffStyle {
+    /// Signed hours, e.g. "+8" or "-5"
+    #[default]
+    Numeric,
+    /// Words, e.g. "8h ahead" or "5h behind"
+    Words,
+}
+
+impl DiffStyle {
+    /// Short label for display
+    pub fn label(self) -> &'static str {
+        match self {
+            DiffStyle::Numeric => "+/-",
+            DiffStyle::Words => "ahead/behind",
+        }
+    }
+
+    /// The other style
+    pub fn next(self) -> Self {
+        match self {
+            DiffStyle::Numeric => DiffStyle::Words,
+            DiffStyle::Words => DiffStyle::Numeric,
+        }
+    }
+}
+
+/// What time differences are measured against
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DiffBaseline {
+    /// The selected zone
+    #[default]
+    Selected,
+    /// The home zone (`home_timezone`), or the selected zone when unset
+    Home,
+    /// The local system (or browser) timezone
+    Local,
+}
+
+impl Config {
+    /// Get what diffs are measured against
+    ///
+    /// Without an explicit `diff_baseline`, a configured `home_timezone`
+    /// is used, as before the option existed.
+    pub fn effective_diff_baseline(&self) -> DiffBaseline {
+        match (self.diff_baseline, &self.home_timezone) {
+            (Some(baseline), _) => baseline,
+            (None, Some(_)) => DiffBaseline::Home,
+            (None, None) => DiffBaseline::Selected,
+        }
+    }
+
+    /// Get the application title, falling back to `DEFAULT_TITLE`
+    pub fn display_title(&self) -> &str {
+        self.title
+            .as_deref()
+            .map(str::trim)
+            .filter(|title| !title.is_empty())
+            .unwrap_or(DEFAULT_TITLE)
+    }
+
+    /// Get the configured quick-jump times, skipping entries that fail to parse
+    pub fn quick_jumps(&self) -> Vec<NaiveTime> {
+        self.quick_jump_times
+            .iter()
+            .filter_map(|time| NaiveTime::parse_from_str(time, "%H:%M").ok())
+            .collect()
+    }
+
+    /// Copy the configuration with only some of its zones
+    ///
+    /// Global settings such as `use_12h_format` are kept; zones stay in
+    /// config order.
+    ///
+    /// # Arguments
+    ///
+    /// * `indices` - Indices of the zones to keep; unknown indices are ignored
+    pub fn with_zones(&self, indices: &[usize]) -> Config {
+        Config {
+            timezones: self
+                .timezones
+                .iter()
+                .enumerate()
+                .filter(|(index, _)| indices.contains(index))
+                .map(|(_, tz)| tz.clone())
+                .collect(),
+            ..self.clone()
+        }
+    }
+
+    /// Set the work hours of every zone at once
+    ///
+    /// Only `start` and `end` change; each zone keeps its own
+    /// `end_of_minute` setting.
+    ///
+    /// # Arguments
+    ///
+    /// * `hours` - The new work hours
+    /// * `only_matching` - If set, only zones currently working these hours
+    ///   are changed
+    ///
+    /// # Returns
+    ///
+    /// * `usize` - The number of zones changed
+    pub fn set_all_work_hours(
+        &mut self,
+        hours: &WorkHours,
+        only_matching: Option<&WorkHours>,
+    ) -> usize {
+        let mut changed = 0;
+        for tz in &mut self.timezones {
+            if only_matching.is_some_and(|range| !tz.work_hours.same_range(range)) {
+                continue;
+            }
+            tz.work_hours.start = hours.start.clone();
+            tz.work_hours.end = hours.end.clone();
+            changed += 1;
+        }
+        changed
+    }
+
+    /// Count the zones `set_all_work_hours` would change, without changing
+    /// them
+    ///
+    /// # Arguments
+    ///
+    /// * `only_matching` - If set, only zones currently working these hours
+    ///   are counted
+    ///
+    /// # Returns
+    ///
+    /// * `usize` - The number of zones that would change
+    pub fn count_work_hours_targets(&self, only_matching: Option<&WorkHours>) -> usize {
+        self.timezones
+            .iter()
+            .filter(|tz| only_matching.is_none_or(|range| tz.work_hours.same_range(range)))
+            .count()
+    }
+
+    /// Move the work hours of every zone by the same number of minutes
+    ///
+    /// Times wrap across midnight, so 22:00 shifted by +180 becomes 01:00,
+    /// and whole days drop out of any shift, however large. Nothing changes
+    /// unless every zone's work hours parse.
+    ///
+    /// # Arguments
+    ///
+    /// * `minutes` - The shift, positive for later hours
+    ///
+    /// # Returns
+    ///
+    /// * `Result<(), String>` - Ok once shifted, or a message naming the
+    ///   first zone whose work hours are not in HH:MM format
+    pub fn shift_work_hours(&mut self, minutes: i64) -> Result<(), String> {
+        let delta = chrono::Duration::minutes(minutes.rem_euclid(24 * 60));
+        let mut shifted = Vec::with_capacity(self.timezones.len());
+        for tz in &self.timezones {
+            let (Some(start), Some(end)) = (tz.work_hours.start_time(), tz.work_hours.end_time())
+            else {
+                return Err(format!(
+                    "{}: work hours \"{}\"-\"{}\" are not in HH:MM format",
+                    tz.name, tz.work_hours.start, tz.work_hours.end
+                ));
+            };
+            shifted.push((
+                start.overflowing_add_signed(delta).0,
+                end.overflowing_add_signed(delta).0,
+            ));
+        }
+        for (tz, (start, end)) in self.timezones.iter_mut().zip(shifted) {
+            tz.work_hours.start = start.format("%H:%M").to_string();
+            tz.work_hours.end = end.format("%H:%M").to_string();
+        }
+        Ok(())
+    }
+
+    /// Describe an unusable `home_timezone`
+    ///
+    /// Diffs then fall back to the selected zone, which is easy to mistake
+    /// for the configured home.
+    ///
+    /// # Returns
+    ///
+    /// * `Option<String>` - The warning, or None if the home zone is unset or valid
+    pub fn home_timezone_warning(&self) -> Option<String> {
+        let home = self.home_timezone.as_deref()?;
+        (!is_valid_timezone(home)).then(|| {
+            format!("home timezone \"{home}\" is invalid; diffs use the selected zone instead")
+        })
+    }
+
+    /// Check the configuration for values the UIs cannot use
+    ///
+    /// Invalid entries do not stop the app from running, but the affected
+    /// zones cannot show a time or work status. A zone with an
+    /// `offset_override` runs on that offset, so its identifier is not
+    /// checked.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<(), Vec<String>>` - Ok if everything is usable, otherwise one
+    ///   message per problem
+    pub fn validate(&self) -> Result<(), Vec<String>> {
+        let mut problems = Vec::new();
+        for tz in &self.timezones {
+            if tz.offset_override.is_none() && !is_valid_timezone(&tz.timezone) {
+                problems.push(format!("{}: unknown timezone \"{}\"", tz.name, tz.timezone));
+            }
+            if let Some(offset) = tz.offset_override
+                && FixedOffset::east_opt(offset).is_none()
+            {
+                problems.push(format!(
+                    "{}: offset override {offset}s is out of range",
+                    tz.name
+                ));
+            }
+            if !tz.work_hours.is_valid() {
+                problems.push(format!(
+                    "{}: work hours \"{}\"-\"{}\" are not in HH:MM format",
+                    tz.name, tz.work_hours.start, tz.work_hours.end
+                ));
+            }
+        }
+        problems.extend(self.home_timezone_warning());
+        if let Some(anchor) = &self.title_timezone
+            && !is_valid_timezone(anchor)
+        {
+            problems.push(format!("title_timezone: unknown timezone \"{anchor}\""));
+        }
+        for saved in &self.saved_offsets {
+            if let Err(message) = saved.seconds() {
+                problems.push(format!("saved offset \"{}\": {message}", saved.name));
+            }
+        }
+        if problems.is_empty() {
+            Ok(())
+        } else {
+            Err(problems)
+        }
+    }
 }
 
 impl Default for Config {
@@ -26,7 +465,9 @@ impl Default for Config {
                     work_hours: WorkHours {
                         start: "09:00".to_string(),
                         end: "18:00".to_string(),
+                        ..Default::default()
                     },
+                    ..Default::default()
                 },
                 TimezoneConfig {
                     name: "London".to_string(),
@@ -34,23 +475,53 @@ impl Default for Config {
                     work_hours: WorkHours {
                         start: "09:00".to_string(),
                         end: "17:30".to_string(),
+                        ..Default::default()
                     },
+                    ..Default::default()
                 },
                 TimezoneConfig {
                     name: "New York".to_string(),
                     timezone: "America/New_York".to_string(),
-                    work_hours: WorkHours {
-                        start: "09:00".to_string(),
-                        end: "17:00".to_string(),
-                    },
+                    work_hours: WorkHours::default(),
+                    ..Default::default()
                 },
             ],
             use_12h_format: false,
+            compact_12h: false,
+            date_style: DateStyle::Iso,
+            ending_soon_minutes: default_ending_soon_minutes(),
+            clock_check_minutes: default_clock_check_minutes(),
+            home_timezone: None,
+            diff_baseline: None,
+            title_timezone: None,
+            quick_jump_times: default_quick_jump_times(),
+            saved_offsets: Vec::new(),
+            round_minutes: None,
+            show_diff: true,
+            show_day_offset: false,
+            grid_columns: None,
+            diff_style: DiffStyle::Numeric,
+            title: None,
         }
     }
 }
 
+/// Check whether a string is a known IANA timezone identifier
+///
+/// # Arguments
+///
+/// * `tz_str` - The identifier, e.g. "America/New_York"
+///
+/// # Returns
+///
+/// * `bool` - True if the identifier is known
+pub fn is_valid_timezone(tz_str: &str) -> bool {
+    Tz::from_str(tz_str).is_ok()
+}
+
 /// Configuration for a single timezone
+///
+/// Serializes in declaration order, leaving out unset and default options.
 #[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 pub struct TimezoneConfig {
     /// Display name for the timezone
@@ -59,15 +530,156 @@ pub struct TimezoneConfig {
     pub timezone: String,
     /// Work hours configuration
     pub work_hours: WorkHours,
+    /// Per-zone 12-hour format override (falls back to the global setting)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub use_12h_format: Option<bool>,
+    /// Whether the timezone is shown; muted zones stay configured but hidden
+    /// (default: true)
+    #[serde(
+        default = "default_enabled",
+        skip_serializing_if = "is_default_enabled"
+    )]
+    pub enabled: bool,
+    /// Days of the week with work hours, e.g. `["Mon", "Tue", "Wed", "Thu", "Fri"]`
+    /// (default: every day)
+    #[serde(
+        default = "default_work_days",
+        skip_serializing_if = "is_default_work_days"
+    )]
+    pub work_days: Vec<Weekday>,
+    /// Local dates without work hours, e.g. `["2024-12-25"]`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub holidays: Vec<NaiveDate>,
+    /// Whether the zone is always working (24/7 coverage), ignoring work
+    /// hours, work days and holidays (default: false)
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub always_working: bool,
+    /// Minutes before the work hours in which the zone is still reachable,
+    /// reported as `WorkStatus::Grace` (default: 0)
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub grace_before_minutes: u32,
+    /// Minutes after the work hours in which the zone is still reachable,
+    /// reported as `WorkStatus::Grace` (default: 0)
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub grace_after_minutes: u32,
+    /// Minutes before the work hours start in which the zone asks for
+    /// attention, e.g. a pulsing card (default: 0, disabled)
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub remind_before_minutes: u32,
+    /// Fixed UTC offset in seconds used instead of the IANA zone, for "what
+    /// if" scenarios; the `timezone` identifier is kept unchanged
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub offset_override: Option<i32>,
+}
+
+/// Keys a serialized `TimezoneConfig` may contain, in order
+pub const TIMEZONE_KEYS: &[&str] = &[
+    "name",
+    "timezone",
+    "work_hours",
+    "use_12h_format",
+    "enabled",
+    "work_days",
+    "holidays",
+    "always_working",
+    "grace_before_minutes",
+    "grace_after_minutes",
+    "remind_before_minutes",
+    "offset_override",
+];
+
+fn default_enabled() -> bool {
+    true
+}
+
+fn is_default_enabled(enabled: &bool) -> bool {
+    *enabled == default_enabled()
+}
+
+fn default_work_days() -> Vec<Weekday> {
+    ALL_WEEKDAYS.to_vec()
+}
+
+fn is_default_work_days(days: &[Weekday]) -> bool {
+    days == ALL_WEEKDAYS
+}
+
+/// Every day of the week, Monday first
+pub const ALL_WEEKDAYS: [Weekday; 7] = [
+    Weekday::Mon,
+    Weekday::Tue,
+    Weekday::Wed,
+    Weekday::Thu,
+    Weekday::Fri,
+    Weekday::Sat,
+    Weekday::Sun,
+];
+
+impl Default for TimezoneConfig {
+    fn default() -> Self {
+        Self {
+            name: "UTC".to_string(),
+            timezone: "UTC".to_string(),
+            work_hours: WorkHours::default(),
+            use_12h_format: None,
+            enabled: true,
+            work_days: default_work_days(),
+            holidays: Vec::new(),
+            always_working: false,
+            grace_before_minutes: 0,
+            grace_after_minutes: 0,
+            remind_before_minutes: 0,
+            offset_override: None,
+        }
+    }
+}
+
+/// A named time offset from the current time
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct SavedOffset {
+    /// Label shown in the offset menu, e.g. "India EOD"
+    pub name: String,
+    /// Relative time expression, e.g. "+8h" or "-1d 2h"
+    pub offset: String,
+}
+
+impl SavedOffset {
+    /// Get the offset in seconds
+    ///
+    /// # Returns
+    ///
+    /// * `Result<i64, String>` - The offset, or a message describing the problem
+    pub fn seconds(&self) -> Result<i64, String> {
+        parse_relative_offset(&self.offset)
+    }
 }
 
 /// Work hours configuration for a timezone
+///
+/// Serializes in declaration order.
 #[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 pub struct WorkHours {
     /// Start time of work hours (format: "HH:MM")
     pub start: String,
     /// End time of work hours (format: "HH:MM")
     pub end: String,
+    /// Whether the whole end minute counts as working, i.e. "17:00" lasts
+    /// until 17:00:59 (default: false)
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub end_of_minute: bool,
+}
+
+/// Keys a serialized `WorkHours` may contain, in order
+pub const WORK_HOURS_KEYS: &[&str] = &["start", "end", "end_of_minute"];
+
+impl Default for WorkHours {
+    fn default() -> Self {
+        Self {
+            start: "09:00".to_string(),
+            end: "17:00".to_string(),
+            end_of_minute: false,
+        }
+    }
 }
 
 impl WorkHours {
@@ -88,6 +700,60 @@ impl WorkHours {
     pub fn end_time(&self) -> Option<NaiveTime> {
         NaiveTime::parse_from_str(&self.end, "%H:%M").ok()
     }
+
+    /// Parses a range such as "10:00-18:00"
+    ///
+    /// # Arguments
+    ///
+    /// * `range` - Start and end in "HH:MM" format, separated by a dash
+    ///
+    /// # Returns
+    ///
+    /// * `Result<WorkHours, String>` - The work hours, or an error message
+    pub fn parse_range(range: &str) -> Result<Self, String> {
+        let (start, end) = range
+            .split_once('-')
+            .ok_or_else(|| format!("Expected a range like 10:00-18:00, got \"{range}\""))?;
+        let hours = WorkHours {
+            start: start.trim().to_string(),
+            end: end.trim().to_string(),
+            ..Default::default()
+        };
+        if hours.is_valid() {
+            Ok(hours)
+        } else {
+            Err(format!("Times must be in HH:MM format, got \"{range}\""))
+        }
+    }
+
+    /// Checks whether two work hours cover the same range
+    ///
+    /// Times are compared after parsing, so "9:00" matches "09:00".
+    pub fn same_range(&self, other: &WorkHours) -> bool {
+        self.start_time() == other.start_time() && self.end_time() == other.end_time()
+    }
+
+    /// Checks that both the start and end times parse
+    pub fn is_valid(&self) -> bool {
+        self.start_time().is_some() && self.end_time().is_some()
+    }
+
+    /// Returns the last instant of the day that still counts as working
+    ///
+    /// This is the parsed end time, extended to `HH:MM:59` when
+    /// `end_of_minute` is set.
+    ///
+    /// # Returns
+    ///
+    /// * `Option<NaiveTime>` - The effective end time or None if parsing fails
+    pub fn effective_end_time(&self) -> Option<NaiveTime> {
+        let end = self.end_time()?;
+        if self.end_of_minute {
+            end.with_second(59)
+        } else {
+            Some(end)
+        }
+    }
 }
 
 #[cfg(test)]
@@ -99,6 +765,7 @@ mod tests {
         let wh = WorkHours {
             start: "09:00".to_string(),
             end: "17:00".to_string(),
+            ..Default::default()
         };
 
         assert_eq!(
@@ -111,11 +778,27 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_effective_end_time() {
+        let mut wh = WorkHours::default();
+        assert_eq!(
+            wh.effective_end_time(),
+            Some(NaiveTime::from_hms_opt(17, 0, 0).unwrap())
+        );
+
+        wh.end_of_minute = true;
+        assert_eq!(
+            wh.effective_end_time(),
+            Some(NaiveTime::from_hms_opt(17, 0, 59).unwrap())
+        );
+    }
+
     #[test]
     fn test_invalid_work_hours() {
         let wh = WorkHours {
             start: "25:00".to_string(),
             end: "invalid".to_string(),
+            ..Default::default()
         };
 
         assert_eq!(wh.start_time(), None);
@@ -127,11 +810,260 @@ mod tests {
         let config = Config::default();
         assert_eq!(config.timezones.len(), 3);
         assert!(!config.use_12h_format);
+        assert_eq!(config.date_style, DateStyle::Iso);
         assert_eq!(config.timezones[0].name, "Shanghai");
         assert_eq!(config.timezones[1].name, "London");
         assert_eq!(config.timezones[2].name, "New York");
     }
 
+    #[test]
+    fn test_effective_diff_baseline() {
+        let mut config = Config::default();
+        assert_eq!(config.effective_diff_baseline(), DiffBaseline::Selected);
+
+        config.home_timezone = Some("Europe/London".to_string());
+        assert_eq!(config.effective_diff_baseline(), DiffBaseline::Home);
+
+        let config: Config = serde_json::from_str(
+            r#"{"timezones": [], "home_timezone": "Europe/London", "diff_baseline": "local"}"#,
+        )
+        .unwrap();
+        assert_eq!(config.effective_diff_baseline(), DiffBaseline::Local);
+    }
+
+    #[test]
+    fn test_date_style_cycle() {
+        assert_eq!(DateStyle::Iso.next(), DateStyle::Us);
+        assert_eq!(DateStyle::Long.next(), DateStyle::Iso);
+    }
+
+    #[test]
+    fn test_date_style_deserialize() {
+        let config: Config =
+            serde_json::from_str(r#"{"timezones": [], "date_style": "us"}"#).unwrap();
+        assert_eq!(config.date_style, DateStyle::Us);
+
+        let config: Config = serde_json::from_str(r#"{"timezones": []}"#).unwrap();
+        assert_eq!(config.date_style, DateStyle::Iso);
+        assert_eq!(config.ending_soon_minutes, 30);
+        assert_eq!(config.clock_check_minutes, 5);
+    }
+
+    #[test]
+    fn test_timezone_enabled_default() {
+        let tz: TimezoneConfig = serde_json::from_str(
+            r#"{"name": "UTC", "timezone": "UTC", "work_hours": {"start": "09:00", "end": "17:00"}}"#,
+        )
+        .unwrap();
+        assert!(tz.enabled);
+    }
+
+    #[test]
+    fn test_set_all_work_hours() {
+        let mut config = Config::default();
+        let team = WorkHours::parse_range("10:00-18:00").unwrap();
+        assert_eq!(config.set_all_work_hours(&team, None), 3);
+        assert!(
+            config
+                .timezones
+                .iter()
+                .all(|tz| tz.work_hours.start == "10:00" && tz.work_hours.end == "18:00")
+        );
+
+        // Only New York still works the default 09:00-17:00
+        let mut config = Config::default();
+        let default_hours = WorkHours::parse_range("9:00 - 17:00").unwrap();
+        assert_eq!(config.count_work_hours_targets(None), 3);
+        assert_eq!(config.count_work_hours_targets(Some(&default_hours)), 1);
+        assert_eq!(config, Config::default());
+        assert_eq!(config.set_all_work_hours(&team, Some(&default_hours)), 1);
+        assert_eq!(config.timezones[0].work_hours.start, "09:00");
+        assert_eq!(config.timezones[2].work_hours.start, "10:00");
+
+        assert!(WorkHours::parse_range("10am-6pm").is_err());
+        assert!(WorkHours::parse_range("10:00").is_err());
+    }
+
+    #[test]
+    fn test_shift_work_hours() {
+        let zone = |start: &str, end: &str| TimezoneConfig {
+            work_hours: WorkHours {
+                start: start.to_string(),
+                end: end.to_string(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let hours = |config: &Config| {
+            config
+                .timezones
+                .iter()
+                .map(|tz| (tz.work_hours.start.clone(), tz.work_hours.end.clone()))
+                .collect::<Vec<_>>()
+        };
+        let pair = |start: &str, end: &str| (start.to_string(), end.to_string());
+
+        let mut config = Config {
+            timezones: vec![zone("09:00", "17:00"), zone("9:30", "18:00")],
+            ..Default::default()
+        };
+        config.shift_work_hours(60).unwrap();
+        assert_eq!(
+            hours(&config),
+            [pair("10:00", "18:00"), pair("10:30", "19:00")]
+        );
+
+        // Back by ten hours wraps to the previous evening
+        let mut config = Config {
+            timezones: vec![zone("09:00", "17:00"), zone("9:30", "18:00")],
+            ..Default::default()
+        };
+        config.shift_work_hours(-600).unwrap();
+        assert_eq!(
+            hours(&config),
+            [pair("23:00", "07:00"), pair("23:30", "08:00")]
+        );
+
+        // Whole days drop out, even from shifts too large for a Duration
+        let mut week_later = config.clone();
+        week_later.shift_work_hours(7 * 24 * 60).unwrap();
+        assert_eq!(hours(&week_later), hours(&config));
+        let mut huge = config.clone();
+        huge.shift_work_hours(i64::MAX).unwrap();
+        let mut equivalent = config.clone();
+        equivalent.shift_work_hours(i64::MAX % (24 * 60)).unwrap();
+        assert_eq!(hours(&huge), hours(&equivalent));
+        // i64::MIN is 352 minutes past a whole number of days
+        let mut huge = config.clone();
+        huge.shift_work_hours(i64::MIN).unwrap();
+        assert_eq!(
+            hours(&huge),
+            [pair("04:52", "12:52"), pair("05:22", "13:52")]
+        );
+
+        // A bad entry leaves every zone untouched
+        config.timezones.push(zone("9am", "17:00"));
+        let before = hours(&config);
+        assert!(config.shift_work_hours(30).is_err());
+        assert_eq!(hours(&config), before);
+    }
+
+    #[test]
+    fn test_display_title() {
+        let mut config = Config::default();
+        assert_eq!(config.display_title(), "LongTime");
+
+        config.title = Some("Acme Clocks".to_string());
+        assert_eq!(config.display_title(), "Acme Clocks");
+
+        config.title = Some("  ".to_string());
+        assert_eq!(config.display_title(), "LongTime");
+    }
+
+    #[test]
+    fn test_validate() {
+        assert_eq!(Config::default().validate(), Ok(()));
+
+        let mut config = Config::default();
+        config.timezones[0].work_hours.start = "9am".to_string();
+        config.timezones[1].timezone = "Europe/Londn".to_string();
+        // An overridden offset does not need a known identifier
+        config.timezones.push(TimezoneConfig {
+            name: "Ship".to_string(),
+            timezone: "Etc/Ship".to_string(),
+            offset_override: Some(-3 * 3600),
+            ..Default::default()
+        });
+        config.home_timezone = Some("Mars/Base".to_string());
+        config.title_timezone = Some("Moon/Base".to_string());
+        config.saved_offsets = vec![
+            SavedOffset {
+                name: "India EOD".to_string(),
+                offset: "+8h".to_string(),
+            },
+            SavedOffset {
+                name: "Lunch".to_string(),
+                offset: "noon".to_string(),
+            },
+        ];
+        assert!(!config.timezones[0].work_hours.is_valid());
+        assert_eq!(
+            config.validate(),
+            Err(vec![
+                "Shanghai: work hours \"9am\"-\"18:00\" are not in HH:MM format".to_string(),
+                "London: unknown timezone \"Europe/Londn\"".to_string(),
+                "home timezone \"Mars/Base\" is invalid; diffs use the selected zone instead"
+                    .to_string(),
+                "title_timezone: unknown timezone \"Moon/Base\"".to_string(),
+                "saved offset \"Lunch\": Relative times start with +, - or \"in\", got \"noon\""
+                    .to_string(),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_home_timezone_warning() {
+        let mut config = Config::default();
+        assert_eq!(config.home_timezone_warning(), None);
+        config.home_timezone = Some("Europe/London".to_string());
+        assert_eq!(config.home_timezone_warning(), None);
+
+        config.home_timezone = Some("Europe/Londn".to_string());
+        assert_eq!(
+            config.home_timezone_warning().as_deref(),
+            Some("home timezone \"Europe/Londn\" is invalid; diffs use the selected zone instead")
+        );
+        assert_eq!(
+            config.validate(),
+            Err(vec![config.home_timezone_warning().unwrap()])
+        );
+    }
+
+    #[test]
+    fn test_with_zones() {
+        let config = Config {
+            use_12h_format: true,
+            ..Config::default()
+        };
+        let subset = config.with_zones(&[2, 0, 7]);
+        let names: Vec<_> = subset.timezones.iter().map(|tz| tz.name.as_str()).collect();
+        assert_eq!(names, ["Shanghai", "New York"]);
+        assert!(subset.use_12h_format);
+        assert!(config.with_zones(&[]).timezones.is_empty());
+    }
+
+    #[test]
+    fn test_quick_jumps() {
+        let mut config = Config::default();
+        assert_eq!(config.quick_jumps().len(), 3);
+
+        config.quick_jump_times = vec!["08:30".to_string(), "noon".to_string()];
+        assert_eq!(
+            config.quick_jumps(),
+            vec![NaiveTime::from_hms_opt(8, 30, 0).unwrap()]
+        );
+    }
+
+    #[test]
+    fn test_work_days_and_holidays_deserialize() {
+        let tz: TimezoneConfig = serde_json::from_str(
+            r#"{
+                "name": "UTC",
+                "timezone": "UTC",
+                "work_hours": {"start": "09:00", "end": "17:00"},
+                "work_days": ["Mon", "Tue", "Wed", "Thu", "Fri"],
+                "holidays": ["2024-12-25"]
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(tz.work_days, &ALL_WEEKDAYS[..5]);
+        assert_eq!(
+            tz.holidays,
+            [NaiveDate::from_ymd_opt(2024, 12, 25).unwrap()]
+        );
+        assert_eq!(TimezoneConfig::default().work_days, ALL_WEEKDAYS);
+    }
+
     #[test]
     fn test_config_serialization_roundtrip() {
         let config = Config::default();
@@ -139,4 +1071,129 @@ mod tests {
         let deserialized: Config = serde_json::from_str(&json).unwrap();
         assert_eq!(config, deserialized);
     }
+
+    /// Count the top-level keys of a JSON object
+    fn key_count(json: &str) -> usize {
+        serde_json::from_str::<serde_json::Map<String, serde_json::Value>>(json)
+            .unwrap()
+            .len()
+    }
+
+    /// Assert that `keys` appear in `json` in the given order
+    fn assert_key_order(json: &str, keys: &[&str]) {
+        let positions: Vec<_> = keys
+            .iter()
+            .map(|key| {
+                json.find(&format!("\"{key}\":"))
+                    .unwrap_or_else(|| panic!("{key} missing from {json}"))
+            })
+            .collect();
+        assert!(positions.is_sorted(), "{keys:?} out of order in {json}");
+    }
+
+    #[test]
+    fn test_serialized_key_order() {
+        // Every option away from its default, so every key is written
+        let config = Config {
+            use_12h_format: true,
+            compact_12h: true,
+            date_style: DateStyle::Us,
+            ending_soon_minutes: 15,
+            clock_check_minutes: 0,
+            home_timezone: Some("Europe/London".to_string()),
+            diff_baseline: Some(DiffBaseline::Home),
+            title_timezone: Some("UTC".to_string()),
+            quick_jump_times: vec!["08:00".to_string()],
+            saved_offsets: vec![SavedOffset {
+                name: "India EOD".to_string(),
+                offset: "+8h".to_string(),
+            }],
+            round_minutes: Some(15),
+            show_diff: false,
+            show_day_offset: true,
+            diff_style: DiffStyle::Words,
+            title: Some("Acme".to_string()),
+            grid_columns: Some(3),
+            timezones: Vec::new(),
+        };
+        let json = serde_json::to_string(&config).unwrap();
+        assert_key_order(&json, CONFIG_KEYS);
+        assert_eq!(key_count(&json), CONFIG_KEYS.len());
+
+        let zone = TimezoneConfig {
+            use_12h_format: Some(true),
+            enabled: false,
+            work_days: vec![Weekday::Mon],
+            holidays: vec![NaiveDate::from_ymd_opt(2024, 12, 25).unwrap()],
+            always_working: true,
+            grace_before_minutes: 10,
+            grace_after_minutes: 20,
+            remind_before_minutes: 30,
+            offset_override: Some(3600),
+            ..TimezoneConfig::default()
+        };
+        let json = serde_json::to_string(&zone).unwrap();
+        assert_key_order(&json, TIMEZONE_KEYS);
+        assert_eq!(key_count(&json), TIMEZONE_KEYS.len());
+
+        let hours = WorkHours {
+            end_of_minute: true,
+            ..WorkHours::default()
+        };
+        let json = serde_json::to_string(&hours).unwrap();
+        assert_key_order(&json, WORK_HOURS_KEYS);
+        assert_eq!(key_count(&json), WORK_HOURS_KEYS.len());
+    }
+
+    #[test]
+    fn test_unset_options_omitted() {
+        let json = serde_json::to_string(&Config::default()).unwrap();
+        assert!(!json.contains("null"), "{json}");
+        for key in [
+            "home_timezone",
+            "title",
+            "grid_columns",
+            "offset_override",
+            // Default scalars and empty lists
+            "compact_12h",
+            "date_style",
+            "ending_soon_minutes",
+            "clock_check_minutes",
+            "quick_jump_times",
+            "saved_offsets",
+            "show_diff",
+            "show_day_offset",
+            "diff_style",
+            "enabled",
+            "work_days",
+            "holidays",
+            "always_working",
+            "grace_before_minutes",
+            "grace_after_minutes",
+            "remind_before_minutes",
+            "end_of_minute",
+        ] {
+            assert!(!json.contains(&format!("\"{key}\"")), "{key} in {json}");
+        }
+        // Still read back as unset
+        let restored: Config = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, Config::default());
+
+        // Non-false defaults are only left out while they keep their default
+        let config = Config {
+            show_diff: false,
+            ending_soon_minutes: 0,
+            timezones: vec![TimezoneConfig {
+                enabled: false,
+                ..TimezoneConfig::default()
+            }],
+            ..Config::default()
+        };
+        let json = serde_json::to_string(&config).unwrap();
+        for key in ["show_diff", "ending_soon_minutes", "enabled"] {
+            assert!(json.contains(&format!("\"{key}\"")), "{key} not in {json}");
+        }
+        let restored: Config = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, config);
+    }
 }
@@ -0,0 +1,184 @@
+//! Daylight saving time transitions
+//!
+//! `chrono_tz` does not expose its transition table, so transitions are
+//! found by sampling the zone's offset and bisecting where it changes.
+//! Searches are cached per zone for a day, since UIs ask on every tick.
+
+use std::{cell::RefCell, collections::HashMap, str::FromStr};
+
+use chrono::{DateTime, Duration, Offset, TimeZone, Utc};
+use chrono_tz::Tz;
+
+/// How far ahead `next_dst_change` looks for a transition, in days
+pub const DST_LOOKAHEAD_DAYS: i64 = 400;
+
+/// How close a transition must be for `dst_notice` to mention it, in days
+pub const DST_NOTICE_DAYS: i64 = 30;
+
+/// A transition search: the instant it started from and its result
+type DstSearch = (DateTime<Utc>, Option<(DateTime<Utc>, i32)>);
+
+thread_local! {
+    /// The latest search per timezone identifier
+    static DST_CACHE: RefCell<HashMap<String, DstSearch>> = RefCell::new(HashMap::new());
+}
+
+/// Find the next offset change of a timezone
+///
+/// The offset is sampled once a day and bisected where it differs, so two
+/// changes less than a day apart that cancel out are not found. A search is
+/// reused for later instants before the change it found, and for up to a
+/// day when it found none.
+///
+/// # Arguments
+///
+/// * `tz_str` - IANA timezone identifier
+/// * `now` - The instant to search from
+///
+/// # Returns
+///
+/// * `Option<(DateTime<Utc>, i32)>` - The first instant on the new offset and
+///   the new offset in seconds, or None if the zone is invalid or does not
+///   change within `DST_LOOKAHEAD_DAYS`
+pub fn next_dst_change(tz_str: &str, now: DateTime<Utc>) -> Option<(DateTime<Utc>, i32)> {
+    let cached = DST_CACHE.with_borrow(|cache| {
+        let (from, found) = cache.get(tz_str)?;
+        let valid_until = match found {
+            Some((at, _)) => *at,
+            None => *from + Duration::days(1),
+        };
+        (*from <= now && now < valid_until).then_some(*found)
+    });
+    if let Some(found) = cached {
+        return found;
+    }
+    let found = search_dst_change(tz_str, now);
+    DST_CACHE.with_borrow_mut(|cache| cache.insert(tz_str.to_string(), (now, found)));
+    found
+}
+
+/// Search for the next offset change of a timezone without the cache
+fn search_dst_change(tz_str: &str, now: DateTime<Utc>) -> Option<(DateTime<Utc>, i32)> {
+    let tz = Tz::from_str(tz_str).ok()?;
+    // Offsets by whole UTC seconds; transitions always fall on one
+    let offset_at = |timestamp: i64| {
+        DateTime::from_timestamp(timestamp, 0)
+            .map(|instant| tz.offset_from_utc_datetime(&instant.naive_utc()).fix())
+    };
+    let start = now.timestamp();
+    let current = offset_at(start)?;
+
+    let day = Duration::days(1).num_seconds();
+    let mut before = start;
+    for _ in 0..DST_LOOKAHEAD_DAYS {
+        let mut after = before + day;
+        if offset_at(after)? == current {
+            before = after;
+            continue;
+        }
+
+        // Invariant: `before` is on the current offset, `after` is not
+        while after - before > 1 {
+            let middle = before + (after - before) / 2;
+            if offset_at(middle)? == current {
+                before = middle;
+            } else {
+                after = middle;
+            }
+        }
+        let new_offset = offset_at(after)?.local_minus_utc();
+        return DateTime::from_timestamp(after, 0).map(|at| (at, new_offset));
+    }
+    None
+}
+
+/// Describe an upcoming clock change, e.g. "Clocks change Mar 10 (+1h)"
+///
+/// # Arguments
+///
+/// * `tz_str` - IANA timezone identifier
+/// * `now` - The current instant
+///
+/// # Returns
+///
+/// * `Option<String>` - The notice, or None if the zone has no transition
+///   within `DST_NOTICE_DAYS`
+pub fn dst_notice(tz_str: &str, now: DateTime<Utc>) -> Option<String> {
+    let tz = Tz::from_str(tz_str).ok()?;
+    let (at, new_offset) = next_dst_change(tz_str, now)?;
+    if at - now > Duration::days(DST_NOTICE_DAYS) {
+        return None;
+    }
+
+    let old_offset = tz
+        .offset_from_utc_datetime(&now.naive_utc())
+        .fix()
+        .local_minus_utc();
+    let shift = new_offset - old_offset;
+    let shift = if shift % 3600 == 0 {
+        format!("{:+}h", shift / 3600)
+    } else {
+        format!("{:+}m", shift / 60)
+    };
+    Some(format!(
+        "Clocks change {} ({shift})",
+        at.with_timezone(&tz).format("%b %-d")
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_next_dst_change() {
+        let now = Utc.with_ymd_and_hms(2024, 3, 1, 12, 0, 0).unwrap();
+
+        // New York springs forward at 02:00 EST on March 10, 2024
+        assert_eq!(
+            next_dst_change("America/New_York", now),
+            Some((
+                Utc.with_ymd_and_hms(2024, 3, 10, 7, 0, 0).unwrap(),
+                -4 * 3600
+            ))
+        );
+
+        assert_eq!(next_dst_change("Asia/Tokyo", now), None);
+        assert_eq!(next_dst_change("Invalid/Zone", now), None);
+    }
+
+    #[test]
+    fn test_next_dst_change_cache() {
+        let at = |month, day, hour| Utc.with_ymd_and_hms(2024, month, day, hour, 0, 0).unwrap();
+        let spring = Some((at(3, 10, 7), -4 * 3600));
+        let autumn = Some((at(11, 3, 6), -5 * 3600));
+
+        // Reused until the change it found, then searched again
+        assert_eq!(next_dst_change("America/New_York", at(3, 9, 12)), spring);
+        assert_eq!(next_dst_change("America/New_York", at(3, 10, 6)), spring);
+        assert_eq!(next_dst_change("America/New_York", at(3, 10, 7)), autumn);
+        // Jumping back searches again too
+        assert_eq!(next_dst_change("America/New_York", at(3, 1, 0)), spring);
+        assert_eq!(search_dst_change("America/New_York", at(3, 10, 8)), autumn);
+    }
+
+    #[test]
+    fn test_dst_notice() {
+        let now = Utc.with_ymd_and_hms(2024, 3, 1, 12, 0, 0).unwrap();
+        assert_eq!(
+            dst_notice("America/New_York", now).as_deref(),
+            Some("Clocks change Mar 10 (+1h)")
+        );
+        // London changes on March 31, more than 30 days after Feb 20
+        let feb = Utc.with_ymd_and_hms(2024, 2, 20, 12, 0, 0).unwrap();
+        assert_eq!(dst_notice("Europe/London", feb), None);
+        assert_eq!(dst_notice("Asia/Tokyo", now), None);
+
+        // Lord Howe Island shifts by half an hour
+        let now = Utc.with_ymd_and_hms(2024, 3, 20, 0, 0, 0).unwrap();
+        assert_eq!(
+            dst_notice("Australia/Lord_Howe", now).as_deref(),
+            Some("Clocks change Apr 7 (-30m)")
+        );
+    }
+}
@@ -0,0 +1,263 @@
+//! Signed duration parsing and formatting
+//!
+//! This module converts between user-entered offsets such as "-3:30" or
+//! "90" (minutes) and offsets in seconds, and bounds simulation offsets to
+//! a safe range.
+
+use chrono::{DateTime, Utc};
+
+/// Largest time-travel offset in either direction (about 10 years), in seconds
+pub const MAX_TIME_OFFSET_SECONDS: i64 = 10 * 366 * 24 * 3600;
+
+/// Clamp a time-travel offset to `±MAX_TIME_OFFSET_SECONDS`
+///
+/// Callers should combine offsets with saturating arithmetic before clamping
+/// so that repeated adjustments stay at the boundary instead of overflowing.
+///
+/// # Arguments
+///
+/// * `seconds` - The requested offset in seconds
+///
+/// # Returns
+///
+/// * `i64` - The offset limited to the supported range
+pub fn clamp_time_offset(seconds: i64) -> i64 {
+    seconds.clamp(-MAX_TIME_OFFSET_SECONDS, MAX_TIME_OFFSET_SECONDS)
+}
+
+/// Get the offset in whole seconds that makes a clock reading `base` show
+/// `target`
+///
+/// Rounds up so the simulated clock never reads just before the target,
+/// e.g. one second short of a work start.
+///
+/// # Arguments
+///
+/// * `target` - The instant the simulated clock should read
+/// * `base` - The instant the real clock reads
+///
+/// # Returns
+///
+/// * `i64` - The offset in seconds, not yet clamped
+pub fn offset_to_reach(target: DateTime<Utc>, base: DateTime<Utc>) -> i64 {
+    let millis = (target - base).num_milliseconds();
+    millis.saturating_add(999).div_euclid(1000)
+}
+
+/// Parse a signed offset entered by a user into seconds
+///
+/// Accepts either `[+-]H:MM` or a plain `[+-]minutes` value.
+///
+/// # Arguments
+///
+/// * `input` - The text to parse, surrounding whitespace is ignored
+///
+/// # Returns
+///
+/// * `Result<i64, String>` - The offset in seconds, or a message describing the problem
+///
+/// # Example
+///
+/// ```
+/// use longtime_core::parse_signed_duration;
+///
+/// assert_eq!(parse_signed_duration("-3:30"), Ok(-(3 * 3600 + 30 * 60)));
+/// assert_eq!(parse_signed_duration("90"), Ok(90 * 60));
+/// ```
+pub fn parse_signed_duration(input: &str) -> Result<i64, String> {
+    let input = input.trim();
+    let (sign, body) = match input.strip_prefix('-') {
+        Some(rest) => (-1, rest),
+        None => (1, input.strip_prefix('+').unwrap_or(input)),
+    };
+
+    if body.is_empty() {
+        return Err("Enter an offset like -3:30 or 90".to_string());
+    }
+
+    let minutes = match body.split_once(':') {
+        Some((hours, minutes)) => {
+            let hours: i64 = parse_digits(hours)?;
+            let minutes: i64 = parse_digits(minutes)?;
+            if minutes >= 60 {
+                return Err(format!("Minutes must be below 60, got {minutes}"));
+            }
+            hours
+                .checked_mul(60)
+                .and_then(|m| m.checked_add(minutes))
+                .ok_or_else(|| "Offset is too large".to_string())?
+        }
+        None => parse_digits(body)?,
+    };
+
+    minutes
+        .checked_mul(60 * sign)
+        .ok_or_else(|| "Offset is too large".to_string())
+}
+
+/// Format an offset in seconds as a signed `H:MM` string
+///
+/// Seconds below a full minute are truncated.
+///
+/// # Arguments
+///
+/// * `seconds` - The offset in seconds
+///
+/// # Returns
+///
+/// * `String` - Formatted string like "+3:30", "-0:15", or "0:00"
+pub fn format_signed_duration(seconds: i64) -> String {
+    let sign = match seconds.signum() {
+        1 => "+",
+        -1 => "-",
+        _ => "",
+    };
+    let total_minutes = seconds.unsigned_abs() / 60;
+    format!("{sign}{}:{:02}", total_minutes / 60, total_minutes % 60)
+}
+
+/// Format a duration in seconds as hours and minutes, e.g. "3h 10m"
+///
+/// The sign is ignored and leftover seconds are truncated.
+///
+/// # Arguments
+///
+/// * `seconds` - The duration in seconds
+///
+/// # Returns
+///
+/// * `String` - The formatted duration
+pub fn format_hours_minutes(seconds: i64) -> String {
+    let total_minutes = seconds.unsigned_abs() / 60;
+    format!("{}h {}m", total_minutes / 60, total_minutes % 60)
+}
+
+/// Format a signed offset in seconds as days, hours and minutes
+///
+/// Zero parts are left out and leftover seconds are truncated.
+///
+/// # Arguments
+///
+/// * `seconds` - The offset in seconds
+///
+/// # Returns
+///
+/// * `String` - Formatted string like "+7d", "-1d 2h" or "+1h 30m"
+pub fn format_relative_offset(seconds: i64) -> String {
+    let sign = if seconds < 0 { "-" } else { "+" };
+    let total_minutes = seconds.unsigned_abs() / 60;
+    let parts: Vec<String> = [
+        (total_minutes / (24 * 60), "d"),
+        (total_minutes / 60 % 24, "h"),
+        (total_minutes % 60, "m"),
+    ]
+    .into_iter()
+    .filter(|(value, _)| *value > 0)
+    .map(|(value, unit)| format!("{value}{unit}"))
+    .collect();
+    if parts.is_empty() {
+        return "+0m".to_string();
+    }
+    format!("{sign}{}", parts.join(" "))
+}
+
+/// Parse a non-empty run of ASCII digits
+fn parse_digits(digits: &str) -> Result<i64, String> {
+    if digits.is_empty() || !digits.chars().all(|c| c.is_ascii_digit()) {
+        return Err(format!("Invalid offset: \"{digits}\""));
+    }
+    digits
+        .parse()
+        .map_err(|_| "Offset is too large".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Duration, TimeZone};
+
+    use super::*;
+
+    #[test]
+    fn test_offset_to_reach() {
+        let now = Utc.with_ymd_and_hms(2024, 7, 1, 12, 0, 0).unwrap();
+        let target = Utc.with_ymd_and_hms(2024, 7, 10, 15, 0, 0).unwrap();
+        assert_eq!(offset_to_reach(target, now), (9 * 24 + 3) * 3600);
+        assert_eq!(offset_to_reach(now, target), -(9 * 24 + 3) * 3600);
+        // Partial seconds round up so the target is never missed
+        assert_eq!(
+            offset_to_reach(target, target - Duration::milliseconds(1500)),
+            2
+        );
+        assert_eq!(
+            offset_to_reach(target, target + Duration::milliseconds(1500)),
+            -1
+        );
+    }
+
+    #[test]
+    fn test_format_relative_offset() {
+        assert_eq!(format_relative_offset(7 * 86400), "+7d");
+        assert_eq!(format_relative_offset(-(86400 + 2 * 3600)), "-1d 2h");
+        assert_eq!(format_relative_offset(90 * 60 + 59), "+1h 30m");
+        assert_eq!(format_relative_offset(30), "+0m");
+    }
+
+    #[test]
+    fn test_format_hours_minutes() {
+        assert_eq!(format_hours_minutes(0), "0h 0m");
+        assert_eq!(format_hours_minutes(3 * 3600 + 10 * 60 + 59), "3h 10m");
+        assert_eq!(format_hours_minutes(-90 * 60), "1h 30m");
+    }
+
+    #[test]
+    fn test_parse_signed_duration_hours_minutes() {
+        assert_eq!(parse_signed_duration("3:30"), Ok(12600));
+        assert_eq!(parse_signed_duration("+3:30"), Ok(12600));
+        assert_eq!(parse_signed_duration("-3:30"), Ok(-12600));
+        assert_eq!(parse_signed_duration(" -0:15 "), Ok(-900));
+    }
+
+    #[test]
+    fn test_parse_signed_duration_minutes() {
+        assert_eq!(parse_signed_duration("90"), Ok(5400));
+        assert_eq!(parse_signed_duration("-210"), Ok(-12600));
+        assert_eq!(parse_signed_duration("0"), Ok(0));
+    }
+
+    #[test]
+    fn test_parse_signed_duration_invalid() {
+        assert!(parse_signed_duration("").is_err());
+        assert!(parse_signed_duration("-").is_err());
+        assert!(parse_signed_duration("abc").is_err());
+        assert!(parse_signed_duration("3:75").is_err());
+        assert!(parse_signed_duration("3:").is_err());
+        assert!(parse_signed_duration("1:-5").is_err());
+        assert!(parse_signed_duration("99999999999999999999").is_err());
+    }
+
+    #[test]
+    fn test_format_signed_duration() {
+        assert_eq!(format_signed_duration(0), "0:00");
+        assert_eq!(format_signed_duration(12600), "+3:30");
+        assert_eq!(format_signed_duration(-900), "-0:15");
+        assert_eq!(format_signed_duration(-12630), "-3:30");
+    }
+
+    #[test]
+    fn test_clamp_time_offset() {
+        assert_eq!(clamp_time_offset(3600), 3600);
+        assert_eq!(
+            clamp_time_offset(MAX_TIME_OFFSET_SECONDS + 1),
+            MAX_TIME_OFFSET_SECONDS
+        );
+        assert_eq!(clamp_time_offset(i64::MIN), -MAX_TIME_OFFSET_SECONDS);
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        for seconds in [-12600, -900, 0, 60, 5400, 86400 * 3] {
+            let text = format_signed_duration(seconds);
+            assert_eq!(parse_signed_duration(&text), Ok(seconds));
+        }
+    }
+}
@@ -0,0 +1,263 @@
+//! Human time expressions
+//!
+//! This module turns short expressions such as "+2h", "in 90m", "14:00" or
+//! "tomorrow 9am" into the time-travel offset that realizes them.
+
+use std::str::FromStr;
+
+use chrono::{DateTime, Days, NaiveTime, Utc};
+use chrono_tz::Tz;
+
+use crate::{
+    config::TimezoneConfig,
+    time::{zone_local_time, zone_to_utc},
+};
+
+/// Parse a human time expression into an offset relative to `now`
+///
+/// Supported forms:
+///
+/// - Relative: `+2h`, `-30m`, `+1h30m`, `in 90m`, `+7d`
+/// - Absolute today in the reference zone: `14:00`, `9am`, `5:30pm`
+/// - Tomorrow in the reference zone: `tomorrow 09:00`, `tomorrow 9am`
+///
+/// # Arguments
+///
+/// * `expr` - The expression, case-insensitive, surrounding whitespace ignored
+/// * `now` - The instant the expression is relative to
+/// * `reference` - The zone that absolute times are read in, honouring its
+///   `offset_override`
+///
+/// # Returns
+///
+/// * `Result<i64, String>` - Seconds to add to `now`, or a message describing the problem
+///
+/// # Example
+///
+/// ```
+/// use chrono::{TimeZone, Utc};
+/// use longtime_core::{TimezoneConfig, parse_time_expression};
+///
+/// let utc = TimezoneConfig {
+///     timezone: "UTC".to_string(),
+///     ..Default::default()
+/// };
+/// let now = Utc.with_ymd_and_hms(2024, 1, 1, 10, 0, 0).unwrap();
+/// assert_eq!(parse_time_expression("+2h", now, &utc), Ok(7200));
+/// assert_eq!(parse_time_expression("14:00", now, &utc), Ok(4 * 3600));
+/// ```
+pub fn parse_time_expression(
+    expr: &str,
+    now: DateTime<Utc>,
+    reference: &TimezoneConfig,
+) -> Result<i64, String> {
+    let expr = expr.trim().to_lowercase();
+    if expr.is_empty() {
+        return Err("Empty time expression".to_string());
+    }
+
+    if expr.starts_with("in ") || expr.starts_with(['+', '-']) {
+        return parse_relative_offset(&expr);
+    }
+
+    if reference.offset_override.is_none() && Tz::from_str(&reference.timezone).is_err() {
+        return Err(format!("Invalid timezone: \"{}\"", reference.timezone));
+    }
+    let (days, time) = match expr.strip_prefix("tomorrow") {
+        Some(rest) => (1, parse_clock_time(rest.trim())?),
+        None => (0, parse_clock_time(&expr)?),
+    };
+
+    let date = zone_local_time(now, reference)
+        .and_then(|local| local.date_naive().checked_add_days(Days::new(days)))
+        .ok_or_else(|| format!("Date out of range: \"{expr}\""))?;
+    let target = zone_to_utc(reference, date.and_time(time), false)
+        .ok_or_else(|| format!("Local time does not exist: \"{expr}\""))?;
+    Ok((target - now).num_seconds())
+}
+
+/// Parse a relative time expression such as "+8h", "-30m" or "in 90m"
+///
+/// # Arguments
+///
+/// * `expr` - The expression, case-insensitive, surrounding whitespace ignored
+///
+/// # Returns
+///
+/// * `Result<i64, String>` - The offset in seconds, or a message describing the problem
+pub fn parse_relative_offset(expr: &str) -> Result<i64, String> {
+    let expr = expr.trim().to_lowercase();
+    if let Some(rest) = expr.strip_prefix("in ") {
+        return parse_relative(rest.trim());
+    }
+    if let Some(rest) = expr.strip_prefix('+') {
+        return parse_relative(rest);
+    }
+    if let Some(rest) = expr.strip_prefix('-') {
+        return parse_relative(rest).map(|seconds| -seconds);
+    }
+    Err(format!(
+        "Relative times start with +, - or \"in\", got \"{expr}\""
+    ))
+}
+
+/// Parse an unsigned duration made of day, hour and minute parts, e.g. "1h30m"
+fn parse_relative(input: &str) -> Result<i64, String> {
+    let invalid = || format!("Invalid relative time: \"{input}\"");
+    let mut total: i64 = 0;
+    let mut digits = String::new();
+    for c in input.chars().filter(|c| !c.is_whitespace()) {
+        if c.is_ascii_digit() {
+            digits.push(c);
+            continue;
+        }
+        let unit = match c {
+            'd' => 86400,
+            'h' => 3600,
+            'm' => 60,
+            _ => return Err(invalid()),
+        };
+        let value: i64 = digits.parse().map_err(|_| invalid())?;
+        total = value
+            .checked_mul(unit)
+            .and_then(|seconds| total.checked_add(seconds))
+            .ok_or_else(invalid)?;
+        digits.clear();
+    }
+
+    if input.is_empty() || !digits.is_empty() {
+        return Err(invalid());
+    }
+    Ok(total)
+}
+
+/// Parse a wall-clock time such as "14:00", "9am" or "5:30pm"
+fn parse_clock_time(input: &str) -> Result<NaiveTime, String> {
+    let invalid = || format!("Invalid time: \"{input}\"");
+    let (clock, pm) = if let Some(clock) = input.strip_suffix("am") {
+        (clock.trim(), Some(false))
+    } else if let Some(clock) = input.strip_suffix("pm") {
+        (clock.trim(), Some(true))
+    } else {
+        (input, None)
+    };
+
+    let (hour, minute) = match clock.split_once(':') {
+        Some((hour, minute)) => (hour, minute),
+        None if pm.is_some() => (clock, "0"),
+        None => return Err(invalid()),
+    };
+    let mut hour: u32 = hour.parse().map_err(|_| invalid())?;
+    let minute: u32 = minute.parse().map_err(|_| invalid())?;
+    if let Some(pm) = pm {
+        if !(1..=12).contains(&hour) {
+            return Err(invalid());
+        }
+        hour = hour % 12 + if pm { 12 } else { 0 };
+    }
+    NaiveTime::from_hms_opt(hour, minute, 0).ok_or_else(invalid)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+
+    fn now() -> DateTime<Utc> {
+        // 10:00 UTC, 19:00 in Tokyo
+        Utc.with_ymd_and_hms(2024, 1, 1, 10, 0, 0).unwrap()
+    }
+
+    fn zone(tz: &str) -> TimezoneConfig {
+        TimezoneConfig {
+            timezone: tz.to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_relative_expressions() {
+        assert_eq!(
+            parse_time_expression("+2h", now(), &zone("UTC")),
+            Ok(2 * 3600)
+        );
+        assert_eq!(
+            parse_time_expression("-30m", now(), &zone("UTC")),
+            Ok(-30 * 60)
+        );
+        assert_eq!(
+            parse_time_expression("+1h30m", now(), &zone("UTC")),
+            Ok(90 * 60)
+        );
+        assert_eq!(
+            parse_time_expression("+7d", now(), &zone("UTC")),
+            Ok(7 * 86400)
+        );
+        assert_eq!(
+            parse_time_expression("in 90m", now(), &zone("UTC")),
+            Ok(90 * 60)
+        );
+    }
+
+    #[test]
+    fn test_relative_offset() {
+        assert_eq!(parse_relative_offset(" +8H "), Ok(8 * 3600));
+        assert_eq!(parse_relative_offset("-1d 2h"), Ok(-26 * 3600));
+        assert_eq!(parse_relative_offset("in 90m"), Ok(90 * 60));
+        assert!(parse_relative_offset("14:00").is_err());
+        assert!(parse_relative_offset("").is_err());
+    }
+
+    #[test]
+    fn test_absolute_today() {
+        assert_eq!(
+            parse_time_expression("14:00", now(), &zone("UTC")),
+            Ok(4 * 3600)
+        );
+        assert_eq!(parse_time_expression("9am", now(), &zone("UTC")), Ok(-3600));
+        // 21:30 in Tokyo is 2.5 hours after 19:00 there
+        assert_eq!(
+            parse_time_expression("9:30pm", now(), &zone("Asia/Tokyo")),
+            Ok(150 * 60)
+        );
+    }
+
+    #[test]
+    fn test_tomorrow() {
+        assert_eq!(
+            parse_time_expression("tomorrow 09:00", now(), &zone("UTC")),
+            Ok(23 * 3600)
+        );
+        // Tomorrow 9am in Tokyo is 14 hours after 19:00 today there
+        assert_eq!(
+            parse_time_expression("Tomorrow 9am", now(), &zone("Asia/Tokyo")),
+            Ok(14 * 3600)
+        );
+    }
+
+    #[test]
+    fn test_invalid_expressions() {
+        for expr in ["", "soon", "+", "+2x", "25:00", "13pm", "tomorrow", "in"] {
+            assert!(
+                parse_time_expression(expr, now(), &zone("UTC")).is_err(),
+                "{expr:?} should not parse"
+            );
+        }
+        assert!(parse_time_expression("14:00", now(), &zone("Invalid/Zone")).is_err());
+    }
+
+    #[test]
+    fn test_offset_override_reference() {
+        // A clock fixed at UTC-5 reads 05:00, whatever the identifier says
+        let fixed = TimezoneConfig {
+            offset_override: Some(-5 * 3600),
+            ..zone("Invalid/Zone")
+        };
+        assert_eq!(parse_time_expression("9am", now(), &fixed), Ok(4 * 3600));
+        assert_eq!(
+            parse_time_expression("tomorrow 05:00", now(), &fixed),
+            Ok(24 * 3600)
+        );
+    }
+}
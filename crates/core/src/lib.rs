@@ -3,17 +3,64 @@
 //! This crate contains the core business logic for the LongTime multi-timezone
 //! time management tool. It provides:
 //!
+//! - Time sources (`Clock`, `SystemClock`, `FixedClock`)
 //! - Configuration data structures (`Config`, `TimezoneConfig`, `WorkHours`)
-//! - Time calculation utilities (`is_work_hours`, `get_time_display_info`)
+//! - Timezone presets (`PRESETS`, `Preset`)
+//! - Timezone alias canonicalization (`canonicalize_timezone`, `same_timezone`)
+//! - Timezone regions (`timezone_regions`, `timezones_in_region`)
+//! - Time calculation utilities (`is_work_hours`, `is_within_window`, `work_statuses`,
+//!   `get_time_display_info`, `offset_difference`, `earliest_zone`,
+//!   `latest_zone`, `zone_offset`, `zone_local_time`, `group_by_status`,
+//!   `local_to_utc`, `workday_progress`, `reference_offset`, `system_offset`,
+//!   `distinct_offsets`)
+//! - Planning helpers (`offset_matrix`, `week_availability`,
+//!   `pairwise_overlaps`, `format_overlap_report`, `score_meeting_hours`)
+//! - Formatting helpers (`format_time_diff`, `format_diff`, `format_invite_text`)
+//! - Duration parsing and formatting (`parse_signed_duration`,
+//!   `format_signed_duration`, `format_hours_minutes`)
+//! - Human time expressions (`parse_time_expression`, `parse_relative_offset`)
+//! - Daylight saving transitions (`next_dst_change`, `dst_notice`)
 //!
 //! This crate is designed to be shared between the TUI and Web interfaces,
 //! and is compatible with WASM targets.
 
+pub mod aliases;
+pub mod clock;
 pub mod config;
+pub mod dst;
+pub mod duration;
+pub mod expression;
+pub mod presets;
+pub mod regions;
 pub mod time;
 
-pub use config::{Config, TimezoneConfig, WorkHours};
+pub use aliases::{TIMEZONE_ALIASES, canonicalize_timezone, same_timezone};
+pub use clock::{Clock, FixedClock, SystemClock};
+pub use config::{
+    ALL_WEEKDAYS, CONFIG_KEYS, Config, DEFAULT_TITLE, DateStyle, DiffBaseline, DiffStyle,
+    MAX_GRID_COLUMNS, SavedOffset, TIMEZONE_KEYS, TimezoneConfig, WORK_HOURS_KEYS, WorkHours,
+    is_valid_timezone,
+};
+pub use dst::{DST_LOOKAHEAD_DAYS, DST_NOTICE_DAYS, dst_notice, next_dst_change};
+pub use duration::{
+    MAX_TIME_OFFSET_SECONDS, clamp_time_offset, format_hours_minutes, format_relative_offset,
+    format_signed_duration, offset_to_reach, parse_signed_duration,
+};
+pub use expression::{parse_relative_offset, parse_time_expression};
+pub use presets::{PRESETS, Preset};
+pub use regions::{timezone_region, timezone_regions, timezones_in_region};
 pub use time::{
-    TimeDisplayInfo, calculate_time_difference, format_time_diff, get_time_display_info,
-    get_timezone_offset, is_work_hours,
+    DayBoundary, DayPhase, DisplayOptions, OffReason, PLANNING_DAYS, PairOverlap, ReferenceHours,
+    TimeDisplayInfo, WorkProgress, WorkStatus, baseline_label, calculate_time_difference,
+    compare_display_info, configured_zone, day_boundary_offset, day_offset, day_phase,
+    diff_header_label, distinct_offsets, earliest_zone, format_day_offset, format_diff,
+    format_invite_text, format_overlap_report, format_time_diff, get_time_display_info,
+    get_timezone_offset, group_by_status, home_zone, home_zone_label, is_in_grace,
+    is_within_remind_window, is_within_window, is_work_hours, latest_zone, local_date,
+    local_time_instant, local_to_utc, local_zone, next_work_start, next_work_transition,
+    off_reason, offset_difference, offset_matrix, pairwise_overlaps, reference_name,
+    reference_offset, reference_zone, remaining_week_work, same_local_date, score_meeting_hours,
+    sort_by_offset, system_offset, week_availability, work_by_hour, work_hours_in_reference,
+    work_overlap, work_overlap_by_hour, work_progress, work_status, work_statuses,
+    workday_progress, zone_local_time, zone_offset,
 };
@@ -0,0 +1,119 @@
+//! Timezone presets
+//!
+//! This module provides named groups of commonly used timezones that can be
+//! appended to a configuration in one step.
+
+use crate::{
+    aliases::same_timezone,
+    config::{Config, TimezoneConfig, WorkHours},
+};
+
+/// A named group of timezones
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Preset {
+    /// Display name of the group
+    pub name: &'static str,
+    /// Display names and IANA identifiers of the zones in the group
+    pub zones: &'static [(&'static str, &'static str)],
+}
+
+/// Built-in presets
+pub const PRESETS: &[Preset] = &[
+    Preset {
+        name: "US offices",
+        zones: &[
+            ("New York", "America/New_York"),
+            ("Chicago", "America/Chicago"),
+            ("Denver", "America/Denver"),
+            ("Los Angeles", "America/Los_Angeles"),
+        ],
+    },
+    Preset {
+        name: "European offices",
+        zones: &[
+            ("London", "Europe/London"),
+            ("Paris", "Europe/Paris"),
+            ("Berlin", "Europe/Berlin"),
+        ],
+    },
+    Preset {
+        name: "APAC offices",
+        zones: &[
+            ("Singapore", "Asia/Singapore"),
+            ("Shanghai", "Asia/Shanghai"),
+            ("Tokyo", "Asia/Tokyo"),
+            ("Sydney", "Australia/Sydney"),
+        ],
+    },
+];
+
+impl Preset {
+    /// Builds the timezone configurations of this preset with default work hours
+    pub fn timezones(&self) -> Vec<TimezoneConfig> {
+        self.zones
+            .iter()
+            .map(|(name, timezone)| TimezoneConfig {
+                name: name.to_string(),
+                timezone: timezone.to_string(),
+                work_hours: WorkHours::default(),
+                ..Default::default()
+            })
+            .collect()
+    }
+
+    /// Appends this preset's zones to a configuration
+    ///
+    /// Zones whose timezone is already configured, possibly under an alias
+    /// such as "US/Eastern", are skipped.
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - The configuration to extend
+    ///
+    /// # Returns
+    ///
+    /// * `usize` - The number of zones added
+    pub fn apply_to(&self, config: &mut Config) -> usize {
+        let before = config.timezones.len();
+        for tz in self.timezones() {
+            if !config
+                .timezones
+                .iter()
+                .any(|existing| same_timezone(&existing.timezone, &tz.timezone))
+            {
+                config.timezones.push(tz);
+            }
+        }
+        config.timezones.len() - before
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_apply_preset_skips_existing() {
+        let mut config = Config::default();
+        let before = config.timezones.len();
+
+        // The default config already contains New York
+        let added = PRESETS[0].apply_to(&mut config);
+        assert_eq!(added, 3);
+        assert_eq!(config.timezones.len(), before + 3);
+
+        let names: Vec<_> = config.timezones[before..]
+            .iter()
+            .map(|tz| tz.name.as_str())
+            .collect();
+        assert_eq!(names, ["Chicago", "Denver", "Los Angeles"]);
+        assert_eq!(config.timezones[before].work_hours, WorkHours::default());
+
+        assert_eq!(PRESETS[0].apply_to(&mut config), 0);
+
+        // Aliases count as already configured
+        let mut config = Config::default();
+        config.timezones[1].timezone = "GB".to_string();
+        assert_eq!(PRESETS[1].apply_to(&mut config), 2);
+    }
+}
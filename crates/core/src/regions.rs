@@ -0,0 +1,90 @@
+//! Timezone regions
+//!
+//! Groups IANA identifiers by their area prefix ("Europe" in
+//! "Europe/London"), so long zone lists can be narrowed to one continent
+//! or ocean first.
+
+use chrono_tz::TZ_VARIANTS;
+
+use crate::aliases::TIMEZONE_ALIASES;
+
+/// Area prefixes that only hold legacy country links, e.g. "US/Eastern"
+const LEGACY_AREAS: &[&str] = &["Brazil", "Canada", "Chile", "Etc", "Mexico", "US"];
+
+/// Get the region of a timezone identifier
+///
+/// # Arguments
+///
+/// * `tz_str` - IANA timezone identifier, e.g. "America/Argentina/Salta"
+///
+/// # Returns
+///
+/// * `Option<&str>` - The area prefix, e.g. "America", or None for
+///   identifiers without one (e.g. "UTC") or in a legacy area
+pub fn timezone_region(tz_str: &str) -> Option<&str> {
+    let (region, _) = tz_str.split_once('/')?;
+    (!LEGACY_AREAS.contains(&region)).then_some(region)
+}
+
+/// Get the regions of the known timezones, sorted by name
+pub fn timezone_regions() -> Vec<&'static str> {
+    let mut regions: Vec<_> = TZ_VARIANTS
+        .iter()
+        .filter_map(|tz| timezone_region(tz.name()))
+        .collect();
+    regions.sort_unstable();
+    regions.dedup();
+    regions
+}
+
+/// Get the canonical timezone identifiers in a region, sorted by name
+///
+/// Deprecated aliases such as "Asia/Calcutta" are left out.
+///
+/// # Arguments
+///
+/// * `region` - Area prefix, e.g. "Europe"
+pub fn timezones_in_region(region: &str) -> Vec<&'static str> {
+    let mut zones: Vec<_> = TZ_VARIANTS
+        .iter()
+        .map(|tz| tz.name())
+        .filter(|name| timezone_region(name) == Some(region))
+        .filter(|name| TIMEZONE_ALIASES.iter().all(|(alias, _)| alias != name))
+        .collect();
+    zones.sort_unstable();
+    zones
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_timezone_regions() {
+        assert_eq!(
+            timezone_regions(),
+            [
+                "Africa",
+                "America",
+                "Antarctica",
+                "Arctic",
+                "Asia",
+                "Atlantic",
+                "Australia",
+                "Europe",
+                "Indian",
+                "Pacific",
+            ]
+        );
+        assert_eq!(timezone_region("America/Argentina/Salta"), Some("America"));
+        assert_eq!(timezone_region("US/Eastern"), None);
+        assert_eq!(timezone_region("UTC"), None);
+
+        let europe = timezones_in_region("Europe");
+        assert!(europe.contains(&"Europe/London"));
+        assert!(europe.contains(&"Europe/Kyiv"));
+        assert!(!europe.contains(&"Europe/Kiev"));
+        assert!(europe.iter().all(|tz| tz.starts_with("Europe/")));
+        assert!(timezones_in_region("Mars").is_empty());
+    }
+}
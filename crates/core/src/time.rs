@@ -4,12 +4,18 @@
 //! determining work hours status, and generating display information
 //! for timezones.
 
-use std::str::FromStr;
+use std::{fmt::Display, str::FromStr};
 
-use chrono::{DateTime, Offset, Utc};
+use chrono::{
+    DateTime, Datelike, FixedOffset, Local, NaiveDate, NaiveDateTime, NaiveTime, Offset, TimeZone,
+    Timelike, Utc,
+};
 use chrono_tz::Tz;
 
-use crate::config::TimezoneConfig;
+use crate::{
+    config::{Config, DateStyle, DiffBaseline, DiffStyle, TimezoneConfig, WorkHours},
+    duration::format_hours_minutes,
+};
 
 /// Information for displaying a timezone's current time
 #[derive(Debug, Clone, PartialEq)]
@@ -22,10 +28,430 @@ pub struct TimeDisplayInfo {
     pub diff_hours: f64,
     /// Whether currently within work hours
     pub is_working: bool,
+    /// Work status, distinguishing zones about to finish their day
+    pub status: WorkStatus,
+}
+
+/// Work status of a timezone at a given instant
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WorkStatus {
+    /// Within work hours
+    Working,
+    /// Within work hours, but close to the end
+    EndingSoon,
+    /// Just outside work hours, within the zone's grace buffer
+    Grace,
+    /// Outside work hours, and why
+    Off(OffReason),
+    /// The configured work hours could not be parsed
+    InvalidHours,
+}
+
+/// Why a timezone is outside work hours
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OffReason {
+    /// A work day, but outside the work hours
+    AfterHours,
+    /// Not one of the configured work days
+    Weekend,
+    /// One of the configured holidays
+    Holiday,
+}
+
+impl OffReason {
+    /// Short lowercase label, e.g. "weekend"
+    pub fn label(self) -> &'static str {
+        match self {
+            OffReason::AfterHours => "after hours",
+            OffReason::Weekend => "weekend",
+            OffReason::Holiday => "holiday",
+        }
+    }
+}
+
+/// Time already worked and still left in the current work day
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WorkProgress {
+    /// Time since the start of work hours
+    pub elapsed: chrono::Duration,
+    /// Time until the end of work hours
+    pub remaining: chrono::Duration,
+}
+
+impl WorkProgress {
+    /// Short summary such as "3h 10m worked / 4h 50m left"
+    pub fn label(&self) -> String {
+        format!(
+            "{} worked / {} left",
+            format_hours_minutes(self.elapsed.num_seconds()),
+            format_hours_minutes(self.remaining.num_seconds())
+        )
+    }
+
+    /// Fraction of the work day that has elapsed, from 0.0 to 1.0
+    pub fn fraction(&self) -> f64 {
+        let elapsed = self.elapsed.num_seconds();
+        let total = elapsed + self.remaining.num_seconds();
+        if total > 0 {
+            elapsed as f64 / total as f64
+        } else {
+            1.0
+        }
+    }
+}
+
+/// The daily window in which two zones are both within work hours
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PairOverlap {
+    /// Index of the first zone
+    pub first: usize,
+    /// Index of the second zone
+    pub second: usize,
+    /// Shared window as UTC instants, or None if the work hours never meet
+    pub window: Option<(DateTime<Utc>, DateTime<Utc>)>,
+}
+
+/// A zone's work hours read on the reference zone's clock
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReferenceHours {
+    /// Reference-local time at which work starts
+    pub start: NaiveTime,
+    /// Reference-local time at which work ends
+    pub end: NaiveTime,
+    /// Days between the reference date and the start, e.g. 1 for tomorrow
+    pub start_day: i64,
+    /// Days between the reference date and the end
+    pub end_day: i64,
+}
+
+impl ReferenceHours {
+    /// Short range such as "17:00 - 01:00 (+1)"
+    pub fn label(&self) -> String {
+        let endpoint = |time: NaiveTime, day: i64| match day {
+            0 => time.format("%H:%M").to_string(),
+            _ => format!("{} ({day:+})", time.format("%H:%M")),
+        };
+        format!(
+            "{} - {}",
+            endpoint(self.start, self.start_day),
+            endpoint(self.end, self.end_day)
+        )
+    }
+}
+
+/// Options controlling how times and dates are rendered
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct DisplayOptions {
+    /// Whether to use 12-hour time format
+    pub use_12h_format: bool,
+    /// Whether 12-hour times drop the leading zero of the hour
+    pub compact_12h: bool,
+    /// How dates are rendered
+    pub date_style: DateStyle,
+    /// Minutes before the end of work hours that count as "ending soon"
+    /// (0 disables the state)
+    pub ending_soon_minutes: u32,
+    /// Round displayed times to the nearest multiple of this many minutes
+    pub round_minutes: Option<u32>,
+    /// How diffs are worded
+    pub diff_style: DiffStyle,
+}
+
+impl DisplayOptions {
+    /// Builds display options from the global configuration settings
+    pub fn from_config(config: &Config) -> Self {
+        Self {
+            use_12h_format: config.use_12h_format,
+            compact_12h: config.compact_12h,
+            date_style: config.date_style,
+            ending_soon_minutes: config.ending_soon_minutes,
+            round_minutes: config.round_minutes,
+            diff_style: config.diff_style,
+        }
+    }
+
+    /// Rounds a local date-time to the nearest `round_minutes` multiple
+    ///
+    /// Rounding is done on the local clock, so zones with odd offsets (e.g.
+    /// UTC+5:45) still land on round local times. Halfway points round up.
+    pub fn round<Z: TimeZone>(&self, local_time: &DateTime<Z>) -> DateTime<Z> {
+        let step = match self.round_minutes {
+            Some(minutes) if minutes > 0 => i64::from(minutes) * 60,
+            _ => return local_time.clone(),
+        };
+        let seconds = i64::from(local_time.num_seconds_from_midnight());
+        let rounded = (seconds + step / 2) / step * step;
+        // Near the end of the supported range the unrounded time is kept
+        local_time
+            .clone()
+            .checked_add_signed(chrono::Duration::seconds(rounded - seconds))
+            .unwrap_or_else(|| local_time.clone())
+    }
+
+    /// Formats the time of day of a local date-time
+    pub fn format_time<Z: TimeZone>(&self, local_time: &DateTime<Z>) -> String
+    where
+        Z::Offset: Display,
+    {
+        local_time.format(self.time_format()).to_string()
+    }
+
+    /// Formats a diff in hours in the configured `diff_style`
+    pub fn format_diff(&self, diff_hours: f64) -> String {
+        format_diff(diff_hours, self.diff_style)
+    }
+
+    /// Formats a work hours window, e.g. "09:00–17:00"
+    ///
+    /// Windows that end before they start cross midnight and are shown with
+    /// an arrow, e.g. "22:00→06:00".
+    ///
+    /// # Arguments
+    ///
+    /// * `hours` - The work hours to format
+    ///
+    /// # Returns
+    ///
+    /// * `Option<String>` - The formatted window, or None if a time does not parse
+    pub fn format_work_hours(&self, hours: &WorkHours) -> Option<String> {
+        let start = hours.start_time()?;
+        let end = hours.end_time()?;
+        let separator = if end < start { "→" } else { "–" };
+        Some(format!(
+            "{}{separator}{}",
+            start.format(self.time_format()),
+            end.format(self.time_format())
+        ))
+    }
+
+    /// Time of day format string for the 12/24h setting
+    ///
+    /// Hours are two digits so times keep a fixed width, unless
+    /// `compact_12h` drops the leading zero on a 12-hour clock.
+    fn time_format(&self) -> &'static str {
+        match (self.use_12h_format, self.compact_12h) {
+            (true, true) => "%-I:%M %p",
+            (true, false) => "%I:%M %p",
+            (false, _) => "%H:%M",
+        }
+    }
+
+    /// Formats the calendar date of a local date-time
+    pub fn format_date<Z: TimeZone>(&self, local_time: &DateTime<Z>) -> String
+    where
+        Z::Offset: Display,
+    {
+        local_time.format(self.date_style.format_str()).to_string()
+    }
+}
+
+/// Local hour at which the morning phase starts
+pub const MORNING_START_HOUR: u32 = 6;
+/// Local hour at which the working phase starts
+pub const WORKING_START_HOUR: u32 = 9;
+/// Local hour at which the evening phase starts
+pub const EVENING_START_HOUR: u32 = 17;
+/// Local hour at which the late phase starts
+pub const LATE_START_HOUR: u32 = 22;
+
+/// Rough activity phase of a local day, derived from the hour of day
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DayPhase {
+    /// Night time, before `MORNING_START_HOUR`
+    Sleeping,
+    /// Early hours before a typical working day
+    Morning,
+    /// Typical working hours
+    Working,
+    /// After a typical working day
+    Evening,
+    /// Late night, from `LATE_START_HOUR`
+    Late,
+}
+
+impl DayPhase {
+    /// Maps a local hour of day (0-23) to its phase
+    pub fn from_hour(hour: u32) -> Self {
+        match hour {
+            h if h < MORNING_START_HOUR => DayPhase::Sleeping,
+            h if h < WORKING_START_HOUR => DayPhase::Morning,
+            h if h < EVENING_START_HOUR => DayPhase::Working,
+            h if h < LATE_START_HOUR => DayPhase::Evening,
+            _ => DayPhase::Late,
+        }
+    }
+
+    /// Short lowercase label for display
+    pub fn label(self) -> &'static str {
+        match self {
+            DayPhase::Sleeping => "sleeping",
+            DayPhase::Morning => "morning",
+            DayPhase::Working => "working",
+            DayPhase::Evening => "evening",
+            DayPhase::Late => "late",
+        }
+    }
+
+    /// Icon hinting at the phase
+    pub fn icon(self) -> &'static str {
+        match self {
+            DayPhase::Sleeping => "☾",
+            DayPhase::Morning => "☼",
+            DayPhase::Working => "⚙",
+            DayPhase::Evening => "☕",
+            DayPhase::Late => "★",
+        }
+    }
+}
+
+/// Get the day phase for a timezone from its local hour of day
+///
+/// Unlike `is_work_hours`, this ignores the configured work hours and only
+/// looks at the clock.
+///
+/// # Arguments
+///
+/// * `now` - Current UTC time
+/// * `tz_str` - IANA timezone identifier
+///
+/// # Returns
+///
+/// * `Option<DayPhase>` - The phase, or None if timezone is invalid
+pub fn day_phase(now: DateTime<Utc>, tz_str: &str) -> Option<DayPhase> {
+    let tz = Tz::from_str(tz_str).ok()?;
+    Some(DayPhase::from_hour(checked_local(now, &tz)?.hour()))
+}
+
+/// Get the local calendar date of a timezone
+///
+/// # Arguments
+///
+/// * `now` - Current UTC time
+/// * `tz_str` - IANA timezone identifier
+///
+/// # Returns
+///
+/// * `Option<NaiveDate>` - The local date, or None if timezone is invalid
+pub fn local_date(now: DateTime<Utc>, tz_str: &str) -> Option<NaiveDate> {
+    let tz = Tz::from_str(tz_str).ok()?;
+    Some(checked_local(now, &tz)?.date_naive())
+}
+
+/// Check whether two timezones are on the same calendar day
+///
+/// # Arguments
+///
+/// * `now` - Current UTC time
+/// * `tz_a` - First timezone identifier
+/// * `tz_b` - Second timezone identifier
+///
+/// # Returns
+///
+/// * `Option<bool>` - Whether the local dates match, or None if either
+///   identifier is invalid
+pub fn same_local_date(now: DateTime<Utc>, tz_a: &str, tz_b: &str) -> Option<bool> {
+    Some(local_date(now, tz_a)? == local_date(now, tz_b)?)
+}
+
+/// Sort timezones by their current UTC offset, west to east
+///
+/// Zones sharing an offset are ordered by name (case-insensitive), and the
+/// sort is stable, so the result does not depend on the input order beyond
+/// zones with identical names. Zones with an invalid timezone identifier are
+/// moved to the end.
+///
+/// # Arguments
+///
+/// * `now` - Current UTC time
+/// * `zones` - The items to sort
+/// * `config` - Accessor for the timezone configuration of an item
+pub fn sort_by_offset<T>(
+    now: DateTime<Utc>,
+    zones: &mut [T],
+    config: impl Fn(&T) -> &TimezoneConfig,
+) {
+    zones.sort_by_cached_key(|zone| {
+        let config = config(zone);
+        let offset = zone_offset(now, config);
+        (
+            offset.is_none(),
+            offset.unwrap_or(0),
+            config.name.to_lowercase(),
+        )
+    });
+}
+
+/// Move zones within work hours ahead of the others
+///
+/// The partition is stable, so each group keeps its existing order (e.g.
+/// from `sort_by_offset`). UIs that already hold `work_statuses` can
+/// group from those instead.
+///
+/// # Arguments
+///
+/// * `now` - Current UTC time
+/// * `zones` - The items to group
+/// * `config` - Accessor for the timezone configuration of an item
+///
+/// # Returns
+///
+/// * `usize` - The number of working zones, which now lead the slice
+pub fn group_by_status<T>(
+    now: DateTime<Utc>,
+    zones: &mut [T],
+    config: impl Fn(&T) -> &TimezoneConfig,
+) -> usize {
+    zones.sort_by_cached_key(|zone| !is_work_hours(now, config(zone)));
+    zones
+        .iter()
+        .take_while(|zone| is_work_hours(now, config(zone)))
+        .count()
+}
+
+/// Get why a local date has no work hours at all
+///
+/// Holidays take precedence over non-work days.
+fn day_off_reason(config: &TimezoneConfig, date: NaiveDate) -> Option<OffReason> {
+    if config.holidays.contains(&date) {
+        Some(OffReason::Holiday)
+    } else if !config.work_days.contains(&date.weekday()) {
+        Some(OffReason::Weekend)
+    } else {
+        None
+    }
+}
+
+/// Get why a timezone is outside work hours
+///
+/// # Arguments
+///
+/// * `now` - Current UTC time
+/// * `config` - Timezone configuration with work hours, days and holidays
+///
+/// # Returns
+///
+/// * `Option<OffReason>` - The reason, or None while within work hours
+pub fn off_reason(now: DateTime<Utc>, config: &TimezoneConfig) -> Option<OffReason> {
+    if is_work_hours(now, config) {
+        return None;
+    }
+    let reason = zone_local_time(now, config)
+        .and_then(|local_time| day_off_reason(config, local_time.date_naive()));
+    Some(reason.unwrap_or(OffReason::AfterHours))
 }
 
 /// Check if current time falls within work hours for a timezone
 ///
+/// Work hours only apply on configured work days that are not holidays.
+/// Zones marked `always_working` are always within work hours.
+/// The end boundary is inclusive. When `work_hours.end_of_minute` is set,
+/// the whole end minute counts as working (e.g. "17:00" lasts until 17:00:59).
+/// Hours ending at or before they start run overnight, e.g. 22:00-06:00; the
+/// part after midnight follows the work days and holidays of the day the
+/// shift started on.
+/// The zone's grace buffer (`grace_before_minutes`, `grace_after_minutes`)
+/// widens the hours; see `is_in_grace`.
+///
 /// # Arguments
 ///
 /// * `now` - Current UTC time to check
@@ -47,26 +473,244 @@ pub struct TimeDisplayInfo {
 ///     work_hours: WorkHours {
 ///         start: "09:00".to_string(),
 ///         end: "17:00".to_string(),
+///         ..Default::default()
 ///     },
+///     ..Default::default()
 /// };
 ///
 /// let working_time = Utc.with_ymd_and_hms(2023, 1, 1, 12, 0, 0).unwrap();
 /// assert!(is_work_hours(working_time, &config));
 /// ```
 pub fn is_work_hours(now: DateTime<Utc>, config: &TimezoneConfig) -> bool {
-    let Ok(tz) = Tz::from_str(&config.timezone) else {
-        return false;
-    };
+    within_work_hours(now, config, true)
+}
 
-    let local_time = now.with_timezone(&tz);
-    let naive_time = local_time.time();
+/// Check if a timezone is in the grace buffer just outside its work hours
+///
+/// # Arguments
+///
+/// * `now` - Current UTC time to check
+/// * `config` - Timezone configuration with work hours and grace buffer
+///
+/// # Returns
+///
+/// * `bool` - True if the zone is reachable only thanks to the grace buffer
+pub fn is_in_grace(now: DateTime<Utc>, config: &TimezoneConfig) -> bool {
+    within_work_hours(now, config, true) && !within_work_hours(now, config, false)
+}
+
+/// Check if a time falls within work hours, optionally widened by the grace
+/// buffer
+fn within_work_hours(now: DateTime<Utc>, config: &TimezoneConfig, with_grace: bool) -> bool {
+    WorkSchedule::new(config).contains(now, with_grace)
+}
+
+/// A zone's clock and work hours, parsed once for checking many instants
+struct WorkSchedule<'a> {
+    config: &'a TimezoneConfig,
+    clock: Option<ZoneClock>,
+    hours: Option<(NaiveTime, NaiveTime)>,
+}
+
+/// The rules a zone's local time follows: its IANA identifier, or a fixed
+/// `offset_override`
+enum ZoneClock {
+    Named(Tz),
+    Fixed(FixedOffset),
+}
+
+impl<'a> WorkSchedule<'a> {
+    fn new(config: &'a TimezoneConfig) -> Self {
+        let clock = match config.offset_override {
+            Some(offset) => FixedOffset::east_opt(offset).map(ZoneClock::Fixed),
+            None => Tz::from_str(&config.timezone).ok().map(ZoneClock::Named),
+        };
+        let hours = config
+            .work_hours
+            .start_time()
+            .zip(config.work_hours.effective_end_time());
+        Self {
+            config,
+            clock,
+            hours,
+        }
+    }
+
+    /// Local wall-clock time at an instant, or None if the timezone or
+    /// override is invalid or the local time is out of range
+    fn local(&self, now: DateTime<Utc>) -> Option<NaiveDateTime> {
+        match self.clock.as_ref()? {
+            ZoneClock::Named(tz) => checked_local(now, tz).map(|local| local.naive_local()),
+            ZoneClock::Fixed(offset) => checked_local(now, offset).map(|local| local.naive_local()),
+        }
+    }
+
+    /// Check if an instant falls within work hours, optionally widened by
+    /// the grace buffer; see `within_work_hours`
+    fn contains(&self, now: DateTime<Utc>, with_grace: bool) -> bool {
+        let Some(local) = self.local(now) else {
+            return false;
+        };
+        self.config.always_working
+            || self.hours.is_some_and(|hours| {
+                shift_containing(self.config, hours, local, with_grace).is_some()
+            })
+    }
+}
+
+/// Get the local start and end of the work shift beginning on a date
+///
+/// Hours ending at or before their start run overnight and end on the next
+/// day. Work days and holidays are not checked.
+fn local_shift(config: &TimezoneConfig, date: NaiveDate) -> Option<(NaiveDateTime, NaiveDateTime)> {
+    let start = config.work_hours.start_time()?;
+    let end = config.work_hours.effective_end_time()?;
+    shift_on((start, end), date)
+}
+
+/// Get the shift beginning on a date for already parsed start and end times
+fn shift_on(
+    (start, end): (NaiveTime, NaiveTime),
+    date: NaiveDate,
+) -> Option<(NaiveDateTime, NaiveDateTime)> {
+    let end_date = if end <= start { date.succ_opt()? } else { date };
+    Some((date.and_time(start), end_date.and_time(end)))
+}
+
+/// Get the work shift that a local time falls in
+///
+/// A shift belongs to the day it starts on, so the part of an overnight
+/// shift after midnight follows the previous day's work days and holidays.
+/// Both boundaries are inclusive. With `with_grace`, the shift is widened by
+/// the grace buffer, which does not reach past midnight on either side.
+///
+/// The part of each shift on the local date is checked as a time-of-day
+/// window with `window_contains`, the check behind `is_within_window`.
+///
+/// # Returns
+///
+/// * `Option<(NaiveDateTime, NaiveDateTime)>` - The shift without its grace
+///   buffer, or None outside work hours
+fn containing_shift(
+    config: &TimezoneConfig,
+    local: NaiveDateTime,
+    with_grace: bool,
+) -> Option<(NaiveDateTime, NaiveDateTime)> {
+    let hours = config
+        .work_hours
+        .start_time()
+        .zip(config.work_hours.effective_end_time())?;
+    shift_containing(config, hours, local, with_grace)
+}
+
+/// `containing_shift` for already parsed start and end times
+fn shift_containing(
+    config: &TimezoneConfig,
+    hours: (NaiveTime, NaiveTime),
+    local: NaiveDateTime,
+    with_grace: bool,
+) -> Option<(NaiveDateTime, NaiveDateTime)> {
+    let today = local.date();
+    [today.pred_opt(), Some(today)]
+        .into_iter()
+        .flatten()
+        .filter(|day| day_off_reason(config, *day).is_none())
+        .filter_map(|day| shift_on(hours, day))
+        .find(|(start, end)| {
+            let (mut from, mut to) = (*start, *end);
+            if with_grace {
+                let before = chrono::Duration::minutes(i64::from(config.grace_before_minutes));
+                let after = chrono::Duration::minutes(i64::from(config.grace_after_minutes));
+                from = (from - before).max(from.date().and_time(NaiveTime::MIN));
+                to = (to + after).min(to.date().and_time(LAST_INSTANT));
+            }
+            // The shift's part on the local date, e.g. 22:00-end of day for
+            // the first night of an overnight shift
+            let date = local.date();
+            if date < from.date() || date > to.date() {
+                return false;
+            }
+            let first = if from.date() == date {
+                from.time()
+            } else {
+                NaiveTime::MIN
+            };
+            let last = if to.date() == date {
+                to.time()
+            } else {
+                LAST_INSTANT
+            };
+            window_contains(local.time(), first, last)
+        })
+}
+
+/// The last representable instant of a day
+const LAST_INSTANT: NaiveTime = NaiveTime::from_hms_nano_opt(23, 59, 59, 999_999_999)
+    .expect("23:59:59.999999999 is a valid time");
+
+/// Check whether the local time in a zone falls within a daily window
+///
+/// Windows ending before they start run overnight, e.g. 22:00-06:00. Both
+/// boundaries are inclusive. Unlike `is_work_hours`, no work hours, work
+/// days or holidays are involved.
+///
+/// A window whose start equals its end is that single minute. Work hours
+/// with equal start and end differ: they are first turned into a dated
+/// 24-hour shift, whose part on each date is then checked with the same
+/// window test.
+///
+/// # Arguments
+///
+/// * `now` - Current UTC time
+/// * `tz_str` - IANA timezone identifier
+/// * `start` - Local start of the window
+/// * `end` - Local end of the window
+///
+/// # Returns
+///
+/// * `Option<bool>` - Whether the local time is within the window, or None
+///   if the timezone is invalid
+pub fn is_within_window(
+    now: DateTime<Utc>,
+    tz_str: &str,
+    start: NaiveTime,
+    end: NaiveTime,
+) -> Option<bool> {
+    let tz = Tz::from_str(tz_str).ok()?;
+    Some(window_contains(checked_local(now, &tz)?.time(), start, end))
+}
 
-    match (config.work_hours.start_time(), config.work_hours.end_time()) {
-        (Some(start), Some(end)) => naive_time >= start && naive_time <= end,
-        _ => false,
+/// Check whether a time of day falls within a daily window, running
+/// overnight when it ends before it starts
+fn window_contains(time: NaiveTime, start: NaiveTime, end: NaiveTime) -> bool {
+    if start <= end {
+        time >= start && time <= end
+    } else {
+        time >= start || time <= end
     }
 }
 
+/// Check the work status of every zone at one instant
+///
+/// Each zone's timezone and work hours are parsed once, so UIs can look
+/// statuses up by index instead of calling `is_work_hours` per zone.
+///
+/// # Arguments
+///
+/// * `now` - Current UTC time
+/// * `zones` - Zones to check
+///
+/// # Returns
+///
+/// * `Vec<bool>` - Whether each zone is in work hours, in the same order as
+///   `zones`; invalid identifiers count as not working
+pub fn work_statuses(now: DateTime<Utc>, zones: &[TimezoneConfig]) -> Vec<bool> {
+    zones
+        .iter()
+        .map(|zone| WorkSchedule::new(zone).contains(now, true))
+        .collect()
+}
+
 /// Calculate time difference in hours between a timezone and a reference offset
 ///
 /// # Arguments
@@ -92,9 +736,12 @@ pub fn calculate_time_difference(
 
 /// Get the UTC offset in seconds for a timezone
 ///
+/// The offset follows the zone's rules at `now`, so a past or future
+/// instant gets that date's DST state rather than today's.
+///
 /// # Arguments
 ///
-/// * `now` - Current UTC time
+/// * `now` - UTC instant to evaluate, not necessarily the current time
 /// * `tz_str` - IANA timezone identifier
 ///
 /// # Returns
@@ -106,78 +753,1335 @@ pub fn get_timezone_offset(now: DateTime<Utc>, tz_str: &str) -> Option<i32> {
     Some(local_time.offset().fix().local_minus_utc())
 }
 
-/// Get comprehensive display info for a timezone
+/// Get the effective UTC offset in seconds for a configured zone
+///
+/// A zone's `offset_override` takes precedence over its IANA identifier.
 ///
 /// # Arguments
 ///
 /// * `now` - Current UTC time
 /// * `config` - Timezone configuration
-/// * `reference_offset_seconds` - Reference timezone offset for difference calculation
-/// * `use_12h_format` - Whether to use 12-hour time format
 ///
 /// # Returns
 ///
-/// * `Option<TimeDisplayInfo>` - Display information, or None if timezone is invalid
-pub fn get_time_display_info(
-    now: DateTime<Utc>,
-    config: &TimezoneConfig,
-    reference_offset_seconds: i32,
-    use_12h_format: bool,
-) -> Option<TimeDisplayInfo> {
-    let tz = Tz::from_str(&config.timezone).ok()?;
-    let local_time = now.with_timezone(&tz);
-
-    let time_format = if use_12h_format { "%I:%M %p" } else { "%H:%M" };
-    let time = local_time.format(time_format).to_string();
-    let date = local_time.format("%Y-%m-%d").to_string();
-
-    let current_offset = local_time.offset().fix().local_minus_utc();
-    let diff_hours = (current_offset - reference_offset_seconds) as f64 / 3600.0;
-
-    let is_working = is_work_hours(now, config);
+/// * `Option<i32>` - Offset in seconds, or None if the timezone or override
+///   is invalid
+pub fn zone_offset(now: DateTime<Utc>, config: &TimezoneConfig) -> Option<i32> {
+    zone_local_time(now, config).map(|local_time| local_time.offset().local_minus_utc())
+}
 
-    Some(TimeDisplayInfo {
-        time,
-        date,
-        diff_hours,
-        is_working,
-    })
+/// Get the distinct UTC offsets in use by a set of zones
+///
+/// # Arguments
+///
+/// * `now` - Current UTC time
+/// * `zones` - Zones to inspect, e.g. a slice or borrowed filtered list
+///
+/// # Returns
+///
+/// * `Vec<i32>` - Offsets in seconds, sorted and without duplicates;
+///   invalid zones are skipped
+pub fn distinct_offsets<'a>(
+    now: DateTime<Utc>,
+    zones: impl IntoIterator<Item = &'a TimezoneConfig>,
+) -> Vec<i32> {
+    let mut offsets: Vec<_> = zones
+        .into_iter()
+        .filter_map(|zone| zone_offset(now, zone))
+        .collect();
+    offsets.sort_unstable();
+    offsets.dedup();
+    offsets
 }
 
-/// Format time difference as a display string
+/// Get the current local time of a configured zone
+///
+/// A zone's `offset_override` takes precedence over its IANA identifier.
 ///
 /// # Arguments
 ///
-/// * `diff_hours` - Time difference in hours
+/// * `now` - Current UTC time
+/// * `config` - Timezone configuration
 ///
 /// # Returns
 ///
-/// * `String` - Formatted string like "+8", "-5", or "="
-pub fn format_time_diff(diff_hours: f64) -> String {
-    if diff_hours == 0.0 {
-        "=".to_string()
-    } else if diff_hours > 0.0 {
-        format!("+{diff_hours}")
-    } else {
-        format!("{diff_hours}")
+/// * `Option<DateTime<FixedOffset>>` - The local time, or None if the
+///   timezone or override is invalid or the local time is out of range
+pub fn zone_local_time(
+    now: DateTime<Utc>,
+    config: &TimezoneConfig,
+) -> Option<DateTime<FixedOffset>> {
+    match config.offset_override {
+        Some(offset) => checked_local(now, &FixedOffset::east_opt(offset)?),
+        None => {
+            let tz = Tz::from_str(&config.timezone).ok()?;
+            checked_local(now, &tz).map(|local_time| local_time.fixed_offset())
+        }
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use chrono::TimeZone;
+/// Convert an instant to a timezone, or None if the local date-time falls
+/// outside chrono's supported range
+///
+/// `DateTime::with_timezone` succeeds at the extremes of the range, but
+/// reading the local date or time of the result then panics.
+pub(crate) fn checked_local<Z: TimeZone>(now: DateTime<Utc>, tz: &Z) -> Option<DateTime<Z>> {
+    let offset = tz.offset_from_utc_datetime(&now.naive_utc()).fix();
+    now.naive_utc().checked_add_offset(offset)?;
+    Some(now.with_timezone(tz))
+}
 
-    use super::*;
-    use crate::config::WorkHours;
+/// Convert a zone's local wall-clock time to UTC, honouring its
+/// `offset_override`
+///
+/// Ambiguous local times resolve to the later instant when `latest` is set
+/// and to the earlier one otherwise; times skipped by a DST transition give
+/// None.
+pub(crate) fn zone_to_utc(
+    config: &TimezoneConfig,
+    local: NaiveDateTime,
+    latest: bool,
+) -> Option<DateTime<Utc>> {
+    let resolved = match config.offset_override {
+        Some(offset) => FixedOffset::east_opt(offset)?
+            .from_local_datetime(&local)
+            .single(),
+        None => {
+            let result = Tz::from_str(&config.timezone)
+                .ok()?
+                .from_local_datetime(&local)
+                .map(|local_time| local_time.fixed_offset());
+            if latest {
+                result.latest()
+            } else {
+                result.earliest()
+            }
+        }
+    };
+    resolved.map(|local_time| local_time.with_timezone(&Utc))
+}
 
-    fn create_test_config(timezone: &str) -> TimezoneConfig {
-        TimezoneConfig {
-            name: "Test".to_string(),
-            timezone: timezone.to_string(),
-            work_hours: WorkHours {
+/// Get the current offset difference between two timezones
+///
+/// # Arguments
+///
+/// * `now` - Current UTC time
+/// * `tz_a` - IANA timezone identifier of the first zone
+/// * `tz_b` - IANA timezone identifier of the second zone
+///
+/// # Returns
+///
+/// * `Option<i32>` - Offset of `tz_a` minus offset of `tz_b` in seconds, or
+///   None if either timezone is invalid
+pub fn offset_difference(now: DateTime<Utc>, tz_a: &str, tz_b: &str) -> Option<i32> {
+    Some(get_timezone_offset(now, tz_a)? - get_timezone_offset(now, tz_b)?)
+}
+
+/// Get the pairwise offset differences between timezones
+///
+/// Cell `(i, j)` holds the offset of zone `j` relative to zone `i`, so a
+/// row reads "how far ahead each zone is of this one".
+///
+/// # Arguments
+///
+/// * `now` - Current UTC time
+/// * `zones` - The timezones to compare
+///
+/// # Returns
+///
+/// * `Vec<Vec<Option<i32>>>` - Differences in seconds; None where either zone
+///   is invalid
+pub fn offset_matrix(now: DateTime<Utc>, zones: &[&TimezoneConfig]) -> Vec<Vec<Option<i32>>> {
+    zones
+        .iter()
+        .map(|row| {
+            zones
+                .iter()
+                .map(|column| Some(zone_offset(now, column)? - zone_offset(now, row)?))
+                .collect()
+        })
+        .collect()
+}
+
+/// Number of days covered by `week_availability`
+pub const PLANNING_DAYS: usize = 7;
+
+/// Get whether each zone is working at the same local time of the
+/// reference zone on consecutive days
+///
+/// Row `i` belongs to zone `i`; column `d` is `hour` on the reference zone's
+/// date `first_day` plus `d` days, so a row shows whether a recurring
+/// meeting at that time falls in the zone's work hours, honoring work days
+/// and holidays but not the grace buffer. The meeting keeps its local time
+/// in the reference zone across a DST change there.
+///
+/// # Arguments
+///
+/// * `reference` - The zone the meeting is scheduled in
+/// * `first_day` - The meeting's first date in the reference zone
+/// * `hour` - The meeting's local time in the reference zone
+/// * `zones` - The timezones to check
+///
+/// # Returns
+///
+/// * `Vec<[bool; PLANNING_DAYS]>` - Work status per zone and day; invalid
+///   zones, and days the local time does not exist in the reference zone,
+///   are never working
+pub fn week_availability(
+    reference: &TimezoneConfig,
+    first_day: NaiveDate,
+    hour: NaiveTime,
+    zones: &[&TimezoneConfig],
+) -> Vec<[bool; PLANNING_DAYS]> {
+    let instants: Vec<_> = first_day
+        .iter_days()
+        .take(PLANNING_DAYS)
+        .map(|date| zone_to_utc(reference, date.and_time(hour), false))
+        .collect();
+    zones
+        .iter()
+        .map(|zone| {
+            let schedule = WorkSchedule::new(zone);
+            let mut days = [false; PLANNING_DAYS];
+            for (cell, at) in days.iter_mut().zip(&instants) {
+                *cell = at.is_some_and(|at| schedule.contains(at, false));
+            }
+            days
+        })
+        .collect()
+}
+
+/// Find the zone whose local time is furthest behind
+///
+/// # Arguments
+///
+/// * `now` - Current UTC time
+/// * `zones` - Zones to compare; invalid identifiers are skipped
+///
+/// # Returns
+///
+/// * `Option<usize>` - Index of the zone with the smallest UTC offset (the
+///   first one on ties), or None if no zone is valid
+pub fn earliest_zone(now: DateTime<Utc>, zones: &[TimezoneConfig]) -> Option<usize> {
+    zone_offsets(now, zones)
+        .min_by_key(|(_, offset)| *offset)
+        .map(|(index, _)| index)
+}
+
+/// Find the zone whose local time is furthest ahead
+///
+/// # Arguments
+///
+/// * `now` - Current UTC time
+/// * `zones` - Zones to compare; invalid identifiers are skipped
+///
+/// # Returns
+///
+/// * `Option<usize>` - Index of the zone with the largest UTC offset (the
+///   first one on ties), or None if no zone is valid
+pub fn latest_zone(now: DateTime<Utc>, zones: &[TimezoneConfig]) -> Option<usize> {
+    zone_offsets(now, zones)
+        .min_by_key(|(_, offset)| std::cmp::Reverse(*offset))
+        .map(|(index, _)| index)
+}
+
+/// Indices and current offsets of the zones with valid identifiers
+fn zone_offsets(
+    now: DateTime<Utc>,
+    zones: &[TimezoneConfig],
+) -> impl Iterator<Item = (usize, i32)> + '_ {
+    zones
+        .iter()
+        .enumerate()
+        .filter_map(move |(index, zone)| Some((index, zone_offset(now, zone)?)))
+}
+
+/// Get a display name for the configured home timezone
+///
+/// Uses the name of a configured zone with the same identifier, falling back
+/// to the city part of the identifier (e.g. "New York" for
+/// "America/New_York").
+///
+/// # Arguments
+///
+/// * `config` - Configuration holding the home timezone
+///
+/// # Returns
+///
+/// * `Option<String>` - The label, or None if no valid home timezone is set
+pub fn home_zone_label(config: &Config) -> Option<String> {
+    home_zone(config).map(|zone| zone.name)
+}
+
+/// Get the timezone configuration of the home zone
+///
+/// Uses the enabled zone with the same identifier, or a zone with default
+/// work hours when the home timezone is not in the list or is muted.
+///
+/// # Arguments
+///
+/// * `config` - Configuration holding the home timezone
+///
+/// # Returns
+///
+/// * `Option<TimezoneConfig>` - The home zone, or None if no valid home timezone is set
+pub fn home_zone(config: &Config) -> Option<TimezoneConfig> {
+    configured_zone(config, config.home_timezone.as_deref()?)
+}
+
+/// Get the timezone configuration for an IANA identifier
+///
+/// Uses the enabled zone with the same identifier, or a zone with default
+/// work hours named after the identifier's city when it is not in the list
+/// or is muted.
+///
+/// # Arguments
+///
+/// * `config` - Configuration holding the timezone list
+/// * `tz_str` - IANA timezone identifier
+///
+/// # Returns
+///
+/// * `Option<TimezoneConfig>` - The zone, or None if the identifier is invalid
+pub fn configured_zone(config: &Config, tz_str: &str) -> Option<TimezoneConfig> {
+    Tz::from_str(tz_str).ok()?;
+    let zone = config
+        .timezones
+        .iter()
+        .find(|tz| tz.enabled && tz.timezone == tz_str)
+        .cloned()
+        .unwrap_or_else(|| TimezoneConfig {
+            name: tz_str
+                .rsplit('/')
+                .next()
+                .unwrap_or(tz_str)
+                .replace('_', " "),
+            timezone: tz_str.to_string(),
+            ..Default::default()
+        });
+    Some(zone)
+}
+
+/// Get the offset that diffs are measured against
+///
+/// Follows `Config::effective_diff_baseline`: the home timezone (falling
+/// back to the selected zone), the selected zone, or the system timezone.
+///
+/// # Arguments
+///
+/// * `now` - Current UTC time
+/// * `config` - Configuration holding the home timezone and baseline
+/// * `selected` - The currently selected timezone, if any
+///
+/// # Returns
+///
+/// * `i32` - Reference offset in seconds (0 if nothing valid is available)
+pub fn reference_offset(
+    now: DateTime<Utc>,
+    config: &Config,
+    selected: Option<&TimezoneConfig>,
+) -> i32 {
+    reference_zone(now, config, selected)
+        .and_then(|zone| zone_offset(now, &zone))
+        .unwrap_or(0)
+}
+
+/// Get the zone that diffs, jumps to absolute times and overlaps are
+/// relative to
+///
+/// Follows `Config::effective_diff_baseline` like `reference_offset`. The
+/// system timezone has no IANA identifier here, so it is represented by a
+/// zone named "Local" fixed at the system offset at `now`.
+///
+/// # Arguments
+///
+/// * `now` - Current UTC time, fixing the system offset
+/// * `config` - Configuration holding the home timezone and baseline
+/// * `selected` - The currently selected timezone, if any
+///
+/// # Returns
+///
+/// * `Option<TimezoneConfig>` - The reference zone, or None when it would be
+///   the selected zone and none is selected
+pub fn reference_zone(
+    now: DateTime<Utc>,
+    config: &Config,
+    selected: Option<&TimezoneConfig>,
+) -> Option<TimezoneConfig> {
+    baseline_zone(config, selected, system_offset(now))
+}
+
+/// `reference_zone` with the system offset passed in
+fn baseline_zone(
+    config: &Config,
+    selected: Option<&TimezoneConfig>,
+    system_offset: i32,
+) -> Option<TimezoneConfig> {
+    match config.effective_diff_baseline() {
+        DiffBaseline::Home => home_zone(config).or_else(|| selected.cloned()),
+        DiffBaseline::Selected => selected.cloned(),
+        DiffBaseline::Local => Some(local_zone(system_offset)),
+    }
+}
+
+/// Get a zone standing for the system (or browser) timezone
+///
+/// # Arguments
+///
+/// * `system_offset` - The system offset in seconds, e.g. from `system_offset`
+///
+/// # Returns
+///
+/// * `TimezoneConfig` - A zone named "Local" fixed at that offset
+pub fn local_zone(system_offset: i32) -> TimezoneConfig {
+    TimezoneConfig {
+        name: "Local".to_string(),
+        timezone: "UTC".to_string(),
+        offset_override: Some(system_offset),
+        ..Default::default()
+    }
+}
+
+/// Get the offset of the system (or browser) timezone at an instant
+///
+/// # Arguments
+///
+/// * `now` - Current UTC time
+///
+/// # Returns
+///
+/// * `i32` - Offset in seconds east of UTC
+pub fn system_offset(now: DateTime<Utc>) -> i32 {
+    Local
+        .offset_from_utc_datetime(&now.naive_utc())
+        .local_minus_utc()
+}
+
+/// Get the name of the zone diffs are measured against, unless it is the
+/// selected zone
+///
+/// # Arguments
+///
+/// * `config` - Configuration holding the home timezone and baseline
+///
+/// # Returns
+///
+/// * `Option<String>` - The home zone's name, "Local" for the system
+///   timezone, or None when diffs follow the selected zone
+pub fn baseline_label(config: &Config) -> Option<String> {
+    match config.effective_diff_baseline() {
+        DiffBaseline::Home => home_zone_label(config),
+        DiffBaseline::Selected => None,
+        DiffBaseline::Local => Some("Local".to_string()),
+    }
+}
+
+/// Get the name of the zone diffs and conversions are relative to
+///
+/// # Arguments
+///
+/// * `config` - Configuration holding the home timezone and baseline
+/// * `selected` - The selected zone, used when diffs follow it
+/// * `utc_locked` - Whether the reference is locked to UTC
+///
+/// # Returns
+///
+/// * `String` - "UTC" while locked or without a reference, otherwise the
+///   name from `baseline_label` or the selected zone's name
+pub fn reference_name(
+    config: &Config,
+    selected: Option<&TimezoneConfig>,
+    utc_locked: bool,
+) -> String {
+    if utc_locked {
+        return "UTC".to_string();
+    }
+    baseline_label(config)
+        .or_else(|| selected.map(|tz| tz.name.clone()))
+        .unwrap_or_else(|| "UTC".to_string())
+}
+
+/// Get the diff column label naming the reference zone
+///
+/// # Arguments
+///
+/// * `config` - Configuration holding the home timezone and baseline
+///
+/// # Returns
+///
+/// * `String` - e.g. "Diff (vs London)", or "Diff (vs selected)" when diffs
+///   follow the selected zone
+pub fn diff_header_label(config: &Config) -> String {
+    let reference = baseline_label(config).unwrap_or_else(|| "selected".to_string());
+    format!("Diff (vs {reference})")
+}
+
+/// Get comprehensive display info for a timezone
+///
+/// # Arguments
+///
+/// * `now` - Current UTC time
+/// * `config` - Timezone configuration
+/// * `reference_offset_seconds` - Reference timezone offset for difference calculation
+/// * `options` - Time and date rendering options; the timezone may override the
+///   12-hour setting via `TimezoneConfig::use_12h_format`
+///
+/// # Returns
+///
+/// * `Option<TimeDisplayInfo>` - Display information, or None if timezone is
+///   invalid or the local time is outside chrono's supported range
+pub fn get_time_display_info(
+    now: DateTime<Utc>,
+    config: &TimezoneConfig,
+    reference_offset_seconds: i32,
+    options: DisplayOptions,
+) -> Option<TimeDisplayInfo> {
+    let local_time = zone_local_time(now, config)?;
+
+    let options = DisplayOptions {
+        use_12h_format: config.use_12h_format.unwrap_or(options.use_12h_format),
+        ..options
+    };
+    let shown_time = options.round(&local_time);
+    let time = options.format_time(&shown_time);
+    let date = options.format_date(&shown_time);
+
+    let current_offset = local_time.offset().local_minus_utc();
+    let diff_hours = (current_offset - reference_offset_seconds) as f64 / 3600.0;
+
+    let status = work_status(now, config, options.ending_soon_minutes);
+    let is_working = matches!(
+        status,
+        WorkStatus::Working | WorkStatus::EndingSoon | WorkStatus::Grace
+    );
+
+    Some(TimeDisplayInfo {
+        time,
+        date,
+        diff_hours,
+        is_working,
+        status,
+    })
+}
+
+/// Get how many calendar days a zone's local date is ahead of the reference
+/// clock's date
+///
+/// # Arguments
+///
+/// * `now` - Current UTC time
+/// * `config` - Timezone configuration
+/// * `reference_offset_seconds` - Offset of the reference clock
+///
+/// # Returns
+///
+/// * `Option<i64>` - Local date minus reference date in days (e.g. 1 when
+///   the zone is already on tomorrow), or None if the timezone is invalid
+pub fn day_offset(
+    now: DateTime<Utc>,
+    config: &TimezoneConfig,
+    reference_offset_seconds: i32,
+) -> Option<i64> {
+    let local = zone_local_time(now, config)?.date_naive();
+    let reference = now
+        .naive_utc()
+        .checked_add_signed(chrono::Duration::seconds(reference_offset_seconds.into()))?
+        .date();
+    Some((local - reference).num_days())
+}
+
+/// Format a day offset as a compact chip label
+///
+/// # Arguments
+///
+/// * `days` - Day offset from `day_offset`
+///
+/// # Returns
+///
+/// * `Option<String>` - "+1d", "-1d" and so on, or None on the same day
+pub fn format_day_offset(days: i64) -> Option<String> {
+    (days != 0).then(|| format!("{days:+}d"))
+}
+
+/// Get display info for a timezone at a later instant, for side-by-side
+/// comparisons such as this week against next week
+///
+/// # Arguments
+///
+/// * `now` - The instant compared against
+/// * `delta_seconds` - How far the compared instant is from `now`
+/// * `config` - Timezone configuration
+/// * `reference_offset` - Reference offset in seconds at a given instant, so
+///   diffs follow daylight saving changes in between
+/// * `options` - Time and date rendering options
+///
+/// # Returns
+///
+/// * `Option<TimeDisplayInfo>` - Display info at `now + delta_seconds`, or
+///   None if it cannot be shown
+pub fn compare_display_info(
+    now: DateTime<Utc>,
+    delta_seconds: i64,
+    config: &TimezoneConfig,
+    reference_offset: impl Fn(DateTime<Utc>) -> i32,
+    options: DisplayOptions,
+) -> Option<TimeDisplayInfo> {
+    let later = now.checked_add_signed(chrono::Duration::try_seconds(delta_seconds)?)?;
+    get_time_display_info(later, config, reference_offset(later), options)
+}
+
+/// Find the next start of work hours for a timezone, strictly after `now`
+///
+/// Days outside `work_days` and configured holidays are skipped. Local
+/// start times that do not exist on a given day (e.g. skipped by a DST
+/// transition) are skipped; ambiguous ones resolve to the earlier instant.
+///
+/// # Arguments
+///
+/// * `now` - Current UTC time
+/// * `config` - Timezone configuration with work hours
+///
+/// # Returns
+///
+/// * `Option<DateTime<Utc>>` - The next work start, or None if the zone is
+///   always working or the timezone or start time is invalid
+pub fn next_work_start(now: DateTime<Utc>, config: &TimezoneConfig) -> Option<DateTime<Utc>> {
+    if config.always_working {
+        return None;
+    }
+    let start = config.work_hours.start_time()?;
+    let today = zone_local_time(now, config)?.date_naive();
+
+    // A year covers any combination of work days and holidays
+    today
+        .iter_days()
+        .take(367)
+        .filter(|day| day_off_reason(config, *day).is_none())
+        .filter_map(|day| zone_to_utc(config, day.and_time(start), false))
+        .find(|candidate| *candidate > now)
+}
+
+/// Get which hours of a zone's local day are within its work hours
+///
+/// Each hour of the zone's current local date is sampled at its midpoint.
+/// The grace buffer does not count as work hours.
+///
+/// # Arguments
+///
+/// * `now` - Current UTC time, selecting the zone's local date
+/// * `zone` - The timezone whose day is divided into hours
+///
+/// # Returns
+///
+/// * `Option<[bool; 24]>` - Work status per local hour, or None if `zone` is invalid
+pub fn work_by_hour(now: DateTime<Utc>, zone: &TimezoneConfig) -> Option<[bool; 24]> {
+    let schedule = WorkSchedule::new(zone);
+    sample_hours(now, zone, |at| schedule.contains(at, false))
+}
+
+/// Get which hours of a zone's local day overlap with another zone's work hours
+///
+/// Each hour of the zone's current local date is sampled at its midpoint and
+/// counts as overlapping when it falls in a window shared by both zones'
+/// shifts (see `work_overlap`), honoring work days and holidays.
+///
+/// # Arguments
+///
+/// * `now` - Current UTC time, selecting the zone's local date
+/// * `zone` - The timezone whose day is divided into hours
+/// * `other` - The timezone to compare work hours with
+///
+/// # Returns
+///
+/// * `Option<[bool; 24]>` - Overlap per local hour, or None if `zone` is invalid
+pub fn work_overlap_by_hour(
+    now: DateTime<Utc>,
+    zone: &TimezoneConfig,
+    other: &TimezoneConfig,
+) -> Option<[bool; 24]> {
+    let today = zone_local_time(now, zone)?.date_naive();
+    let other_today = zone_local_time(now, other).map(|local_time| local_time.date_naive());
+    // Shifts starting the day before may still run, and the other zone's
+    // calendar can be a day ahead or behind
+    let zone_windows = work_windows(zone, [today.pred_opt(), Some(today)], true);
+    let other_windows = work_windows(
+        other,
+        (-2..=1).map(|days| other_today?.checked_add_signed(chrono::Duration::days(days))),
+        true,
+    );
+    let shared = shared_windows(&zone_windows, &other_windows);
+    sample_hours(now, zone, |at| {
+        shared.iter().any(|(start, end)| *start <= at && at <= *end)
+    })
+}
+
+/// Hours from a zone's work window within which a meeting is still
+/// considered near its work hours
+const MEETING_NEAR_HOURS: i64 = 2;
+
+/// Score how convenient each UTC hour is for a meeting with every zone
+///
+/// Each hour of the current UTC date is sampled at its midpoint. A zone
+/// scores 1.0 within its work hours, 0.5 within `MEETING_NEAR_HOURS` of
+/// them, 0.25 at other waking hours, 0.1 late in the evening and 0.0 at
+/// night (see `DayPhase`). The grace buffer does not count as work hours.
+/// The hour's score is the mean over all valid zones, so 1.0 means everyone
+/// is at work.
+///
+/// # Arguments
+///
+/// * `now` - Current UTC time, selecting the UTC date
+/// * `zones` - The zones taking part; invalid ones are ignored
+///
+/// # Returns
+///
+/// * `[f64; 24]` - Score from 0.0 to 1.0 per UTC hour, all 0.0 without a
+///   valid zone
+pub fn score_meeting_hours(now: DateTime<Utc>, zones: &[TimezoneConfig]) -> [f64; 24] {
+    let near = chrono::Duration::hours(MEETING_NEAR_HOURS);
+    let schedules: Vec<_> = zones.iter().map(WorkSchedule::new).collect();
+    let mut scores = [0.0; 24];
+    for (hour, score) in (0u32..).zip(scores.iter_mut()) {
+        let Some(at) = now
+            .date_naive()
+            .and_hms_opt(hour, 30, 0)
+            .map(|at| at.and_utc())
+        else {
+            continue;
+        };
+        let zone_scores: Vec<f64> = schedules
+            .iter()
+            .filter_map(|schedule| {
+                let local_hour = schedule.local(at)?.hour();
+                Some(if schedule.contains(at, false) {
+                    1.0
+                } else if schedule.contains(at + near, false) || schedule.contains(at - near, false)
+                {
+                    0.5
+                } else {
+                    match DayPhase::from_hour(local_hour) {
+                        DayPhase::Sleeping => 0.0,
+                        DayPhase::Late => 0.1,
+                        _ => 0.25,
+                    }
+                })
+            })
+            .collect();
+        if !zone_scores.is_empty() {
+            *score = zone_scores.iter().sum::<f64>() / zone_scores.len() as f64;
+        }
+    }
+    scores
+}
+
+/// Evaluate a check at the midpoint of each hour of a zone's current local
+/// date; hours that do not exist locally (DST gaps) are false
+fn sample_hours(
+    now: DateTime<Utc>,
+    zone: &TimezoneConfig,
+    check: impl Fn(DateTime<Utc>) -> bool,
+) -> Option<[bool; 24]> {
+    let today = zone_local_time(now, zone)?.date_naive();
+
+    let mut hours = [false; 24];
+    for (hour, cell) in (0u32..).zip(hours.iter_mut()) {
+        let instant = today
+            .and_hms_opt(hour, 30, 0)
+            .and_then(|local| zone_to_utc(zone, local, false));
+        *cell = instant.is_some_and(&check);
+    }
+    Some(hours)
+}
+
+/// Get a zone's work shift starting on one local date as UTC instants
+///
+/// Overnight hours end on the next day (see `is_work_hours`). Zones marked
+/// `always_working` span the whole local date. Work days and holidays are
+/// ignored.
+fn daily_work_window(
+    config: &TimezoneConfig,
+    date: NaiveDate,
+) -> Option<(DateTime<Utc>, DateTime<Utc>)> {
+    let (start, end) = if config.always_working {
+        (
+            date.and_time(NaiveTime::MIN),
+            date.succ_opt()?.and_time(NaiveTime::MIN),
+        )
+    } else {
+        local_shift(config, date)?
+    };
+    let window = (
+        zone_to_utc(config, start, false)?,
+        zone_to_utc(config, end, true)?,
+    );
+    (window.0 < window.1).then_some(window)
+}
+
+/// Get a zone's work shifts starting on some local dates as UTC instants
+///
+/// With `honor_days`, shifts starting on days off are left out.
+fn work_windows(
+    config: &TimezoneConfig,
+    dates: impl IntoIterator<Item = Option<NaiveDate>>,
+    honor_days: bool,
+) -> Vec<(DateTime<Utc>, DateTime<Utc>)> {
+    dates
+        .into_iter()
+        .flatten()
+        .filter(|date| {
+            !honor_days || config.always_working || day_off_reason(config, *date).is_none()
+        })
+        .filter_map(|date| daily_work_window(config, date))
+        .collect()
+}
+
+/// Intersect two sets of work windows, keeping the non-empty overlaps
+fn shared_windows(
+    windows: &[(DateTime<Utc>, DateTime<Utc>)],
+    others: &[(DateTime<Utc>, DateTime<Utc>)],
+) -> Vec<(DateTime<Utc>, DateTime<Utc>)> {
+    windows
+        .iter()
+        .flat_map(|(start, end)| {
+            others
+                .iter()
+                .map(|(other_start, other_end)| (*start.max(other_start), *end.min(other_end)))
+        })
+        .filter(|(from, to)| from < to)
+        .collect()
+}
+
+/// Get the window in which two zones are both within work hours
+///
+/// The first zone's shift starting on its current local date is compared with
+/// the second zone's work hours on the neighbouring local dates, so windows
+/// crossing midnight are found. Work days and holidays are ignored, as the
+/// result describes a typical work day.
+///
+/// # Arguments
+///
+/// * `now` - Current UTC time, selecting the first zone's local date
+/// * `zone` - The first timezone
+/// * `other` - The second timezone
+///
+/// # Returns
+///
+/// * `Option<(DateTime<Utc>, DateTime<Utc>)>` - The longest shared window,
+///   or None if the work hours never meet or either zone is invalid
+pub fn work_overlap(
+    now: DateTime<Utc>,
+    zone: &TimezoneConfig,
+    other: &TimezoneConfig,
+) -> Option<(DateTime<Utc>, DateTime<Utc>)> {
+    let today = zone_local_time(now, zone)?.date_naive();
+    let other_today = zone_local_time(now, other)?.date_naive();
+
+    let zone_windows = work_windows(zone, [Some(today)], false);
+    let other_windows = work_windows(
+        other,
+        [
+            other_today.pred_opt(),
+            Some(other_today),
+            other_today.succ_opt(),
+        ],
+        false,
+    );
+    shared_windows(&zone_windows, &other_windows)
+        .into_iter()
+        .max_by_key(|(from, to)| *to - *from)
+}
+
+/// Get the work hour overlap of every pair of zones
+///
+/// # Arguments
+///
+/// * `now` - Current UTC time, selecting each first zone's local date
+/// * `zones` - The timezones to compare
+///
+/// # Returns
+///
+/// * `Vec<PairOverlap>` - One entry per unordered pair, in the order
+///   (0, 1), (0, 2), ..., (1, 2), ...
+pub fn pairwise_overlaps(now: DateTime<Utc>, zones: &[&TimezoneConfig]) -> Vec<PairOverlap> {
+    let mut overlaps = Vec::new();
+    for (first, zone) in zones.iter().enumerate() {
+        for (second, other) in zones.iter().enumerate().skip(first + 1) {
+            overlaps.push(PairOverlap {
+                first,
+                second,
+                window: work_overlap(now, zone, other),
+            });
+        }
+    }
+    overlaps
+}
+
+/// Get a zone's work hours for its current local date on the reference clock
+///
+/// For example, London's 09:00-17:30 reads as 17:00 - 01:30 (+1) in Tokyo
+/// during British Summer Time.
+///
+/// # Arguments
+///
+/// * `now` - Current (simulated) UTC time, selecting the zone's work day
+/// * `config` - Timezone configuration with work hours
+/// * `reference_offset_seconds` - Reference timezone offset in seconds
+///
+/// # Returns
+///
+/// * `Option<ReferenceHours>` - The converted range, or None if the zone,
+///   its work hours or the offset are invalid
+pub fn work_hours_in_reference(
+    now: DateTime<Utc>,
+    config: &TimezoneConfig,
+    reference_offset_seconds: i32,
+) -> Option<ReferenceHours> {
+    let reference = FixedOffset::east_opt(reference_offset_seconds)?;
+    let today = zone_local_time(now, config)?.date_naive();
+    let reference_date = checked_local(now, &reference)?.date_naive();
+
+    let convert = |time: NaiveTime| {
+        let instant = zone_to_utc(config, today.and_time(time), false)?;
+        let local = checked_local(instant, &reference)?;
+        Some((
+            local.time(),
+            (local.date_naive() - reference_date).num_days(),
+        ))
+    };
+    let (start, start_day) = convert(config.work_hours.start_time()?)?;
+    let (end, end_day) = convert(config.work_hours.end_time()?)?;
+    Some(ReferenceHours {
+        start,
+        end,
+        start_day,
+        end_day,
+    })
+}
+
+/// Get the instant at which the reference zone reads a local time of day
+///
+/// The local date is the reference zone's date at `now`, so the result may be
+/// earlier or later than `now`. The time is resolved with the zone's rules on
+/// that date, so a DST change between `now` and the target is honoured.
+///
+/// # Arguments
+///
+/// * `now` - Current (simulated) UTC time
+/// * `reference` - The reference zone, honouring its `offset_override`
+/// * `time` - The desired local time of day
+///
+/// # Returns
+///
+/// * `Option<DateTime<Utc>>` - The matching instant (the earlier one when the
+///   time repeats), or None if the zone is invalid or the time is skipped
+pub fn local_time_instant(
+    now: DateTime<Utc>,
+    reference: &TimezoneConfig,
+    time: NaiveTime,
+) -> Option<DateTime<Utc>> {
+    let date = zone_local_time(now, reference)?.date_naive();
+    zone_to_utc(reference, date.and_time(time), false)
+}
+
+/// Convert a wall-clock time in a timezone to UTC
+///
+/// Around DST transitions a wall-clock time may not map to exactly one
+/// instant. Times skipped when clocks spring forward (e.g. 02:30 on a
+/// US spring-forward day) do not exist and give None. Times repeated when
+/// clocks fall back resolve to their first occurrence, the one still on the
+/// earlier (daylight) offset.
+///
+/// # Arguments
+///
+/// * `date` - Local calendar date
+/// * `time` - Local time of day
+/// * `tz_str` - IANA timezone identifier
+///
+/// # Returns
+///
+/// * `Option<DateTime<Utc>>` - The instant, or None if the local time does
+///   not exist or the timezone is invalid
+///
+/// # Example
+///
+/// ```
+/// use chrono::{NaiveDate, NaiveTime, TimeZone, Utc};
+/// use longtime_core::local_to_utc;
+///
+/// let date = NaiveDate::from_ymd_opt(2024, 6, 3).unwrap();
+/// let nine = NaiveTime::from_hms_opt(9, 0, 0).unwrap();
+/// assert_eq!(
+///     local_to_utc(date, nine, "Asia/Tokyo"),
+///     Some(Utc.with_ymd_and_hms(2024, 6, 3, 0, 0, 0).unwrap())
+/// );
+/// ```
+pub fn local_to_utc(date: NaiveDate, time: NaiveTime, tz_str: &str) -> Option<DateTime<Utc>> {
+    let tz = Tz::from_str(tz_str).ok()?;
+    let local = tz.from_local_datetime(&date.and_time(time)).earliest()?;
+    Some(local.with_timezone(&Utc))
+}
+
+/// Start or end of a local day, for snapping the simulated time
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DayBoundary {
+    /// Local midnight, 00:00
+    Start,
+    /// The last minute of the day, 23:59
+    End,
+}
+
+/// The last minute of a day, the time of `DayBoundary::End`
+const LAST_MINUTE: NaiveTime = NaiveTime::from_hms_opt(23, 59, 0).expect("23:59 is a valid time");
+
+impl DayBoundary {
+    /// Local time of day of the boundary
+    pub fn time(self) -> NaiveTime {
+        match self {
+            DayBoundary::Start => NaiveTime::MIN,
+            DayBoundary::End => LAST_MINUTE,
+        }
+    }
+}
+
+/// Get the offset that moves `now` to a boundary of the reference zone's day
+///
+/// The day is the reference zone's local date at `now`, so `Start` moves
+/// backwards and `End` forwards.
+///
+/// # Arguments
+///
+/// * `now` - Current (simulated) UTC time
+/// * `reference` - The reference zone, honouring its `offset_override`
+/// * `boundary` - Which end of the day to move to
+///
+/// # Returns
+///
+/// * `Option<i64>` - Seconds to add to `now`, or None if the zone is invalid
+pub fn day_boundary_offset(
+    now: DateTime<Utc>,
+    reference: &TimezoneConfig,
+    boundary: DayBoundary,
+) -> Option<i64> {
+    let target = local_time_instant(now, reference, boundary.time())?;
+    Some((target - now).num_seconds())
+}
+
+/// Find the next instant at which a timezone's work status flips
+///
+/// While working this is the end of work hours (the last working instant);
+/// otherwise it is the next work start.
+///
+/// # Arguments
+///
+/// * `now` - Current UTC time
+/// * `config` - Timezone configuration with work hours
+///
+/// # Returns
+///
+/// * `Option<DateTime<Utc>>` - The next transition, or None if the zone is
+///   always working or the timezone or work hours are invalid
+pub fn next_work_transition(now: DateTime<Utc>, config: &TimezoneConfig) -> Option<DateTime<Utc>> {
+    if config.always_working {
+        return None;
+    }
+    if !within_work_hours(now, config, false) {
+        return next_work_start(now, config);
+    }
+
+    let local = zone_local_time(now, config)?.naive_local();
+    let (_, end) = containing_shift(config, local, false)?;
+    zone_to_utc(config, end, true)
+}
+
+/// Check whether a zone is within its reminder window before work starts
+///
+/// The window is the zone's `remind_before_minutes` leading up to the next
+/// work start; a zone that is already working is never within it.
+///
+/// # Arguments
+///
+/// * `now` - Current UTC time
+/// * `config` - Timezone configuration with work hours
+///
+/// # Returns
+///
+/// * `bool` - True if work starts within the reminder window, false
+///   otherwise or when reminders are disabled (0)
+pub fn is_within_remind_window(now: DateTime<Utc>, config: &TimezoneConfig) -> bool {
+    if config.remind_before_minutes == 0 || within_work_hours(now, config, false) {
+        return false;
+    }
+    let window = chrono::Duration::minutes(i64::from(config.remind_before_minutes));
+    next_work_transition(now, config).is_some_and(|start| start - now <= window)
+}
+
+/// Get the work status for a timezone
+///
+/// Checks run in the same order as `is_work_hours`: an invalid timezone is
+/// off (even when `always_working` is set), then `always_working` zones are
+/// working regardless of their hours.
+///
+/// # Arguments
+///
+/// * `now` - Current UTC time
+/// * `config` - Timezone configuration with work hours
+/// * `ending_soon_minutes` - Window before the end of work hours reported as
+///   `WorkStatus::EndingSoon` (0 disables it)
+///
+/// # Returns
+///
+/// * `WorkStatus` - The status at `now`
+pub fn work_status(
+    now: DateTime<Utc>,
+    config: &TimezoneConfig,
+    ending_soon_minutes: u32,
+) -> WorkStatus {
+    if zone_local_time(now, config).is_none() {
+        return WorkStatus::Off(OffReason::AfterHours);
+    }
+    if config.always_working {
+        return WorkStatus::Working;
+    }
+    if !config.work_hours.is_valid() {
+        return WorkStatus::InvalidHours;
+    }
+    if let Some(reason) = off_reason(now, config) {
+        return WorkStatus::Off(reason);
+    }
+    if is_in_grace(now, config) {
+        return WorkStatus::Grace;
+    }
+
+    let ending_soon = ending_soon_minutes > 0
+        && next_work_transition(now, config).is_some_and(|end| {
+            end - now <= chrono::Duration::minutes(i64::from(ending_soon_minutes))
+        });
+
+    if ending_soon {
+        WorkStatus::EndingSoon
+    } else {
+        WorkStatus::Working
+    }
+}
+
+/// Get how far a timezone is through its work day
+///
+/// Durations are measured on the zone's local wall clock between
+/// `work_hours.start` and the effective end.
+///
+/// # Arguments
+///
+/// * `now` - Current UTC time
+/// * `config` - Timezone configuration with work hours
+///
+/// # Returns
+///
+/// * `Option<WorkProgress>` - Elapsed and remaining work time, or None if the
+///   zone is outside work hours or invalid
+pub fn work_progress(now: DateTime<Utc>, config: &TimezoneConfig) -> Option<WorkProgress> {
+    if config.always_working || !within_work_hours(now, config, false) {
+        return None;
+    }
+
+    let local = zone_local_time(now, config)?.naive_local();
+    let (start, end) = containing_shift(config, local, false)?;
+    Some(WorkProgress {
+        elapsed: local - start,
+        remaining: end - local,
+    })
+}
+
+/// Get the work time left in a zone's current week
+///
+/// Adds up what remains of the work hours from now until the end of Sunday
+/// on the zone's local calendar, honoring work days and holidays. An
+/// overnight shift still running from yesterday counts, and one starting on
+/// Sunday counts up to midnight. Zones working around the clock count every
+/// remaining hour of the week.
+///
+/// # Arguments
+///
+/// * `now` - Current UTC time
+/// * `config` - Timezone configuration
+///
+/// # Returns
+///
+/// * `Option<chrono::Duration>` - The remaining work time, zero once the
+///   week's last work day is over, or None if the timezone or work hours are
+///   invalid
+pub fn remaining_week_work(
+    now: DateTime<Utc>,
+    config: &TimezoneConfig,
+) -> Option<chrono::Duration> {
+    if !config.always_working && !config.work_hours.is_valid() {
+        return None;
+    }
+    let today = zone_local_time(now, config)?.date_naive();
+    let days_left = 7 - today.weekday().num_days_from_monday();
+    let week_end = today
+        .checked_add_signed(chrono::Duration::days(i64::from(days_left)))
+        .and_then(|monday| zone_to_utc(config, monday.and_time(NaiveTime::MIN), false))
+        .unwrap_or(DateTime::<Utc>::MAX_UTC);
+    let dates = std::iter::once(today.pred_opt())
+        .chain(today.iter_days().take(days_left as usize).map(Some));
+    let remaining = work_windows(config, dates, true)
+        .into_iter()
+        .map(|(start, end)| (end.min(week_end) - start.max(now)).max(chrono::Duration::zero()))
+        .sum();
+    Some(remaining)
+}
+
+/// Get the fraction of a work day that has elapsed in a timezone
+///
+/// The fraction of `work_progress`, so it follows the same rules as
+/// `is_work_hours`: work days, holidays, overnight shifts and
+/// `end_of_minute`. Zones working around the clock have no work day to
+/// measure.
+///
+/// # Arguments
+///
+/// * `now` - Current UTC time
+/// * `config` - Timezone configuration with the work day to measure
+///
+/// # Returns
+///
+/// * `Option<f64>` - From 0.0 at the start to 1.0 at the end, or None outside
+///   the work hours or if the timezone or hours are invalid
+pub fn workday_progress(now: DateTime<Utc>, config: &TimezoneConfig) -> Option<f64> {
+    work_progress(now, config).map(|progress| progress.fraction())
+}
+
+/// Format time difference as a display string
+///
+/// # Arguments
+///
+/// * `diff_hours` - Time difference in hours
+///
+/// # Returns
+///
+/// * `String` - Formatted string like "+8", "-5", or "="
+pub fn format_time_diff(diff_hours: f64) -> String {
+    if diff_hours == 0.0 {
+        "=".to_string()
+    } else if diff_hours > 0.0 {
+        format!("+{diff_hours}")
+    } else {
+        format!("{diff_hours}")
+    }
+}
+
+/// Format a time difference in the given style
+///
+/// # Arguments
+///
+/// * `diff_hours` - Time difference in hours, positive when ahead
+/// * `style` - Numeric ("+8") or words ("8h ahead")
+///
+/// # Returns
+///
+/// * `String` - Formatted string like "+8" / "8h ahead", "-5.5" / "5h 30m behind",
+///   or "=" / "same"
+pub fn format_diff(diff_hours: f64, style: DiffStyle) -> String {
+    if style == DiffStyle::Numeric {
+        return format_time_diff(diff_hours);
+    }
+    let seconds = (diff_hours * 3600.0).round() as i64;
+    let magnitude = if seconds % 3600 == 0 {
+        format!("{}h", seconds.abs() / 3600)
+    } else {
+        format_hours_minutes(seconds)
+    };
+    match seconds.signum() {
+        1 => format!("{magnitude} ahead"),
+        -1 => format!("{magnitude} behind"),
+        _ => "same".to_string(),
+    }
+}
+
+/// Format an invitation sentence phrased in the recipient's timezone
+///
+/// Both sides should be computed for the same UTC instant. Dates are only
+/// mentioned when the two local dates differ.
+///
+/// # Arguments
+///
+/// * `theirs` - Display info for the recipient's timezone
+/// * `mine` - Display info for the sender's timezone
+///
+/// # Returns
+///
+/// * `String` - A sentence like "Let's meet at 15:00 your time (09:00 mine)"
+pub fn format_invite_text(theirs: &TimeDisplayInfo, mine: &TimeDisplayInfo) -> String {
+    if theirs.date == mine.date {
+        format!(
+            "Let's meet at {} your time ({} mine)",
+            theirs.time, mine.time
+        )
+    } else {
+        format!(
+            "Let's meet at {} on {} your time ({} on {} mine)",
+            theirs.time, theirs.date, mine.time, mine.date
+        )
+    }
+}
+
+/// Format the pairwise work hour overlaps as a Markdown planning report
+///
+/// # Arguments
+///
+/// * `now` - Current UTC time, selecting the local dates
+/// * `zones` - The timezones to compare
+///
+/// # Returns
+///
+/// * `String` - A Markdown table with one row per pair of zones, showing the
+///   shared window on both clocks and its length, or "none"
+pub fn format_overlap_report(now: DateTime<Utc>, zones: &[&TimezoneConfig]) -> String {
+    let mut report = String::from(
+        "## Work hour overlaps\n\n| Zones | Overlap | Length |\n| --- | --- | --- |\n",
+    );
+    for pair in pairwise_overlaps(now, zones) {
+        let (zone, other) = (zones[pair.first], zones[pair.second]);
+        let window = pair.window.and_then(|(start, end)| {
+            let range = |config: &TimezoneConfig| {
+                Some(format!(
+                    "{}-{} {}",
+                    zone_local_time(start, config)?.format("%H:%M"),
+                    zone_local_time(end, config)?.format("%H:%M"),
+                    config.name
+                ))
+            };
+            Some((
+                format!("{}, {}", range(zone)?, range(other)?),
+                format_hours_minutes((end - start).num_seconds()),
+            ))
+        });
+        let (overlap, length) = window.unwrap_or_else(|| ("none".to_string(), "-".to_string()));
+        report.push_str(&format!(
+            "| {} / {} | {overlap} | {length} |\n",
+            zone.name, other.name
+        ));
+    }
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+    use crate::config::WorkHours;
+
+    fn create_test_config(timezone: &str) -> TimezoneConfig {
+        TimezoneConfig {
+            name: "Test".to_string(),
+            timezone: timezone.to_string(),
+            work_hours: WorkHours {
                 start: "09:00".to_string(),
                 end: "17:00".to_string(),
+                ..Default::default()
             },
+            ..Default::default()
         }
     }
 
@@ -198,32 +2102,388 @@ mod tests {
     }
 
     #[test]
-    fn test_is_work_hours_invalid_timezone() {
-        let config = create_test_config("Invalid/Timezone");
-        let now = Utc::now();
-        assert!(!is_work_hours(now, &config));
+    fn test_is_work_hours_end_of_minute() {
+        let mut config = create_test_config("UTC");
+        let mid_end_minute = Utc.with_ymd_and_hms(2023, 1, 1, 17, 0, 30).unwrap();
+        let after_end_minute = Utc.with_ymd_and_hms(2023, 1, 1, 17, 1, 0).unwrap();
+
+        // Exact end boundary by default
+        assert!(!is_work_hours(mid_end_minute, &config));
+        assert!(!is_work_hours(after_end_minute, &config));
+
+        // The whole final minute counts as working
+        config.work_hours.end_of_minute = true;
+        assert!(is_work_hours(mid_end_minute, &config));
+        assert!(!is_work_hours(after_end_minute, &config));
+    }
+
+    #[test]
+    fn test_is_work_hours_invalid_timezone() {
+        let config = create_test_config("Invalid/Timezone");
+        let now = Utc::now();
+        assert!(!is_work_hours(now, &config));
+    }
+
+    #[test]
+    fn test_calculate_time_difference() {
+        let now = Utc.with_ymd_and_hms(2023, 6, 1, 12, 0, 0).unwrap();
+        // Shanghai is UTC+8
+        let diff = calculate_time_difference(now, "Asia/Shanghai", 0);
+        assert_eq!(diff, Some(8.0));
+    }
+
+    #[test]
+    fn test_get_timezone_offset() {
+        let now = Utc.with_ymd_and_hms(2023, 6, 1, 12, 0, 0).unwrap();
+        let offset = get_timezone_offset(now, "Asia/Shanghai");
+        assert_eq!(offset, Some(8 * 3600)); // 8 hours in seconds
+    }
+
+    #[test]
+    fn test_get_timezone_offset_follows_instant_dst() {
+        // The instant's own rules apply, whatever today's DST state is
+        let summer = Utc.with_ymd_and_hms(2030, 7, 15, 12, 0, 0).unwrap();
+        let winter = Utc.with_ymd_and_hms(2030, 1, 15, 12, 0, 0).unwrap();
+        assert_eq!(
+            get_timezone_offset(summer, "America/New_York"),
+            Some(-4 * 3600)
+        );
+        assert_eq!(
+            get_timezone_offset(winter, "America/New_York"),
+            Some(-5 * 3600)
+        );
+        assert_eq!(get_timezone_offset(summer, "Europe/Berlin"), Some(2 * 3600));
+        assert_eq!(get_timezone_offset(winter, "Europe/Berlin"), Some(3600));
+        // Southern hemisphere: DST in January
+        assert_eq!(
+            get_timezone_offset(winter, "Australia/Sydney"),
+            Some(11 * 3600)
+        );
+        assert_eq!(
+            get_timezone_offset(summer, "Australia/Sydney"),
+            Some(10 * 3600)
+        );
+
+        // Either side of the 2023 US spring-forward (07:00 UTC on March 12)
+        let before = Utc.with_ymd_and_hms(2023, 3, 12, 6, 59, 0).unwrap();
+        let after = Utc.with_ymd_and_hms(2023, 3, 12, 7, 0, 0).unwrap();
+        assert_eq!(
+            get_timezone_offset(before, "America/New_York"),
+            Some(-5 * 3600)
+        );
+        assert_eq!(
+            get_timezone_offset(after, "America/New_York"),
+            Some(-4 * 3600)
+        );
+    }
+
+    #[test]
+    fn test_offset_difference() {
+        let now = Utc.with_ymd_and_hms(2023, 6, 1, 12, 0, 0).unwrap();
+        // Tokyo is UTC+9, London is on BST (UTC+1)
+        assert_eq!(
+            offset_difference(now, "Asia/Tokyo", "Europe/London"),
+            Some(8 * 3600)
+        );
+        assert_eq!(
+            offset_difference(now, "Europe/London", "Asia/Tokyo"),
+            Some(-8 * 3600)
+        );
+        assert_eq!(offset_difference(now, "Asia/Tokyo", "Asia/Tokyo"), Some(0));
+        assert_eq!(offset_difference(now, "Invalid/Zone", "Asia/Tokyo"), None);
+    }
+
+    #[test]
+    fn test_offset_override() {
+        // 13:00 in London (BST), but the override puts the zone at UTC+5
+        let now = Utc.with_ymd_and_hms(2023, 6, 1, 12, 0, 0).unwrap();
+        let config = TimezoneConfig {
+            offset_override: Some(5 * 3600),
+            ..create_test_config("Europe/London")
+        };
+
+        assert_eq!(zone_offset(now, &config), Some(5 * 3600));
+        let info = get_time_display_info(now, &config, 3600, DisplayOptions::default()).unwrap();
+        assert_eq!(info.time, "17:00");
+        assert_eq!(info.diff_hours, 4.0);
+        assert!(is_work_hours(now, &config));
+
+        // Work starts at 09:00 UTC+5 the next day
+        let evening = Utc.with_ymd_and_hms(2023, 6, 1, 13, 0, 0).unwrap();
+        assert!(!is_work_hours(evening, &config));
+        assert_eq!(
+            next_work_start(evening, &config),
+            Some(Utc.with_ymd_and_hms(2023, 6, 2, 4, 0, 0).unwrap())
+        );
+
+        let invalid = TimezoneConfig {
+            offset_override: Some(30 * 3600),
+            ..create_test_config("Europe/London")
+        };
+        assert_eq!(zone_offset(now, &invalid), None);
+        assert!(!is_work_hours(now, &invalid));
+    }
+
+    #[test]
+    fn test_earliest_and_latest_zone() {
+        let now = Utc.with_ymd_and_hms(2023, 6, 1, 12, 0, 0).unwrap();
+        let zones = [
+            create_test_config("Invalid/Zone"),
+            create_test_config("Europe/London"),
+            create_test_config("America/Los_Angeles"),
+            create_test_config("Asia/Tokyo"),
+            create_test_config("America/Vancouver"),
+        ];
+        // Los Angeles and Vancouver tie on UTC-7; the first one wins
+        assert_eq!(earliest_zone(now, &zones), Some(2));
+        assert_eq!(latest_zone(now, &zones), Some(3));
+
+        assert_eq!(earliest_zone(now, &zones[..1]), None);
+        assert_eq!(latest_zone(now, &[]), None);
+    }
+
+    #[test]
+    fn test_work_statuses() {
+        // 13:00 in London, 21:00 in Tokyo, 05:00 in Los Angeles
+        let now = Utc.with_ymd_and_hms(2023, 6, 1, 12, 0, 0).unwrap();
+        let zones = [
+            create_test_config("Europe/London"),
+            create_test_config("Asia/Tokyo"),
+            create_test_config("Invalid/Zone"),
+            TimezoneConfig {
+                always_working: true,
+                ..create_test_config("America/Los_Angeles")
+            },
+            create_test_config("America/Los_Angeles"),
+            // 15:00 on the override's clock, whatever the identifier says
+            TimezoneConfig {
+                offset_override: Some(3 * 3600),
+                ..create_test_config("Invalid/Zone")
+            },
+        ];
+        assert_eq!(
+            work_statuses(now, &zones),
+            vec![true, false, false, true, false, true]
+        );
+        for (zone, working) in zones.iter().zip(work_statuses(now, &zones)) {
+            assert_eq!(is_work_hours(now, zone), working, "{}", zone.timezone);
+        }
+        assert!(work_statuses(now, &[]).is_empty());
+    }
+
+    #[test]
+    fn test_offset_matrix() {
+        let now = Utc.with_ymd_and_hms(2023, 6, 1, 12, 0, 0).unwrap();
+        let london = create_test_config("Europe/London");
+        let tokyo = create_test_config("Asia/Tokyo");
+        let kolkata = create_test_config("Asia/Kolkata");
+        let hours = |h: f64| Some((h * 3600.0) as i32);
+
+        let matrix = offset_matrix(now, &[&london, &tokyo, &kolkata]);
+        assert_eq!(
+            matrix,
+            vec![
+                vec![Some(0), hours(8.0), hours(4.5)],
+                vec![hours(-8.0), Some(0), hours(-3.5)],
+                vec![hours(-4.5), hours(3.5), Some(0)],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_week_availability() {
+        // Thursday 2023-06-01, 12:00 UTC is 13:00 in London and 21:00 in Tokyo
+        let utc = create_test_config("UTC");
+        let first_day = NaiveDate::from_ymd_opt(2023, 6, 1).unwrap();
+        let noon = NaiveTime::from_hms_opt(12, 0, 0).unwrap();
+        let mut london = create_test_config("Europe/London");
+        london.work_days = crate::config::ALL_WEEKDAYS[..5].to_vec();
+        london.holidays = vec![NaiveDate::from_ymd_opt(2023, 6, 5).unwrap()];
+        let tokyo = create_test_config("Asia/Tokyo");
+        let invalid = create_test_config("Invalid/Zone");
+
+        let week = week_availability(&utc, first_day, noon, &[&london, &tokyo, &invalid]);
+        // Thu, Fri, Sat, Sun, Mon (holiday), Tue, Wed
+        assert_eq!(week[0], [true, true, false, false, false, true, true]);
+        assert_eq!(week[1], [false; PLANNING_DAYS]);
+        assert_eq!(week[2], [false; PLANNING_DAYS]);
+
+        // A 16:30 meeting in New York stays at 16:30 there across the DST
+        // change on Sunday 2023-03-12, so it moves from 21:30 to 20:30 in
+        // London and enters London's 09:00-21:00 hours
+        let new_york = create_test_config("America/New_York");
+        let mut late_london = create_test_config("Europe/London");
+        late_london.work_hours.end = "21:00".to_string();
+        let week = week_availability(
+            &new_york,
+            NaiveDate::from_ymd_opt(2023, 3, 9).unwrap(),
+            NaiveTime::from_hms_opt(16, 30, 0).unwrap(),
+            &[&late_london],
+        );
+        // Thu, Fri, Sat, Sun (DST starts), Mon, Tue, Wed
+        assert_eq!(week[0], [false, false, false, true, true, true, true]);
+    }
+
+    #[test]
+    fn test_distinct_offsets() {
+        let now = Utc.with_ymd_and_hms(2023, 6, 1, 12, 0, 0).unwrap();
+        let zones = [
+            create_test_config("Asia/Tokyo"),
+            create_test_config("Europe/London"),
+            create_test_config("Asia/Seoul"),
+            create_test_config("Invalid/Zone"),
+            create_test_config("Europe/Lisbon"),
+            create_test_config("America/New_York"),
+        ];
+        assert_eq!(distinct_offsets(now, &zones), [-4 * 3600, 3600, 9 * 3600]);
+        assert!(distinct_offsets(now, &[]).is_empty());
     }
 
     #[test]
-    fn test_calculate_time_difference() {
+    fn test_remaining_week_work() {
+        let mut config = create_test_config("America/New_York");
+        config.work_days = crate::config::ALL_WEEKDAYS[..5].to_vec();
+        // Wednesday 2023-06-07 12:00 in New York: 5h today, 8h Thursday and Friday
+        let wednesday_noon = Utc.with_ymd_and_hms(2023, 6, 7, 16, 0, 0).unwrap();
+        assert_eq!(
+            remaining_week_work(wednesday_noon, &config),
+            Some(chrono::Duration::hours(21))
+        );
+
+        // A holiday on Friday leaves 13h
+        config.holidays = vec![NaiveDate::from_ymd_opt(2023, 6, 9).unwrap()];
+        assert_eq!(
+            remaining_week_work(wednesday_noon, &config),
+            Some(chrono::Duration::hours(13))
+        );
+
+        // Saturday: the work week is over
+        let saturday = Utc.with_ymd_and_hms(2023, 6, 10, 16, 0, 0).unwrap();
+        assert_eq!(
+            remaining_week_work(saturday, &config),
+            Some(chrono::Duration::zero())
+        );
+
+        // Around the clock: the rest of Saturday and all of Sunday
+        config.always_working = true;
+        assert_eq!(
+            remaining_week_work(saturday, &config),
+            Some(chrono::Duration::hours(36))
+        );
+
+        config.always_working = false;
+        config.work_hours.start = "9am".to_string();
+        assert_eq!(remaining_week_work(saturday, &config), None);
+
+        // Overnight 22:00-06:00 UTC, Monday to Friday
+        let night = TimezoneConfig {
+            work_hours: WorkHours {
+                start: "22:00".to_string(),
+                end: "06:00".to_string(),
+                ..WorkHours::default()
+            },
+            work_days: crate::config::ALL_WEEKDAYS[..5].to_vec(),
+            ..create_test_config("UTC")
+        };
+        let at = |day, hour| Utc.with_ymd_and_hms(2023, 6, day, hour, 0, 0).unwrap();
+        // Thursday 23:00: 7h left tonight, then Friday night's 8h
+        assert!(is_work_hours(at(8, 23), &night));
+        assert_eq!(
+            remaining_week_work(at(8, 23), &night),
+            Some(chrono::Duration::hours(15))
+        );
+        // Saturday 02:00: the rest of Friday night's shift
+        assert_eq!(
+            remaining_week_work(at(10, 2), &night),
+            Some(chrono::Duration::hours(4))
+        );
+        // A Sunday night shift only counts until midnight
+        let every_night = TimezoneConfig {
+            work_days: crate::config::ALL_WEEKDAYS.to_vec(),
+            ..night
+        };
+        assert_eq!(
+            remaining_week_work(at(11, 12), &every_night),
+            Some(chrono::Duration::hours(2))
+        );
+    }
+
+    #[test]
+    fn test_reference_offset_and_label() {
         let now = Utc.with_ymd_and_hms(2023, 6, 1, 12, 0, 0).unwrap();
-        // Shanghai is UTC+8
-        let diff = calculate_time_difference(now, "Asia/Shanghai", 0);
-        assert_eq!(diff, Some(8.0));
+        let tokyo = create_test_config("Asia/Tokyo");
+        let mut config = Config::default();
+
+        assert_eq!(reference_offset(now, &config, Some(&tokyo)), 9 * 3600);
+        assert_eq!(reference_offset(now, &config, None), 0);
+        assert_eq!(diff_header_label(&config), "Diff (vs selected)");
+
+        config.home_timezone = Some("Europe/London".to_string());
+        assert_eq!(reference_offset(now, &config, Some(&tokyo)), 3600);
+        assert_eq!(diff_header_label(&config), "Diff (vs London)");
+
+        config.home_timezone = Some("America/Los_Angeles".to_string());
+        assert_eq!(diff_header_label(&config), "Diff (vs Los Angeles)");
+
+        config.home_timezone = Some("Invalid/Zone".to_string());
+        assert_eq!(reference_offset(now, &config, Some(&tokyo)), 9 * 3600);
+        assert_eq!(diff_header_label(&config), "Diff (vs selected)");
     }
 
     #[test]
-    fn test_get_timezone_offset() {
+    fn test_diff_baselines() {
         let now = Utc.with_ymd_and_hms(2023, 6, 1, 12, 0, 0).unwrap();
-        let offset = get_timezone_offset(now, "Asia/Shanghai");
-        assert_eq!(offset, Some(8 * 3600)); // 8 hours in seconds
+        let tokyo = create_test_config("Asia/Tokyo");
+        let mut config = Config {
+            home_timezone: Some("Europe/London".to_string()),
+            ..Default::default()
+        };
+
+        config.diff_baseline = Some(DiffBaseline::Home);
+        assert_eq!(reference_offset(now, &config, Some(&tokyo)), 3600);
+        assert_eq!(diff_header_label(&config), "Diff (vs London)");
+        assert_eq!(reference_name(&config, Some(&tokyo), false), "London");
+        assert_eq!(reference_name(&config, Some(&tokyo), true), "UTC");
+
+        config.diff_baseline = Some(DiffBaseline::Selected);
+        assert_eq!(reference_offset(now, &config, Some(&tokyo)), 9 * 3600);
+        assert_eq!(reference_offset(now, &config, None), 0);
+        assert_eq!(diff_header_label(&config), "Diff (vs selected)");
+        assert_eq!(reference_name(&config, Some(&tokyo), false), "Test");
+        assert_eq!(reference_name(&config, None, false), "UTC");
+
+        config.diff_baseline = Some(DiffBaseline::Local);
+        let local = baseline_zone(&config, Some(&tokyo), -5 * 3600).unwrap();
+        assert_eq!(local.name, "Local");
+        assert_eq!(zone_offset(now, &local), Some(-5 * 3600));
+        assert_eq!(
+            baseline_zone(&config, None, 5 * 3600).and_then(|zone| zone_offset(now, &zone)),
+            Some(5 * 3600)
+        );
+        assert_eq!(diff_header_label(&config), "Diff (vs Local)");
+
+        // The home baseline falls back to the selected zone without a home
+        config.diff_baseline = Some(DiffBaseline::Home);
+        assert_eq!(
+            reference_zone(now, &config, Some(&tokyo)).map(|zone| zone.name),
+            Some("London".to_string())
+        );
+        config.home_timezone = None;
+        assert_eq!(
+            reference_zone(now, &config, Some(&tokyo)).map(|zone| zone.timezone),
+            Some("Asia/Tokyo".to_string())
+        );
+        assert_eq!(reference_zone(now, &config, None), None);
+        config.home_timezone = Some("Europe/London".to_string());
+        config.diff_baseline = Some(DiffBaseline::Local);
+        assert_eq!(diff_header_label(&config), "Diff (vs Local)");
     }
 
     #[test]
     fn test_get_time_display_info() {
         let now = Utc.with_ymd_and_hms(2023, 6, 1, 4, 0, 0).unwrap(); // 4:00 UTC = 12:00 Shanghai
         let config = create_test_config("Asia/Shanghai");
-        let info = get_time_display_info(now, &config, 0, false);
+        let info = get_time_display_info(now, &config, 0, DisplayOptions::default());
 
         assert!(info.is_some());
         let info = info.unwrap();
@@ -233,6 +2493,153 @@ mod tests {
         assert!(info.is_working); // 12:00 is within 09:00-17:00
     }
 
+    #[test]
+    fn test_get_time_display_info_format_override() {
+        let now = Utc.with_ymd_and_hms(2023, 6, 1, 14, 30, 0).unwrap();
+        let mut us = create_test_config("America/New_York");
+        us.use_12h_format = Some(true);
+        let mut eu = create_test_config("Europe/Berlin");
+        eu.use_12h_format = Some(false);
+        let global = create_test_config("UTC");
+
+        // Global 24h: the US zone still renders 12h
+        let h24 = DisplayOptions::default();
+        let h12 = DisplayOptions {
+            use_12h_format: true,
+            ..Default::default()
+        };
+        let us_info = get_time_display_info(now, &us, 0, h24).unwrap();
+        let eu_info = get_time_display_info(now, &eu, 0, h24).unwrap();
+        assert_eq!(us_info.time, "10:30 AM");
+        assert_eq!(eu_info.time, "16:30");
+
+        // Global 12h: the EU zone still renders 24h, unset zones follow the global flag
+        let eu_info = get_time_display_info(now, &eu, 0, h12).unwrap();
+        let global_info = get_time_display_info(now, &global, 0, h12).unwrap();
+        assert_eq!(eu_info.time, "16:30");
+        assert_eq!(global_info.time, "02:30 PM");
+    }
+
+    #[test]
+    fn test_compact_12h() {
+        let now = Utc.with_ymd_and_hms(2023, 6, 1, 9, 5, 0).unwrap();
+        let config = create_test_config("UTC");
+        let padded = DisplayOptions {
+            use_12h_format: true,
+            ..Default::default()
+        };
+        let compact = DisplayOptions {
+            compact_12h: true,
+            ..padded
+        };
+
+        let info = get_time_display_info(now, &config, 0, compact).unwrap();
+        assert_eq!(info.time, "9:05 AM");
+        let info = get_time_display_info(now, &config, 0, padded).unwrap();
+        assert_eq!(info.time, "09:05 AM");
+
+        // Two-digit hours and 24-hour times are unaffected
+        let later = Utc.with_ymd_and_hms(2023, 6, 1, 22, 0, 0).unwrap();
+        let info = get_time_display_info(later, &config, 0, compact).unwrap();
+        assert_eq!(info.time, "10:00 PM");
+        let h24 = DisplayOptions {
+            use_12h_format: false,
+            ..compact
+        };
+        let info = get_time_display_info(now, &config, 0, h24).unwrap();
+        assert_eq!(info.time, "09:05");
+    }
+
+    #[test]
+    fn test_get_time_display_info_rounding() {
+        let config = create_test_config("UTC");
+        let options = DisplayOptions {
+            round_minutes: Some(5),
+            ..Default::default()
+        };
+        let time = |minute| {
+            let now = Utc.with_ymd_and_hms(2023, 6, 1, 9, minute, 0).unwrap();
+            get_time_display_info(now, &config, 0, options)
+                .unwrap()
+                .time
+        };
+
+        assert_eq!(time(13), "09:15");
+        assert_eq!(time(7), "09:05");
+        assert_eq!(time(10), "09:10");
+
+        // Rounding can roll over to the next day
+        let late = Utc.with_ymd_and_hms(2023, 6, 1, 23, 58, 0).unwrap();
+        let info = get_time_display_info(late, &config, 0, options).unwrap();
+        assert_eq!(info.time, "00:00");
+        assert_eq!(info.date, "2023-06-02");
+    }
+
+    #[test]
+    fn test_get_time_display_info_date_styles() {
+        let now = Utc.with_ymd_and_hms(2023, 6, 1, 12, 0, 0).unwrap();
+        let config = create_test_config("UTC");
+        let date = |date_style| {
+            let options = DisplayOptions {
+                date_style,
+                ..Default::default()
+            };
+            get_time_display_info(now, &config, 0, options)
+                .unwrap()
+                .date
+        };
+
+        assert_eq!(date(DateStyle::Iso), "2023-06-01");
+        assert_eq!(date(DateStyle::Us), "06/01/2023");
+        assert_eq!(date(DateStyle::Eu), "01/06/2023");
+        assert_eq!(date(DateStyle::Long), "Jun 1");
+    }
+
+    #[test]
+    fn test_format_time_fixed_width() {
+        let morning = Utc.with_ymd_and_hms(2023, 6, 1, 9, 5, 0).unwrap();
+        let evening = Utc.with_ymd_and_hms(2023, 6, 1, 23, 45, 0).unwrap();
+        let h24 = DisplayOptions::default();
+        let h12 = DisplayOptions {
+            use_12h_format: true,
+            ..Default::default()
+        };
+
+        // Hours are zero-padded so times line up across zones
+        assert_eq!(h12.format_time(&morning), "09:05 AM");
+        assert_eq!(h12.format_time(&evening), "11:45 PM");
+        assert_eq!(h24.format_time(&morning), "09:05");
+        assert_eq!(h24.format_time(&evening), "23:45");
+    }
+
+    #[test]
+    fn test_format_work_hours() {
+        let hours = |start: &str, end: &str| WorkHours {
+            start: start.to_string(),
+            end: end.to_string(),
+            ..Default::default()
+        };
+        let h24 = DisplayOptions::default();
+        let h12 = DisplayOptions {
+            use_12h_format: true,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            h24.format_work_hours(&hours("09:00", "17:00")).as_deref(),
+            Some("09:00–17:00")
+        );
+        assert_eq!(
+            h12.format_work_hours(&hours("09:00", "17:30")).as_deref(),
+            Some("09:00 AM–05:30 PM")
+        );
+        assert_eq!(
+            h24.format_work_hours(&hours("22:00", "06:00")).as_deref(),
+            Some("22:00→06:00")
+        );
+        assert_eq!(h24.format_work_hours(&hours("9am", "17:00")), None);
+    }
+
     #[test]
     fn test_format_time_diff() {
         assert_eq!(format_time_diff(0.0), "=");
@@ -240,4 +2647,963 @@ mod tests {
         assert_eq!(format_time_diff(-5.0), "-5");
         assert_eq!(format_time_diff(5.5), "+5.5");
     }
+
+    #[test]
+    fn test_format_diff() {
+        assert_eq!(format_diff(8.0, DiffStyle::Words), "8h ahead");
+        assert_eq!(format_diff(-5.0, DiffStyle::Words), "5h behind");
+        assert_eq!(format_diff(0.0, DiffStyle::Words), "same");
+        assert_eq!(format_diff(-5.5, DiffStyle::Words), "5h 30m behind");
+        assert_eq!(format_diff(8.0, DiffStyle::Numeric), "+8");
+        assert_eq!(format_diff(0.0, DiffStyle::Numeric), "=");
+    }
+
+    #[test]
+    fn test_format_invite_text() {
+        let now = Utc.with_ymd_and_hms(2023, 6, 1, 7, 0, 0).unwrap();
+        let options = DisplayOptions::default();
+        let theirs =
+            get_time_display_info(now, &create_test_config("Asia/Shanghai"), 0, options).unwrap();
+        let mine = get_time_display_info(now, &create_test_config("UTC"), 0, options).unwrap();
+        assert_eq!(
+            format_invite_text(&theirs, &mine),
+            "Let's meet at 15:00 your time (07:00 mine)"
+        );
+
+        // Dates are spelled out when the local days differ
+        let now = Utc.with_ymd_and_hms(2023, 6, 1, 20, 0, 0).unwrap();
+        let theirs =
+            get_time_display_info(now, &create_test_config("Asia/Tokyo"), 0, options).unwrap();
+        let mine = get_time_display_info(now, &create_test_config("UTC"), 0, options).unwrap();
+        assert_eq!(
+            format_invite_text(&theirs, &mine),
+            "Let's meet at 05:00 on 2023-06-02 your time (20:00 on 2023-06-01 mine)"
+        );
+    }
+
+    #[test]
+    fn test_day_phase() {
+        let at = |hour| Utc.with_ymd_and_hms(2023, 6, 1, hour, 0, 0).unwrap();
+        assert_eq!(day_phase(at(3), "UTC"), Some(DayPhase::Sleeping));
+        assert_eq!(day_phase(at(8), "UTC"), Some(DayPhase::Morning));
+        assert_eq!(day_phase(at(14), "UTC"), Some(DayPhase::Working));
+        assert_eq!(day_phase(at(20), "UTC"), Some(DayPhase::Evening));
+        assert_eq!(day_phase(at(23), "UTC"), Some(DayPhase::Late));
+
+        // Uses the local hour: 06:00 UTC is 14:00 in Shanghai
+        assert_eq!(day_phase(at(6), "Asia/Shanghai"), Some(DayPhase::Working));
+        assert_eq!(day_phase(at(6), "Invalid/Timezone"), None);
+    }
+
+    #[test]
+    fn test_local_date() {
+        let now = Utc.with_ymd_and_hms(2023, 6, 1, 20, 0, 0).unwrap();
+        assert_eq!(
+            local_date(now, "Asia/Tokyo"),
+            NaiveDate::from_ymd_opt(2023, 6, 2)
+        );
+        assert_eq!(
+            local_date(now, "America/New_York"),
+            NaiveDate::from_ymd_opt(2023, 6, 1)
+        );
+        assert_eq!(local_date(now, "Invalid/Timezone"), None);
+    }
+
+    #[test]
+    fn test_same_local_date_across_date_line() {
+        // 2023-06-01 12:00 UTC is 00:00 on 2 June in Auckland and 02:00 on 1 June in Honolulu
+        let now = Utc.with_ymd_and_hms(2023, 6, 1, 12, 0, 0).unwrap();
+        assert_eq!(
+            same_local_date(now, "Pacific/Auckland", "Pacific/Honolulu"),
+            Some(false)
+        );
+        // At 10:30 UTC it is 22:30 in Auckland and 00:30 in Honolulu, both on 1 June
+        let earlier = Utc.with_ymd_and_hms(2023, 6, 1, 10, 30, 0).unwrap();
+        assert_eq!(
+            same_local_date(earlier, "Pacific/Auckland", "Pacific/Honolulu"),
+            Some(true)
+        );
+    }
+
+    #[test]
+    fn test_same_local_date_within_region() {
+        let now = Utc.with_ymd_and_hms(2023, 6, 1, 22, 30, 0).unwrap();
+        // 23:30 in London, 00:30 the next day in Paris
+        assert_eq!(
+            same_local_date(now, "Europe/London", "Europe/Paris"),
+            Some(false)
+        );
+        assert_eq!(
+            same_local_date(now, "Europe/Paris", "Europe/Berlin"),
+            Some(true)
+        );
+        assert_eq!(same_local_date(now, "Europe/Paris", "Invalid/Zone"), None);
+    }
+
+    #[test]
+    fn test_sort_by_offset() {
+        let now = Utc.with_ymd_and_hms(2023, 6, 1, 12, 0, 0).unwrap();
+        let mut zones = vec![
+            create_test_config("Asia/Tokyo"),
+            create_test_config("Invalid/Timezone"),
+            create_test_config("America/New_York"),
+            create_test_config("UTC"),
+        ];
+        sort_by_offset(now, &mut zones, |tz| tz);
+        let order: Vec<_> = zones.iter().map(|tz| tz.timezone.as_str()).collect();
+        assert_eq!(
+            order,
+            ["America/New_York", "UTC", "Asia/Tokyo", "Invalid/Timezone"]
+        );
+    }
+
+    #[test]
+    fn test_local_to_utc() {
+        let date = |m, d| NaiveDate::from_ymd_opt(2024, m, d).unwrap();
+        let time = |h, m| NaiveTime::from_hms_opt(h, m, 0).unwrap();
+
+        // 09:00 EDT (UTC-4)
+        assert_eq!(
+            local_to_utc(date(6, 3), time(9, 0), "America/New_York"),
+            Some(Utc.with_ymd_and_hms(2024, 6, 3, 13, 0, 0).unwrap())
+        );
+        // Clocks jump from 02:00 to 03:00 on March 10, so 02:30 never happens
+        assert_eq!(
+            local_to_utc(date(3, 10), time(2, 30), "America/New_York"),
+            None
+        );
+        // 01:30 happens twice on November 3; the first one is still on EDT
+        assert_eq!(
+            local_to_utc(date(11, 3), time(1, 30), "America/New_York"),
+            Some(Utc.with_ymd_and_hms(2024, 11, 3, 5, 30, 0).unwrap())
+        );
+        assert_eq!(local_to_utc(date(6, 3), time(9, 0), "Invalid/Zone"), None);
+    }
+
+    #[test]
+    fn test_group_by_status() {
+        // 13:00 in London, 21:00 in Tokyo, 08:00 in New York, 20:00 in Shanghai
+        let now = Utc.with_ymd_and_hms(2023, 6, 1, 12, 0, 0).unwrap();
+        let mut zones = vec![
+            create_test_config("Asia/Tokyo"),
+            create_test_config("Europe/London"),
+            create_test_config("America/New_York"),
+            create_test_config("UTC"),
+            create_test_config("Asia/Shanghai"),
+        ];
+        assert_eq!(group_by_status(now, &mut zones, |tz| tz), 2);
+        let order: Vec<_> = zones.iter().map(|tz| tz.timezone.as_str()).collect();
+        assert_eq!(
+            order,
+            [
+                "Europe/London",
+                "UTC",
+                "Asia/Tokyo",
+                "America/New_York",
+                "Asia/Shanghai"
+            ]
+        );
+
+        let mut single = vec![create_test_config("UTC")];
+        assert_eq!(group_by_status(now, &mut single, |tz| tz), 1);
+        assert_eq!(group_by_status(now, &mut [], |tz: &TimezoneConfig| tz), 0);
+    }
+
+    #[test]
+    fn test_sort_by_offset_breaks_ties_by_name() {
+        let now = Utc.with_ymd_and_hms(2023, 6, 1, 12, 0, 0).unwrap();
+        let named = |name: &str, timezone: &str| TimezoneConfig {
+            name: name.to_string(),
+            ..create_test_config(timezone)
+        };
+        // All three are UTC+8
+        let mut zones = vec![
+            named("Singapore", "Asia/Singapore"),
+            named("Tokyo", "Asia/Tokyo"),
+            named("perth", "Australia/Perth"),
+        ];
+        sort_by_offset(now, &mut zones, |tz| tz);
+        let order: Vec<_> = zones.iter().map(|tz| tz.name.as_str()).collect();
+        assert_eq!(order, ["perth", "Singapore", "Tokyo"]);
+    }
+
+    #[test]
+    fn test_score_meeting_hours() {
+        let now = Utc.with_ymd_and_hms(2023, 6, 1, 12, 0, 0).unwrap();
+        // 09:00-15:00 BST is 08:00-14:00 UTC, 09:00-17:00 EDT is 13:00-21:00 UTC
+        let mut london = create_test_config("Europe/London");
+        london.work_hours.end = "15:00".to_string();
+        let new_york = create_test_config("America/New_York");
+        let zones = [london, new_york, create_test_config("Invalid/Zone")];
+
+        let scores = score_meeting_hours(now, &zones);
+        let best = (0..24).max_by(|a, b| scores[*a].total_cmp(&scores[*b]));
+        assert_eq!(best, Some(13));
+        assert_eq!(scores[13], 1.0);
+        // London just finished, New York at work
+        assert_eq!(scores[14], 0.75);
+        // 04:30 in London, 23:30 in New York
+        assert_eq!(scores[3], 0.05);
+        assert!(scores.iter().all(|score| (0.0..=1.0).contains(score)));
+
+        assert_eq!(score_meeting_hours(now, &[]), [0.0; 24]);
+    }
+
+    #[test]
+    fn test_work_overlap_by_hour() {
+        let now = Utc.with_ymd_and_hms(2023, 6, 1, 12, 0, 0).unwrap();
+        let london = create_test_config("Europe/London");
+        let new_york = create_test_config("America/New_York");
+
+        // 09:00-17:00 EDT is 14:00-22:00 BST
+        let overlap = work_overlap_by_hour(now, &london, &new_york).unwrap();
+        let hours: Vec<_> = (0..24).filter(|h| overlap[*h]).collect();
+        assert_eq!(hours, [14, 15, 16]);
+
+        // The zone's own work hours include the overlap
+        let own = work_by_hour(now, &london).unwrap();
+        let hours: Vec<_> = (0..24).filter(|h| own[*h]).collect();
+        assert_eq!(hours, [9, 10, 11, 12, 13, 14, 15, 16]);
+        assert!(overlap.iter().zip(own).all(|(shared, own)| !shared || own));
+
+        // A night shift (22:00-06:00 UTC) meets Tokyo's 09:00-17:00
+        // (00:00-08:00 UTC) after midnight
+        let night = TimezoneConfig {
+            work_hours: WorkHours {
+                start: "22:00".to_string(),
+                end: "06:00".to_string(),
+                ..WorkHours::default()
+            },
+            ..create_test_config("UTC")
+        };
+        let tokyo = create_test_config("Asia/Tokyo");
+        let at = |day, hour| Utc.with_ymd_and_hms(2023, 6, day, hour, 0, 0).unwrap();
+        let overlap = work_overlap_by_hour(now, &night, &tokyo).unwrap();
+        let hours: Vec<_> = (0..24).filter(|h| overlap[*h]).collect();
+        assert_eq!(hours, [0, 1, 2, 3, 4, 5]);
+        assert_eq!(
+            work_overlap(now, &night, &tokyo),
+            Some((at(2, 0), at(2, 6)))
+        );
+        let report = format_overlap_report(now, &[&night, &tokyo]);
+        assert!(
+            report.contains("| 00:00-06:00 Test, 09:00-15:00 Test | 6h 0m |"),
+            "{report}"
+        );
+
+        let invalid = create_test_config("Invalid/Timezone");
+        assert_eq!(work_by_hour(now, &invalid), None);
+        assert_eq!(work_overlap_by_hour(now, &invalid, &london), None);
+        assert_eq!(
+            work_overlap_by_hour(now, &london, &invalid),
+            Some([false; 24])
+        );
+    }
+
+    #[test]
+    fn test_home_zone() {
+        let mut config = Config::default();
+        assert_eq!(home_zone(&config), None);
+
+        config.home_timezone = Some("Europe/London".to_string());
+        let home = home_zone(&config).unwrap();
+        assert_eq!(home.name, "London");
+        assert_eq!(home.work_hours.end, "17:30");
+
+        // A muted zone's settings are not used
+        let london = config
+            .timezones
+            .iter_mut()
+            .find(|tz| tz.timezone == "Europe/London")
+            .unwrap();
+        london.enabled = false;
+        london.name = "HQ".to_string();
+        let home = home_zone(&config).unwrap();
+        assert_eq!(home.name, "London");
+        assert_eq!(home.work_hours, WorkHours::default());
+
+        config.home_timezone = Some("Asia/Tokyo".to_string());
+        let home = home_zone(&config).unwrap();
+        assert_eq!(home.name, "Tokyo");
+        assert_eq!(home.work_hours, WorkHours::default());
+    }
+
+    #[test]
+    fn test_local_time_instant() {
+        // 09:00 in Tokyo (UTC+9)
+        let now = Utc.with_ymd_and_hms(2023, 6, 1, 0, 0, 0).unwrap();
+        let noon = NaiveTime::from_hms_opt(12, 0, 0).unwrap();
+        let tokyo = create_test_config("Asia/Tokyo");
+        let new_york = create_test_config("America/New_York");
+        assert_eq!(
+            local_time_instant(now, &tokyo, noon),
+            Some(Utc.with_ymd_and_hms(2023, 6, 1, 3, 0, 0).unwrap())
+        );
+
+        // Still May 31 in New York (UTC-4)
+        assert_eq!(
+            local_time_instant(now, &new_york, noon),
+            Some(Utc.with_ymd_and_hms(2023, 5, 31, 16, 0, 0).unwrap())
+        );
+        let out_of_range = TimezoneConfig {
+            offset_override: Some(30 * 3600),
+            ..create_test_config("UTC")
+        };
+        assert_eq!(local_time_instant(now, &out_of_range, noon), None);
+
+        // Midnight in New York on 2023-03-12 is still UTC-5, but clocks
+        // spring forward at 02:00, so noon that day is on UTC-4
+        let before_change = Utc.with_ymd_and_hms(2023, 3, 12, 5, 0, 0).unwrap();
+        assert_eq!(
+            local_time_instant(before_change, &new_york, noon),
+            Some(Utc.with_ymd_and_hms(2023, 3, 12, 16, 0, 0).unwrap())
+        );
+        // 02:30 does not exist that day
+        let skipped = NaiveTime::from_hms_opt(2, 30, 0).unwrap();
+        assert_eq!(local_time_instant(before_change, &new_york, skipped), None);
+    }
+
+    #[test]
+    fn test_day_boundary_offset() {
+        // 10:30 on June 1 in Tokyo (UTC+9)
+        let now = Utc.with_ymd_and_hms(2023, 6, 1, 1, 30, 0).unwrap();
+        let tokyo = create_test_config("Asia/Tokyo");
+        let new_york = create_test_config("America/New_York");
+        let out_of_range = TimezoneConfig {
+            offset_override: Some(30 * 3600),
+            ..create_test_config("UTC")
+        };
+        assert_eq!(
+            day_boundary_offset(now, &tokyo, DayBoundary::Start),
+            Some(-(10 * 3600 + 30 * 60))
+        );
+        assert_eq!(
+            day_boundary_offset(now, &tokyo, DayBoundary::End),
+            Some(13 * 3600 + 29 * 60)
+        );
+
+        // Still May 31 (21:30) in New York (UTC-4)
+        assert_eq!(
+            day_boundary_offset(now, &new_york, DayBoundary::Start),
+            Some(-(21 * 3600 + 30 * 60))
+        );
+        assert_eq!(
+            day_boundary_offset(now, &out_of_range, DayBoundary::Start),
+            None
+        );
+    }
+
+    #[test]
+    fn test_always_working_zone() {
+        // 03:00 on a Sunday in London
+        let now = Utc.with_ymd_and_hms(2024, 1, 7, 3, 0, 0).unwrap();
+        let mut config = create_test_config("Europe/London");
+        config.work_days = vec![chrono::Weekday::Mon, chrono::Weekday::Tue];
+        assert!(!is_work_hours(now, &config));
+
+        config.always_working = true;
+        assert!(is_work_hours(now, &config));
+        assert_eq!(work_status(now, &config, 30), WorkStatus::Working);
+        assert_eq!(off_reason(now, &config), None);
+        assert_eq!(next_work_transition(now, &config), None);
+
+        // An invalid timezone is off in both checks, even around the clock
+        let invalid = TimezoneConfig {
+            always_working: true,
+            ..create_test_config("Invalid/Timezone")
+        };
+        assert!(!is_work_hours(now, &invalid));
+        assert_eq!(
+            work_status(now, &invalid, 30),
+            WorkStatus::Off(OffReason::AfterHours)
+        );
+        assert_eq!(work_statuses(now, &[invalid]), [false]);
+    }
+
+    #[test]
+    fn test_next_work_start() {
+        // 20:00 in Shanghai: next start is tomorrow 09:00 local (01:00 UTC)
+        let now = Utc.with_ymd_and_hms(2023, 6, 1, 12, 0, 0).unwrap();
+        let config = create_test_config("Asia/Shanghai");
+        assert_eq!(
+            next_work_start(now, &config),
+            Some(Utc.with_ymd_and_hms(2023, 6, 2, 1, 0, 0).unwrap())
+        );
+
+        // Before the start: later the same day
+        let now = Utc.with_ymd_and_hms(2023, 6, 1, 8, 0, 0).unwrap();
+        let config = create_test_config("UTC");
+        assert_eq!(
+            next_work_start(now, &config),
+            Some(Utc.with_ymd_and_hms(2023, 6, 1, 9, 0, 0).unwrap())
+        );
+
+        // Exactly at the start: the next day's start
+        let now = Utc.with_ymd_and_hms(2023, 6, 1, 9, 0, 0).unwrap();
+        assert_eq!(
+            next_work_start(now, &config),
+            Some(Utc.with_ymd_and_hms(2023, 6, 2, 9, 0, 0).unwrap())
+        );
+
+        assert_eq!(
+            next_work_start(now, &create_test_config("Invalid/Timezone")),
+            None
+        );
+    }
+
+    #[test]
+    fn test_next_work_transition() {
+        let config = create_test_config("UTC");
+
+        // Working: the transition is the end of the day's work hours
+        let now = Utc.with_ymd_and_hms(2023, 6, 1, 12, 0, 0).unwrap();
+        assert_eq!(
+            next_work_transition(now, &config),
+            Some(Utc.with_ymd_and_hms(2023, 6, 1, 17, 0, 0).unwrap())
+        );
+
+        // Off: the transition is the next work start
+        let now = Utc.with_ymd_and_hms(2023, 6, 1, 18, 0, 0).unwrap();
+        assert_eq!(
+            next_work_transition(now, &config),
+            Some(Utc.with_ymd_and_hms(2023, 6, 2, 9, 0, 0).unwrap())
+        );
+    }
+
+    #[test]
+    fn test_work_progress() {
+        let config = create_test_config("UTC");
+
+        let noon = Utc.with_ymd_and_hms(2023, 6, 1, 12, 0, 0).unwrap();
+        let progress = work_progress(noon, &config).unwrap();
+        assert_eq!(progress.elapsed, chrono::Duration::hours(3));
+        assert_eq!(progress.remaining, chrono::Duration::hours(5));
+        assert_eq!(progress.label(), "3h 0m worked / 5h 0m left");
+
+        let evening = Utc.with_ymd_and_hms(2023, 6, 1, 18, 0, 0).unwrap();
+        assert_eq!(work_progress(evening, &config), None);
+    }
+
+    #[test]
+    fn test_off_reason() {
+        let mut config = create_test_config("UTC");
+        config.work_days = crate::config::ALL_WEEKDAYS[..5].to_vec();
+        config.holidays = vec![NaiveDate::from_ymd_opt(2024, 12, 25).unwrap()];
+        let at = |day, hour| Utc.with_ymd_and_hms(2024, 12, day, hour, 0, 0).unwrap();
+
+        // Monday 23 December
+        assert_eq!(off_reason(at(23, 12), &config), None);
+        assert_eq!(off_reason(at(23, 20), &config), Some(OffReason::AfterHours));
+        // Saturday 21 December
+        assert_eq!(off_reason(at(21, 12), &config), Some(OffReason::Weekend));
+        // Wednesday 25 December
+        assert_eq!(off_reason(at(25, 12), &config), Some(OffReason::Holiday));
+        assert_eq!(
+            work_status(at(25, 12), &config, 30),
+            WorkStatus::Off(OffReason::Holiday)
+        );
+    }
+
+    #[test]
+    fn test_next_work_start_skips_days_off() {
+        let mut config = create_test_config("UTC");
+        config.work_days = crate::config::ALL_WEEKDAYS[..5].to_vec();
+        config.holidays = vec![NaiveDate::from_ymd_opt(2024, 12, 23).unwrap()];
+
+        // Friday evening: skips the weekend and the Monday holiday
+        let friday = Utc.with_ymd_and_hms(2024, 12, 20, 18, 0, 0).unwrap();
+        assert_eq!(
+            next_work_start(friday, &config),
+            Some(Utc.with_ymd_and_hms(2024, 12, 24, 9, 0, 0).unwrap())
+        );
+
+        config.work_days.clear();
+        assert_eq!(next_work_start(friday, &config), None);
+    }
+
+    #[test]
+    fn test_work_status() {
+        let config = create_test_config("UTC");
+        let at = |hour, minute| Utc.with_ymd_and_hms(2023, 6, 1, hour, minute, 0).unwrap();
+
+        assert_eq!(work_status(at(12, 0), &config, 30), WorkStatus::Working);
+        assert_eq!(work_status(at(16, 45), &config, 30), WorkStatus::EndingSoon);
+        assert_eq!(work_status(at(16, 30), &config, 30), WorkStatus::EndingSoon);
+        assert_eq!(work_status(at(16, 29), &config, 30), WorkStatus::Working);
+        assert_eq!(work_status(at(16, 45), &config, 0), WorkStatus::Working);
+        assert_eq!(
+            work_status(at(18, 0), &config, 30),
+            WorkStatus::Off(OffReason::AfterHours)
+        );
+    }
+
+    #[test]
+    fn test_day_offset_across_date_line() {
+        // 02:00 on 2 June in Kiritimati (+14), 01:00 on 1 June in Pago Pago (-11)
+        let now = Utc.with_ymd_and_hms(2023, 6, 1, 12, 0, 0).unwrap();
+        let kiritimati = create_test_config("Pacific/Kiritimati");
+        let pago_pago = create_test_config("Pacific/Pago_Pago");
+
+        assert_eq!(day_offset(now, &kiritimati, -11 * 3600), Some(1));
+        assert_eq!(day_offset(now, &pago_pago, 14 * 3600), Some(-1));
+        assert_eq!(day_offset(now, &pago_pago, -11 * 3600), Some(0));
+        // UTC is still on 1 June
+        assert_eq!(day_offset(now, &kiritimati, 0), Some(1));
+        assert_eq!(
+            day_offset(now, &create_test_config("Invalid/Zone"), 0),
+            None
+        );
+
+        assert_eq!(format_day_offset(1).as_deref(), Some("+1d"));
+        assert_eq!(format_day_offset(-1).as_deref(), Some("-1d"));
+        assert_eq!(format_day_offset(0), None);
+    }
+
+    #[test]
+    fn test_compare_display_info() {
+        let london = create_test_config("Europe/London");
+        let options = DisplayOptions::default();
+        // Monday 20 March 2023, a week before the clocks go forward
+        let now = Utc.with_ymd_and_hms(2023, 3, 20, 12, 0, 0).unwrap();
+
+        let next_week = compare_display_info(now, 7 * 86400, &london, |_| 0, options).unwrap();
+        assert_eq!(
+            (next_week.time.as_str(), next_week.date.as_str()),
+            ("13:00", "2023-03-27")
+        );
+        assert_eq!(next_week.diff_hours, 1.0);
+        assert_eq!(next_week.status, WorkStatus::Working);
+
+        // The reference is read at the compared instant
+        let next_week = compare_display_info(
+            now,
+            7 * 86400,
+            &london,
+            |at| zone_offset(at, &london).unwrap_or(0),
+            options,
+        )
+        .unwrap();
+        assert_eq!(next_week.diff_hours, 0.0);
+
+        assert!(
+            compare_display_info(now, 0, &create_test_config("Invalid/Zone"), |_| 0, options)
+                .is_none()
+        );
+        assert!(compare_display_info(now, i64::MAX, &london, |_| 0, options).is_none());
+    }
+
+    #[test]
+    fn test_is_within_window() {
+        let at = |hour, minute| Utc.with_ymd_and_hms(2023, 6, 1, hour, minute, 0).unwrap();
+        let time = |hour, minute| NaiveTime::from_hms_opt(hour, minute, 0).unwrap();
+
+        // Normal window, boundaries included
+        let (start, end) = (time(6, 0), time(9, 0));
+        assert_eq!(is_within_window(at(7, 30), "UTC", start, end), Some(true));
+        assert_eq!(is_within_window(at(6, 0), "UTC", start, end), Some(true));
+        assert_eq!(is_within_window(at(9, 0), "UTC", start, end), Some(true));
+        assert_eq!(is_within_window(at(5, 59), "UTC", start, end), Some(false));
+        assert_eq!(is_within_window(at(9, 1), "UTC", start, end), Some(false));
+        // 22:00 UTC is 07:00 in Tokyo
+        assert_eq!(
+            is_within_window(at(22, 0), "Asia/Tokyo", start, end),
+            Some(true)
+        );
+
+        // Overnight window
+        let (start, end) = (time(22, 0), time(6, 0));
+        for (hour, minute) in [(22, 0), (23, 30), (0, 0), (3, 0), (6, 0)] {
+            assert_eq!(
+                is_within_window(at(hour, minute), "UTC", start, end),
+                Some(true),
+                "{hour}:{minute}"
+            );
+        }
+        for (hour, minute) in [(21, 59), (6, 1), (12, 0)] {
+            assert_eq!(
+                is_within_window(at(hour, minute), "UTC", start, end),
+                Some(false),
+                "{hour}:{minute}"
+            );
+        }
+
+        assert_eq!(is_within_window(at(7, 0), "Invalid/Zone", start, end), None);
+
+        // Equal start and end is one instant here, but a 24-hour shift as
+        // work hours
+        let nine = time(9, 0);
+        assert_eq!(is_within_window(at(9, 0), "UTC", nine, nine), Some(true));
+        assert_eq!(is_within_window(at(15, 0), "UTC", nine, nine), Some(false));
+        let around_the_clock = TimezoneConfig {
+            work_hours: WorkHours {
+                start: "09:00".to_string(),
+                end: "09:00".to_string(),
+                ..Default::default()
+            },
+            ..create_test_config("UTC")
+        };
+        assert!(is_work_hours(at(15, 0), &around_the_clock));
+        assert!(is_work_hours(at(3, 0), &around_the_clock));
+    }
+
+    #[test]
+    fn test_overnight_shift_belongs_to_its_start_day() {
+        let config = TimezoneConfig {
+            work_hours: WorkHours {
+                start: "22:00".to_string(),
+                end: "06:00".to_string(),
+                ..WorkHours::default()
+            },
+            work_days: crate::config::ALL_WEEKDAYS[..5].to_vec(),
+            ..create_test_config("UTC")
+        };
+        // 2023-06-02 is a Friday
+        let at = |day, hour| Utc.with_ymd_and_hms(2023, 6, day, hour, 0, 0).unwrap();
+
+        // Friday night's shift runs into Saturday morning
+        assert!(is_work_hours(at(2, 23), &config));
+        assert!(is_work_hours(at(3, 2), &config));
+        assert_eq!(off_reason(at(3, 2), &config), None);
+        assert_eq!(next_work_transition(at(3, 2), &config), Some(at(3, 6)));
+        // No shift starts on Saturday or Sunday
+        assert!(!is_work_hours(at(3, 23), &config));
+        assert!(!is_work_hours(at(4, 2), &config));
+        assert_eq!(off_reason(at(4, 2), &config), Some(OffReason::Weekend));
+        // Monday is a work day, but its shift only starts in the evening
+        assert!(!is_work_hours(at(5, 2), &config));
+        assert_eq!(off_reason(at(5, 2), &config), Some(OffReason::AfterHours));
+        // Thursday's shift covers early Friday
+        assert!(is_work_hours(at(2, 2), &config));
+        let progress = work_progress(at(3, 2), &config).unwrap();
+        assert_eq!(progress.elapsed, chrono::Duration::hours(4));
+        assert_eq!(progress.remaining, chrono::Duration::hours(4));
+    }
+
+    #[test]
+    fn test_grace_buffer() {
+        let config = TimezoneConfig {
+            grace_before_minutes: 15,
+            grace_after_minutes: 30,
+            ..create_test_config("UTC")
+        };
+        let at = |hour, minute| Utc.with_ymd_and_hms(2023, 6, 1, hour, minute, 0).unwrap();
+
+        // Inside the buffer: reachable, but distinctly in grace
+        for now in [at(8, 50), at(8, 45), at(17, 15), at(17, 30)] {
+            assert!(is_work_hours(now, &config));
+            assert!(is_in_grace(now, &config));
+            assert_eq!(work_status(now, &config, 30), WorkStatus::Grace);
+            assert_eq!(work_progress(now, &config), None);
+        }
+        // Fully off outside the buffer
+        for now in [at(8, 44), at(17, 31), at(3, 0)] {
+            assert!(!is_work_hours(now, &config));
+            assert!(!is_in_grace(now, &config));
+            assert_eq!(
+                work_status(now, &config, 30),
+                WorkStatus::Off(OffReason::AfterHours)
+            );
+        }
+        assert!(!is_in_grace(at(12, 0), &config));
+        assert_eq!(work_status(at(12, 0), &config, 30), WorkStatus::Working);
+        // Grace before the start counts down to the start itself
+        assert_eq!(next_work_transition(at(8, 50), &config), Some(at(9, 0)));
+
+        // Without a buffer 08:50 is off
+        assert!(!is_work_hours(at(8, 50), &create_test_config("UTC")));
+
+        // Planning helpers leave the buffer out: 08:00-09:00 and 17:00-18:00
+        // are not work hours, and neither is 08:50 in a week plan
+        let hours = work_by_hour(at(12, 0), &config).unwrap();
+        assert_eq!(hours.iter().filter(|on| **on).count(), 8);
+        assert!(!hours[8] && !hours[17]);
+        let week = week_availability(
+            &config,
+            at(0, 0).date_naive(),
+            NaiveTime::from_hms_opt(8, 50, 0).unwrap(),
+            &[&config],
+        );
+        assert_eq!(week[0], [false; PLANNING_DAYS]);
+
+        // The buffer stops at midnight
+        let late = TimezoneConfig {
+            grace_after_minutes: 120,
+            work_hours: WorkHours {
+                start: "14:00".to_string(),
+                end: "23:00".to_string(),
+                ..WorkHours::default()
+            },
+            ..create_test_config("UTC")
+        };
+        assert!(is_in_grace(at(23, 59), &late));
+        assert!(!is_work_hours(at(0, 30), &late));
+    }
+
+    #[test]
+    fn test_is_within_remind_window() {
+        let config = TimezoneConfig {
+            remind_before_minutes: 15,
+            ..create_test_config("UTC")
+        };
+        let at = |hour, minute| Utc.with_ymd_and_hms(2023, 6, 1, hour, minute, 0).unwrap();
+
+        assert!(is_within_remind_window(at(8, 45), &config));
+        assert!(is_within_remind_window(at(8, 59), &config));
+        assert!(!is_within_remind_window(at(8, 44), &config));
+        // Not once work has started, nor before the end of the day
+        assert!(!is_within_remind_window(at(9, 0), &config));
+        assert!(!is_within_remind_window(at(16, 50), &config));
+        // Disabled by default
+        assert!(!is_within_remind_window(
+            at(8, 50),
+            &create_test_config("UTC")
+        ));
+
+        // Skips days off: Friday and Monday mornings remind, Sunday does not
+        let weekdays = TimezoneConfig {
+            work_days: crate::config::ALL_WEEKDAYS[..5].to_vec(),
+            remind_before_minutes: 60,
+            ..create_test_config("UTC")
+        };
+        let friday = Utc.with_ymd_and_hms(2023, 6, 2, 8, 30, 0).unwrap();
+        let sunday = Utc.with_ymd_and_hms(2023, 6, 4, 8, 30, 0).unwrap();
+        let monday = Utc.with_ymd_and_hms(2023, 6, 5, 8, 30, 0).unwrap();
+        assert!(is_within_remind_window(friday, &weekdays));
+        assert!(!is_within_remind_window(sunday, &weekdays));
+        assert!(is_within_remind_window(monday, &weekdays));
+
+        // Always-working zones never open
+        let always = TimezoneConfig {
+            always_working: true,
+            ..config
+        };
+        assert!(!is_within_remind_window(at(8, 50), &always));
+    }
+
+    #[test]
+    fn test_work_hours_in_reference() {
+        let now = Utc.with_ymd_and_hms(2023, 6, 1, 12, 0, 0).unwrap();
+        let mut london = create_test_config("Europe/London");
+        london.work_hours.end = "17:30".to_string();
+
+        // BST 09:00-17:30 is 17:00-01:30 in Tokyo (+9)
+        let in_tokyo = work_hours_in_reference(now, &london, 9 * 3600).unwrap();
+        assert_eq!(in_tokyo.start, NaiveTime::from_hms_opt(17, 0, 0).unwrap());
+        assert_eq!(in_tokyo.end, NaiveTime::from_hms_opt(1, 30, 0).unwrap());
+        assert_eq!((in_tokyo.start_day, in_tokyo.end_day), (0, 1));
+        assert_eq!(in_tokyo.label(), "17:00 - 01:30 (+1)");
+
+        // Tokyo 09:00-17:00 is 20:00 (-1) - 04:00 in New York (-4)
+        let tokyo = create_test_config("Asia/Tokyo");
+        let in_new_york = work_hours_in_reference(now, &tokyo, -4 * 3600).unwrap();
+        assert_eq!(in_new_york.label(), "20:00 (-1) - 04:00");
+
+        let mut bad = create_test_config("UTC");
+        bad.work_hours.start = "nine".to_string();
+        assert_eq!(work_hours_in_reference(now, &bad, 0), None);
+    }
+
+    #[test]
+    fn test_work_status_with_unparseable_hours() {
+        let mut config = create_test_config("UTC");
+        config.work_hours.start = "9am".to_string();
+        let noon = Utc.with_ymd_and_hms(2023, 6, 1, 12, 0, 0).unwrap();
+
+        assert_eq!(work_status(noon, &config, 30), WorkStatus::InvalidHours);
+        let info = get_time_display_info(noon, &config, 0, DisplayOptions::default()).unwrap();
+        assert_eq!(info.status, WorkStatus::InvalidHours);
+        assert!(!info.is_working);
+    }
+
+    #[test]
+    fn test_pairwise_overlaps() {
+        let zone = |name: &str, timezone: &str, start: &str, end: &str| TimezoneConfig {
+            name: name.to_string(),
+            timezone: timezone.to_string(),
+            work_hours: WorkHours {
+                start: start.to_string(),
+                end: end.to_string(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let london = zone("London", "Europe/London", "09:00", "17:00");
+        let new_york = zone("New York", "America/New_York", "09:00", "18:00");
+        let tokyo = zone("Tokyo", "Asia/Tokyo", "07:00", "10:00");
+        let zones = [&london, &new_york, &tokyo];
+        let now = Utc.with_ymd_and_hms(2024, 1, 10, 12, 0, 0).unwrap();
+        let at = |day, hour| Utc.with_ymd_and_hms(2024, 1, day, hour, 0, 0).unwrap();
+
+        let overlaps = pairwise_overlaps(now, &zones);
+        assert_eq!(
+            overlaps,
+            vec![
+                PairOverlap {
+                    first: 0,
+                    second: 1,
+                    window: Some((at(10, 14), at(10, 17))),
+                },
+                PairOverlap {
+                    first: 0,
+                    second: 2,
+                    window: None,
+                },
+                // New York's evening is Tokyo's next morning
+                PairOverlap {
+                    first: 1,
+                    second: 2,
+                    window: Some((at(10, 22), at(10, 23))),
+                },
+            ]
+        );
+
+        let report = format_overlap_report(now, &zones);
+        assert!(
+            report.contains(
+                "| London / New York | 14:00-17:00 London, 09:00-12:00 New York | 3h 0m |"
+            )
+        );
+        assert!(report.contains("| London / Tokyo | none | - |"));
+        assert!(
+            report
+                .contains("| New York / Tokyo | 17:00-18:00 New York, 07:00-08:00 Tokyo | 1h 0m |")
+        );
+    }
+
+    #[test]
+    fn test_extreme_instants() {
+        let tokyo = create_test_config("Asia/Tokyo");
+        let new_york = create_test_config("America/New_York");
+        let utc = create_test_config("UTC");
+        let options = DisplayOptions {
+            round_minutes: Some(15),
+            ..Default::default()
+        };
+        let last = Utc.with_ymd_and_hms(9999, 12, 31, 23, 59, 59).unwrap();
+        let first = Utc.with_ymd_and_hms(1, 1, 1, 0, 0, 0).unwrap();
+
+        // Ordinary extremes are still displayed
+        let info = get_time_display_info(last, &tokyo, 0, options).unwrap();
+        assert_eq!(info.date, "+10000-01-01");
+        let info = get_time_display_info(first, &new_york, 0, options).unwrap();
+        assert_eq!(info.date, "0000-12-31");
+
+        // Local times beyond chrono's range give None instead of panicking
+        for (now, zone, offset) in [
+            (DateTime::<Utc>::MAX_UTC, &tokyo, 9 * 3600),
+            (DateTime::<Utc>::MIN_UTC, &new_york, -5 * 3600),
+        ] {
+            assert_eq!(get_time_display_info(now, zone, 0, options), None);
+            assert_eq!(zone_local_time(now, zone), None);
+            assert!(!is_work_hours(now, zone));
+            assert_eq!(next_work_start(now, zone), None);
+            assert_eq!(work_overlap_by_hour(now, zone, &utc), None);
+            assert_eq!(work_hours_in_reference(now, &utc, offset), None);
+            assert_eq!(local_time_instant(now, zone, NaiveTime::MIN), None);
+            assert_eq!(
+                week_availability(&utc, NaiveDate::MAX, NaiveTime::MIN, &[&utc]),
+                vec![[false; PLANNING_DAYS]]
+            );
+            assert!(get_timezone_offset(now, &zone.timezone).is_some());
+            assert!(get_time_display_info(now, &utc, 0, options).is_some());
+        }
+    }
+
+    #[test]
+    fn test_workday_progress() {
+        let hours = |start: &str, end: &str| WorkHours {
+            start: start.to_string(),
+            end: end.to_string(),
+            ..Default::default()
+        };
+        let at = |hour, minute| Utc.with_ymd_and_hms(2024, 3, 1, hour, minute, 0).unwrap();
+        let zone = |timezone: &str, work_hours: &WorkHours| TimezoneConfig {
+            work_hours: work_hours.clone(),
+            ..create_test_config(timezone)
+        };
+        let day = hours("09:00", "17:00");
+
+        assert_eq!(workday_progress(at(9, 0), &zone("UTC", &day)), Some(0.0));
+        assert_eq!(workday_progress(at(13, 0), &zone("UTC", &day)), Some(0.5));
+        let almost = workday_progress(at(16, 59), &zone("UTC", &day)).unwrap();
+        assert!(almost > 0.99 && almost < 1.0, "{almost}");
+        assert_eq!(workday_progress(at(8, 59), &zone("UTC", &day)), None);
+        assert_eq!(workday_progress(at(17, 1), &zone("UTC", &day)), None);
+
+        // Measured on the local clock: 13:00 in Tokyo is 04:00 UTC
+        assert_eq!(
+            workday_progress(at(4, 0), &zone("Asia/Tokyo", &day)),
+            Some(0.5)
+        );
+
+        // An offset override moves the local clock: 13:00 at UTC+9
+        let overridden = TimezoneConfig {
+            offset_override: Some(9 * 3600),
+            ..zone("UTC", &day)
+        };
+        assert_eq!(workday_progress(at(4, 0), &overridden), Some(0.5));
+
+        // Overnight shifts wrap through midnight
+        let night = hours("22:00", "06:00");
+        assert_eq!(workday_progress(at(2, 0), &zone("UTC", &night)), Some(0.5));
+        let night_shift = TimezoneConfig {
+            work_hours: night.clone(),
+            ..create_test_config("UTC")
+        };
+        assert!(is_work_hours(at(23, 0), &night_shift));
+        assert!(!is_work_hours(at(12, 0), &night_shift));
+        let progress = work_progress(at(23, 0), &night_shift).unwrap();
+        assert_eq!(progress.elapsed, chrono::Duration::hours(1));
+        assert_eq!(progress.remaining, chrono::Duration::hours(7));
+        assert_eq!(
+            workday_progress(at(23, 0), &zone("UTC", &night)),
+            Some(0.125)
+        );
+        assert_eq!(workday_progress(at(12, 0), &zone("UTC", &night)), None);
+
+        assert_eq!(
+            workday_progress(at(13, 0), &zone("Invalid/Zone", &day)),
+            None
+        );
+        assert_eq!(
+            workday_progress(at(13, 0), &zone("UTC", &hours("9am", "17:00"))),
+            None
+        );
+
+        // Days off have no progress: Saturday, then a holiday
+        let weekdays = TimezoneConfig {
+            work_days: crate::config::ALL_WEEKDAYS[..5].to_vec(),
+            ..zone("UTC", &day)
+        };
+        let saturday = Utc.with_ymd_and_hms(2024, 3, 2, 12, 0, 0).unwrap();
+        assert_eq!(workday_progress(saturday, &weekdays), None);
+        let holiday = TimezoneConfig {
+            holidays: vec![NaiveDate::from_ymd_opt(2024, 3, 1).unwrap()],
+            ..zone("UTC", &day)
+        };
+        assert_eq!(workday_progress(at(13, 0), &holiday), None);
+        let always = TimezoneConfig {
+            always_working: true,
+            ..zone("UTC", &day)
+        };
+        assert_eq!(workday_progress(at(13, 0), &always), None);
+
+        // The whole end minute counts with end_of_minute
+        let to_end_of_minute = zone(
+            "UTC",
+            &WorkHours {
+                end_of_minute: true,
+                ..day.clone()
+            },
+        );
+        let last_minute = at(17, 0) + chrono::Duration::seconds(30);
+        let late = workday_progress(last_minute, &to_end_of_minute).unwrap();
+        assert!(late > 0.99 && late < 1.0, "{late}");
+
+        // Equal start and end is a 24-hour shift, as for is_work_hours
+        assert_eq!(
+            workday_progress(at(21, 0), &zone("UTC", &hours("09:00", "09:00"))),
+            Some(0.5)
+        );
+    }
 }